@@ -1,9 +1,12 @@
 use iced::{
-    widget::{Button, Container, Row, Text},
+    widget::{Button, Column, Container, Row, Text},
     Alignment, Color, Element, Length,
 };
 
-use iced_aw::helpers::color_picker;
+use iced_aw::{
+    helpers::{color_picker, color_wheel, hue_bar},
+    style::colors,
+};
 
 fn main() -> iced::Result {
     iced::application(
@@ -21,6 +24,7 @@ enum Message {
     ChooseColor,
     SubmitColor(Color),
     CancelColor,
+    ChangeColor(Color),
 }
 
 #[derive(Debug)]
@@ -50,6 +54,9 @@ impl ColorPickerExample {
             Message::CancelColor => {
                 self.show_picker = false;
             }
+            Message::ChangeColor(color) => {
+                self.color = color;
+            }
         }
     }
 
@@ -62,7 +69,18 @@ impl ColorPickerExample {
             but,
             Message::CancelColor,
             Message::SubmitColor,
-        );
+        )
+        .palette([
+            colors::RED,
+            colors::ORANGE,
+            colors::GOLD,
+            colors::LIME_GREEN,
+            colors::DODGER_BLUE,
+            colors::REBECCA_PURPLE,
+            colors::BLACK,
+            colors::WHITE,
+        ])
+        .recent_colors(8);
 
         let row = Row::new()
             .align_y(Alignment::Center)
@@ -70,7 +88,14 @@ impl ColorPickerExample {
             .push(color_picker)
             .push(Text::new(format!("Color: {:?}", self.color)));
 
-        Container::new(row)
+        let column = Column::new()
+            .align_x(Alignment::Center)
+            .spacing(10)
+            .push(row)
+            .push(color_wheel(self.color, Message::ChangeColor))
+            .push(hue_bar(self.color, Message::ChangeColor).width(200));
+
+        Container::new(column)
             .width(Length::Fill)
             .height(Length::Fill)
             .center_x(Length::Fill)
@@ -1,8 +1,11 @@
 use iced::{
-    widget::{Button, Container, Row, Text},
+    widget::{Button, Column, Container, Row, Text},
     Alignment, Element, Length,
 };
-use iced_aw::{date_picker::Date, helpers::date_picker};
+use iced_aw::{
+    date_picker::Date,
+    helpers::{calendar, date_picker, date_range_picker},
+};
 
 fn main() -> iced::Result {
     iced::application(
@@ -20,12 +23,18 @@ enum Message {
     ChooseDate,
     SubmitDate(Date),
     CancelDate,
+    SelectDate(Date),
+    ChooseRange,
+    SubmitRange(Date, Date),
+    CancelRange,
 }
 
 #[derive(Default)]
 struct DatePickerExample {
     date: Date,
     show_picker: bool,
+    range: (Date, Date),
+    show_range_picker: bool,
 }
 
 impl DatePickerExample {
@@ -41,6 +50,19 @@ impl DatePickerExample {
             Message::CancelDate => {
                 self.show_picker = false;
             }
+            Message::SelectDate(date) => {
+                self.date = date;
+            }
+            Message::ChooseRange => {
+                self.show_range_picker = true;
+            }
+            Message::SubmitRange(start, end) => {
+                self.range = (start, end);
+                self.show_range_picker = false;
+            }
+            Message::CancelRange => {
+                self.show_range_picker = false;
+            }
         }
     }
 
@@ -53,7 +75,8 @@ impl DatePickerExample {
             but,
             Message::CancelDate,
             Message::SubmitDate,
-        );
+        )
+        .text_input("%Y-%m-%d");
 
         let row = Row::new()
             .align_y(Alignment::Center)
@@ -61,7 +84,34 @@ impl DatePickerExample {
             .push(datepicker)
             .push(Text::new(format!("Date: {}", self.date,)));
 
-        Container::new(row)
+        let range_but = Button::new(Text::new("Set Range")).on_press(Message::ChooseRange);
+
+        let rangepicker = date_range_picker(
+            self.show_range_picker,
+            self.range.0,
+            self.range.1,
+            range_but,
+            Message::CancelRange,
+            Message::SubmitRange,
+        );
+
+        let range_row = Row::new()
+            .align_y(Alignment::Center)
+            .spacing(10)
+            .push(rangepicker)
+            .push(Text::new(format!(
+                "Range: {} - {}",
+                self.range.0, self.range.1
+            )));
+
+        let col = Column::new()
+            .align_x(Alignment::Center)
+            .spacing(10)
+            .push(row)
+            .push(range_row)
+            .push(calendar(self.date, Message::SelectDate));
+
+        Container::new(col)
             .center_x(Length::Fill)
             .center_y(Length::Fill)
             .width(Length::Fill)
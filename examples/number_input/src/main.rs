@@ -39,7 +39,9 @@ impl NumberInputDemo {
         let lb_minute = Text::new("Number Input:");
         let txt_minute = number_input(self.value, -10.0..250.0, Message::NumInpChanged)
             .style(number_input::number_input::primary)
-            .step(0.5);
+            .step(0.5)
+            .precision(1)
+            .suffix(" °C");
 
         Container::new(
             Row::new()
@@ -1,8 +1,9 @@
+use chrono::NaiveDateTime;
 use iced::{
-    widget::{Button, Container, Row, Text},
+    widget::{Button, Column, Container, Row, Text},
     Alignment, Element, Length,
 };
-use iced_aw::{time_picker::Time, TimePicker};
+use iced_aw::{time_picker::Time, DateTimePicker, TimePicker};
 
 fn main() -> iced::Result {
     iced::application(
@@ -20,12 +21,17 @@ enum Message {
     ChooseTime,
     SubmitTime(Time),
     CancelTime,
+    ChooseDateTime,
+    SubmitDateTime(NaiveDateTime),
+    CancelDateTime,
 }
 
 #[derive(Debug, Default)]
 struct TimePickerExample {
     time: Time,
     show_picker: bool,
+    date_time: NaiveDateTime,
+    show_date_time_picker: bool,
 }
 
 impl TimePickerExample {
@@ -41,6 +47,16 @@ impl TimePickerExample {
             Message::CancelTime => {
                 self.show_picker = false;
             }
+            Message::ChooseDateTime => {
+                self.show_date_time_picker = true;
+            }
+            Message::SubmitDateTime(date_time) => {
+                self.date_time = date_time;
+                self.show_date_time_picker = false;
+            }
+            Message::CancelDateTime => {
+                self.show_date_time_picker = false;
+            }
         }
     }
 
@@ -62,7 +78,31 @@ impl TimePickerExample {
             .push(timepicker)
             .push(Text::new(format!("Time: {}", self.time)));
 
-        Container::new(row)
+        let but = Button::new(Text::new("Set Date and Time")).on_press(Message::ChooseDateTime);
+
+        let date_time_picker = DateTimePicker::new(
+            self.show_date_time_picker,
+            self.date_time,
+            but,
+            Message::CancelDateTime,
+            Message::SubmitDateTime,
+        )
+        .use_24h()
+        .minute_step(5);
+
+        let date_time_row = Row::new()
+            .align_y(Alignment::Center)
+            .spacing(10)
+            .push(date_time_picker)
+            .push(Text::new(format!("Date and time: {}", self.date_time)));
+
+        let col = Column::new()
+            .align_x(Alignment::Center)
+            .spacing(10)
+            .push(row)
+            .push(date_time_row);
+
+        Container::new(col)
             .center_x(Length::Fill)
             .center_y(Length::Fill)
             .width(Length::Fill)
@@ -1,5 +1,5 @@
 use iced::{
-    widget::{Container, Row, Text},
+    widget::{Column, Container, Row, Text},
     Alignment, Element, Length,
 };
 use iced_aw::widgets::typed_input;
@@ -7,11 +7,13 @@ use iced_aw::widgets::typed_input;
 #[derive(Default, Debug)]
 pub struct TypedInputDemo {
     value: f32,
+    error: Option<String>,
 }
 
 #[derive(Debug, Clone)]
 pub enum Message {
     TypedInpChanged(f32),
+    TypedInpInvalid(String, std::num::ParseFloatError),
 }
 
 fn main() -> iced::Result {
@@ -30,22 +32,36 @@ fn main() -> iced::Result {
 
 impl TypedInputDemo {
     fn update(&mut self, message: self::Message) {
-        let Message::TypedInpChanged(val) = message;
-        println!("Value changed to {:?}", val);
-        self.value = val;
+        match message {
+            Message::TypedInpChanged(val) => {
+                println!("Value changed to {:?}", val);
+                self.value = val;
+                self.error = None;
+            }
+            Message::TypedInpInvalid(text, error) => {
+                self.error = Some(format!("\"{text}\": {error}"));
+            }
+        }
     }
 
     fn view(&self) -> Element<Message> {
         let lb_minute = Text::new("Typed Input:");
         let txt_minute =
-            typed_input::TypedInput::new("Placeholder", &self.value, Message::TypedInpChanged);
+            typed_input::TypedInput::new("Placeholder", &self.value, Message::TypedInpChanged)
+                .on_invalid(Message::TypedInpInvalid);
 
         Container::new(
-            Row::new()
+            Column::new()
                 .spacing(10)
-                .align_y(Alignment::Center)
-                .push(lb_minute)
-                .push(txt_minute),
+                .align_x(Alignment::Center)
+                .push(
+                    Row::new()
+                        .spacing(10)
+                        .align_y(Alignment::Center)
+                        .push(lb_minute)
+                        .push(txt_minute),
+                )
+                .push_maybe(self.error.as_deref().map(Text::new)),
         )
         .width(Length::Fill)
         .height(Length::Fill)
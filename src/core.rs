@@ -10,6 +10,9 @@ pub mod clock;
 #[cfg(feature = "color_picker")]
 pub mod color;
 
+#[cfg(feature = "number_input")]
+pub mod number;
+
 pub mod overlay;
 
 pub mod renderer;
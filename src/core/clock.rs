@@ -82,6 +82,56 @@ pub fn circle_points(distance_radius: f32, center: Point, amount: u16) -> Vec<Po
     points
 }
 
+/// Gets the distance from the value to the next multiple of the step.
+#[must_use]
+pub fn distance_to_next_step(value: u32, step: u8) -> u32 {
+    let step = u32::from(step.max(1));
+    step - value % step
+}
+
+/// Gets the distance from the value to the previous multiple of the step.
+#[must_use]
+pub fn distance_to_previous_step(value: u32, step: u8) -> u32 {
+    let step = u32::from(step.max(1));
+    match value % step {
+        0 => step,
+        rest => rest,
+    }
+}
+
+/// Rounds the minute or second value to the nearest multiple of the step,
+/// wrapping around at 60.
+#[must_use]
+pub fn nearest_step(value: u32, step: u8) -> u32 {
+    let step = u32::from(step.max(1));
+    ((value + step / 2) / step * step) % 60
+}
+
+/// Gets the interval of the labeled minutes or seconds of a clock showing
+/// only the multiples of the step, so that every label lies on a shown value.
+///
+/// The labels keep the given interval if it is a multiple of the step and
+/// are otherwise put on the common multiples of both, unless these are more
+/// than twice as far apart, then on the first multiple of the step reaching
+/// the interval.
+#[must_use]
+pub fn label_step(step: u8, interval: u8) -> usize {
+    let step = usize::from(step.max(1));
+    let interval = usize::from(interval.max(1));
+
+    let (mut a, mut b) = (step, interval);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    let common = step * interval / a;
+
+    if common <= 2 * interval {
+        common
+    } else {
+        interval.div_ceil(step) * step
+    }
+}
+
 /// # Panics
 /// Determines the nearest point with the smallest distance to the cursor
 /// position. The index of the point is returned.
@@ -125,7 +175,10 @@ pub fn nearest_radius(
 mod tests {
     use iced::{Point, Vector};
 
-    use super::{circle_points, nearest_point, nearest_radius, NearestRadius};
+    use super::{
+        circle_points, distance_to_next_step, distance_to_previous_step, label_step, nearest_point,
+        nearest_radius, nearest_step, NearestRadius,
+    };
 
     #[test]
     fn circle_points_test() {
@@ -215,4 +268,46 @@ mod tests {
         result = nearest_point(&points, cursor_position);
         assert_eq!(index, result);
     }
+
+    #[test]
+    fn distance_to_step_test() {
+        assert_eq!(distance_to_next_step(0, 15), 15);
+        assert_eq!(distance_to_next_step(7, 15), 8);
+        assert_eq!(distance_to_next_step(50, 15), 10);
+        assert_eq!(distance_to_next_step(7, 1), 1);
+        assert_eq!(distance_to_next_step(7, 0), 1);
+
+        assert_eq!(distance_to_previous_step(0, 15), 15);
+        assert_eq!(distance_to_previous_step(7, 15), 7);
+        assert_eq!(distance_to_previous_step(45, 15), 15);
+        assert_eq!(distance_to_previous_step(7, 1), 1);
+        assert_eq!(distance_to_previous_step(7, 0), 1);
+    }
+
+    #[test]
+    fn nearest_step_test() {
+        assert_eq!(nearest_step(7, 1), 7);
+        assert_eq!(nearest_step(7, 5), 5);
+        assert_eq!(nearest_step(8, 5), 10);
+        assert_eq!(nearest_step(22, 15), 15);
+        assert_eq!(nearest_step(23, 15), 30);
+        assert_eq!(nearest_step(53, 15), 0);
+        assert_eq!(nearest_step(44, 30), 30);
+        assert_eq!(nearest_step(45, 30), 0);
+        assert_eq!(nearest_step(59, 0), 59);
+    }
+
+    #[test]
+    fn label_step_test() {
+        assert_eq!(label_step(1, 5), 5);
+        assert_eq!(label_step(0, 5), 5);
+        assert_eq!(label_step(2, 5), 10);
+        assert_eq!(label_step(3, 5), 6);
+        assert_eq!(label_step(7, 5), 7);
+        assert_eq!(label_step(10, 5), 10);
+        assert_eq!(label_step(15, 5), 15);
+        assert_eq!(label_step(3, 10), 12);
+        assert_eq!(label_step(4, 10), 20);
+        assert_eq!(label_step(30, 10), 30);
+    }
 }
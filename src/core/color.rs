@@ -1,6 +1,8 @@
 //! Helper functions and structs for picking dates.
 
-use iced::Color;
+use iced::{gradient::ColorStop, Color};
+
+use crate::style::colors;
 
 /// A color in the HSV color space.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -97,11 +99,480 @@ impl From<Hsv> for Color {
     }
 }
 
+/// A color in the HSL color space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsl {
+    /// HSL hue in degrees.
+    pub(crate) hue: f32,
+    /// HSL saturation.
+    pub(crate) saturation: f32,
+    /// HSL lightness.
+    pub(crate) lightness: f32,
+}
+
+impl Hsl {
+    /// Creates a [`Hsl`] from its HSL components.
+    #[must_use]
+    pub const fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        Self {
+            hue,
+            saturation,
+            lightness,
+        }
+    }
+}
+
+impl From<Color> for Hsl {
+    // https://en.wikipedia.org/wiki/HSL_and_HSV#From_RGB
+    fn from(color: Color) -> Self {
+        let max = color.r.max(color.g.max(color.b));
+        let min = color.r.min(color.g.min(color.b));
+        let chroma = max - min;
+
+        let hue = if chroma < f32::EPSILON {
+            0.0
+        } else if (max - color.r).abs() < f32::EPSILON {
+            60.0 * (0.0 + (color.g - color.b) / chroma)
+        } else if (max - color.g).abs() < f32::EPSILON {
+            60.0 * (2.0 + (color.b - color.r) / chroma)
+        } else {
+            60.0 * (4.0 + (color.r - color.g) / chroma)
+        };
+
+        let lightness = (max + min) / 2.0;
+
+        let saturation = if chroma < f32::EPSILON {
+            0.0
+        } else {
+            chroma / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+
+        Self {
+            hue: hue.rem_euclid(360.0),
+            saturation: saturation.clamp(0.0, 1.0),
+            lightness,
+        }
+    }
+}
+
+impl From<Hsl> for Color {
+    // https://en.wikipedia.org/wiki/HSL_and_HSV#HSL_to_RGB
+    fn from(hsl: Hsl) -> Self {
+        let chroma = (1.0 - (2.0 * hsl.lightness - 1.0).abs()) * hsl.saturation;
+        let hue = hsl.hue.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (hue.rem_euclid(2.0) - 1.0).abs());
+        let m = hsl.lightness - chroma / 2.0;
+
+        let (red, green, blue) = match hue as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        Self::from_rgb(
+            (red + m).clamp(0.0, 1.0),
+            (green + m).clamp(0.0, 1.0),
+            (blue + m).clamp(0.0, 1.0),
+        )
+    }
+}
+
+/// A color in the perceptual Oklab color space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklab {
+    /// The perceived lightness from 0 to 1.
+    pub(crate) lightness: f32,
+    /// The green/red axis, roughly from -0.4 to 0.4.
+    pub(crate) a: f32,
+    /// The blue/yellow axis, roughly from -0.4 to 0.4.
+    pub(crate) b: f32,
+}
+
+impl Oklab {
+    /// Creates an [`Oklab`] from its Oklab components.
+    #[must_use]
+    pub const fn from_lab(lightness: f32, a: f32, b: f32) -> Self {
+        Self { lightness, a, b }
+    }
+}
+
+impl From<Color> for Oklab {
+    // https://bottosson.github.io/posts/oklab/#converting-from-linear-srgb-to-oklab
+    #[allow(clippy::excessive_precision, clippy::unreadable_literal)]
+    fn from(color: Color) -> Self {
+        let (r, g, b) = (
+            srgb_to_linear(color.r),
+            srgb_to_linear(color.g),
+            srgb_to_linear(color.b),
+        );
+
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+        Self {
+            lightness: 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            a: 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            b: 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+        }
+    }
+}
+
+impl From<Oklab> for Color {
+    // https://bottosson.github.io/posts/oklab/#converting-from-linear-srgb-to-oklab
+    #[allow(clippy::excessive_precision, clippy::unreadable_literal)]
+    fn from(oklab: Oklab) -> Self {
+        let l = (oklab.lightness + 0.3963377774 * oklab.a + 0.2158037573 * oklab.b).powi(3);
+        let m = (oklab.lightness - 0.1055613458 * oklab.a - 0.0638541728 * oklab.b).powi(3);
+        let s = (oklab.lightness - 0.0894841775 * oklab.a - 1.2914855480 * oklab.b).powi(3);
+
+        Self::from_rgb(
+            linear_to_srgb(4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s),
+            linear_to_srgb(-1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s),
+            linear_to_srgb(-0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s),
+        )
+    }
+}
+
+/// A color in the OKLCH color space, the polar form of [`Oklab`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklch {
+    /// The perceived lightness from 0 to 1.
+    pub(crate) lightness: f32,
+    /// The chroma, roughly from 0 to 0.4.
+    pub(crate) chroma: f32,
+    /// The hue in degrees.
+    pub(crate) hue: f32,
+}
+
+impl Oklch {
+    /// Creates an [`Oklch`] from its OKLCH components.
+    #[must_use]
+    pub const fn from_lch(lightness: f32, chroma: f32, hue: f32) -> Self {
+        Self {
+            lightness,
+            chroma,
+            hue,
+        }
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(oklab: Oklab) -> Self {
+        let chroma = oklab.a.hypot(oklab.b);
+        let hue = if chroma < CHROMA_EPSILON {
+            0.0
+        } else {
+            oklab.b.atan2(oklab.a).to_degrees().rem_euclid(360.0)
+        };
+
+        Self {
+            lightness: oklab.lightness,
+            chroma,
+            hue,
+        }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(oklch: Oklch) -> Self {
+        let (sin, cos) = oklch.hue.to_radians().sin_cos();
+
+        Self {
+            lightness: oklch.lightness,
+            a: oklch.chroma * cos,
+            b: oklch.chroma * sin,
+        }
+    }
+}
+
+impl From<Color> for Oklch {
+    fn from(color: Color) -> Self {
+        Oklab::from(color).into()
+    }
+}
+
+impl From<Oklch> for Color {
+    fn from(oklch: Oklch) -> Self {
+        Oklab::from(oklch).into()
+    }
+}
+
+/// A color in the CMYK color model.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cmyk {
+    /// The cyan component.
+    pub(crate) cyan: f32,
+    /// The magenta component.
+    pub(crate) magenta: f32,
+    /// The yellow component.
+    pub(crate) yellow: f32,
+    /// The black (key) component.
+    pub(crate) key: f32,
+}
+
+impl Cmyk {
+    /// Creates a [`Cmyk`] from its CMYK components.
+    #[must_use]
+    pub const fn from_cmyk(cyan: f32, magenta: f32, yellow: f32, key: f32) -> Self {
+        Self {
+            cyan,
+            magenta,
+            yellow,
+            key,
+        }
+    }
+}
+
+impl From<Color> for Cmyk {
+    fn from(color: Color) -> Self {
+        let key = 1.0 - color.r.max(color.g.max(color.b));
+
+        if key > 1.0 - f32::EPSILON {
+            return Self::from_cmyk(0.0, 0.0, 0.0, 1.0);
+        }
+
+        Self {
+            cyan: (1.0 - color.r - key) / (1.0 - key),
+            magenta: (1.0 - color.g - key) / (1.0 - key),
+            yellow: (1.0 - color.b - key) / (1.0 - key),
+            key,
+        }
+    }
+}
+
+impl From<Cmyk> for Color {
+    fn from(cmyk: Cmyk) -> Self {
+        Self::from_rgb(
+            (1.0 - cmyk.cyan) * (1.0 - cmyk.key),
+            (1.0 - cmyk.magenta) * (1.0 - cmyk.key),
+            (1.0 - cmyk.yellow) * (1.0 - cmyk.key),
+        )
+    }
+}
+
+/// Mixes two colors linearly, giving the first color for a factor of 0 and
+/// the second color for a factor of 1.
+#[must_use]
+pub fn mix(from: Color, to: Color, factor: f32) -> Color {
+    let factor = factor.clamp(0.0, 1.0);
+    let mix = |from: f32, to: f32| from + (to - from) * factor;
+
+    Color::from_rgba(
+        mix(from.r, to.r),
+        mix(from.g, to.g),
+        mix(from.b, to.b),
+        mix(from.a, to.a),
+    )
+}
+
+/// Gets the color of a gradient with the given stops at the given offset, or
+/// `None` if the gradient has no stops.
+///
+/// The stops do not need to be sorted by their offset.
+#[must_use]
+pub fn gradient_color_at(stops: &[ColorStop], offset: f32) -> Option<Color> {
+    let mut stops = stops.to_vec();
+    stops.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+
+    let first = stops.first()?;
+    if offset <= first.offset {
+        return Some(first.color);
+    }
+
+    stops
+        .windows(2)
+        .find(|pair| offset <= pair[1].offset)
+        .map_or(stops.last().map(|last| last.color), |pair| {
+            let span = pair[1].offset - pair[0].offset;
+            let factor = if span > 0.0 {
+                (offset - pair[0].offset) / span
+            } else {
+                0.0
+            };
+
+            Some(mix(pair[0].color, pair[1].color, factor))
+        })
+}
+
+/// Parses a CSS color.
+///
+/// Supports hexadecimal colors (`#rgb`, `#rgba`, `#rrggbb` and `#rrggbbaa`),
+/// the `rgb()`, `rgba()`, `hsl()` and `hsla()` functions and the named colors
+/// of [`colors`](crate::style::colors).
+#[must_use]
+pub fn parse_color(text: &str) -> Option<Color> {
+    let text = text.trim();
+
+    if let Some(hex) = text.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+
+    if let Some((function, arguments)) =
+        text.strip_suffix(')').and_then(|text| text.split_once('('))
+    {
+        let arguments: Vec<&str> = arguments
+            .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+            .filter(|argument| !argument.is_empty())
+            .collect();
+
+        return match function.trim().to_ascii_lowercase().as_str() {
+            "rgb" | "rgba" => parse_rgb(&arguments),
+            "hsl" | "hsla" => parse_hsl(&arguments),
+            _ => None,
+        };
+    }
+
+    colors::from_name(text)
+}
+
+/// Parses the digits of a hexadecimal color.
+fn parse_hex(hex: &str) -> Option<Color> {
+    let digits = hex
+        .chars()
+        .map(|c| c.to_digit(16).map(|digit| digit as u8))
+        .collect::<Option<Vec<u8>>>()?;
+
+    let channels: Vec<u8> = match digits.len() {
+        3 | 4 => digits.iter().map(|digit| digit * 17).collect(),
+        6 | 8 => digits
+            .chunks(2)
+            .map(|pair| pair[0] * 16 + pair[1])
+            .collect(),
+        _ => return None,
+    };
+
+    let alpha = channels.get(3).copied().unwrap_or(u8::MAX);
+
+    Some(Color::from_rgba8(
+        channels[0],
+        channels[1],
+        channels[2],
+        f32::from(alpha) / 255.0,
+    ))
+}
+
+/// Parses the arguments of the `rgb()` and `rgba()` functions.
+fn parse_rgb(arguments: &[&str]) -> Option<Color> {
+    let [red, green, blue, alpha @ ..] = arguments else {
+        return None;
+    };
+
+    Some(Color::from_rgba(
+        parse_component(red, 255.0)?,
+        parse_component(green, 255.0)?,
+        parse_component(blue, 255.0)?,
+        parse_alpha(alpha)?,
+    ))
+}
+
+/// Parses the arguments of the `hsl()` and `hsla()` functions.
+fn parse_hsl(arguments: &[&str]) -> Option<Color> {
+    let [hue, saturation, lightness, alpha @ ..] = arguments else {
+        return None;
+    };
+
+    let hue = hue
+        .strip_suffix("deg")
+        .unwrap_or(hue)
+        .parse::<f32>()
+        .ok()
+        .filter(|hue| hue.is_finite())?;
+
+    let color = Color::from(Hsl::from_hsl(
+        hue,
+        parse_component(saturation, 100.0)?,
+        parse_component(lightness, 100.0)?,
+    ));
+
+    Some(Color {
+        a: parse_alpha(alpha)?,
+        ..color
+    })
+}
+
+/// Parses the optional alpha argument of a color function.
+fn parse_alpha(alpha: &[&str]) -> Option<f32> {
+    match alpha {
+        [] => Some(1.0),
+        [alpha] => parse_component(alpha, 1.0),
+        _ => None,
+    }
+}
+
+/// Parses a number or a percentage into a component from 0 to 1, where a
+/// number is divided by the given maximum.
+fn parse_component(text: &str, max: f32) -> Option<f32> {
+    let (text, max) = text
+        .strip_suffix('%')
+        .map_or((text, max), |text| (text, 100.0));
+
+    text.parse::<f32>()
+        .ok()
+        .filter(|value| value.is_finite())
+        .map(|value| (value / max).clamp(0.0, 1.0))
+}
+
+/// The chroma below which the hue of an [`Oklch`] color is undefined.
+const CHROMA_EPSILON: f32 = 1e-4;
+
+/// Converts a gamma encoded sRGB component into linear light.
+fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a linear light component into a gamma encoded sRGB component,
+/// clamping colors outside of the sRGB gamut.
+fn linear_to_srgb(value: f32) -> f32 {
+    let value = if value <= 0.003_130_8 {
+        12.92 * value
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    };
+
+    value.clamp(0.0, 1.0)
+}
+
 #[cfg(test)]
 mod tests {
     use iced::Color;
 
-    use super::Hsv;
+    use iced::gradient::ColorStop;
+
+    use super::{gradient_color_at, mix, parse_color, Cmyk, Hsl, Hsv, Oklab, Oklch};
+
+    /// The colors used for the round trips between the color models.
+    fn round_trip_colors() -> [Color; 10] {
+        [
+            Color::from_rgb(1.0, 0.0, 0.0),
+            Color::from_rgb(0.0, 1.0, 0.0),
+            Color::from_rgb(0.0, 0.0, 1.0),
+            Color::from_rgb(1.0, 1.0, 1.0),
+            Color::from_rgb(0.0, 0.0, 0.0),
+            Color::from_rgb(0.5, 0.5, 0.5),
+            Color::from_rgb(0.36, 0.18, 0.09),
+            Color::from_rgb(0.25, 0.6, 0.85),
+            Color::from_rgb(0.9, 0.1, 0.45),
+            Color::from_rgb(0.12, 0.34, 0.05),
+        ]
+    }
+
+    /// Asserts that the components of two colors differ by less than `0.001`.
+    fn assert_color_eq(left: Color, right: Color) {
+        let close = |a: f32, b: f32| (a - b).abs() < 0.001;
+
+        assert!(
+            close(left.r, right.r) && close(left.g, right.g) && close(left.b, right.b),
+            "{left:?} != {right:?}"
+        );
+    }
 
     #[allow(clippy::cognitive_complexity)]
     #[test]
@@ -336,4 +807,332 @@ mod tests {
         let light_blue_red_rgb = Color::from_rgb(1.0, 0.0, 0.25);
         assert_eq!(light_blue_red_rgb, light_blue_red_hsv.into());
     }
+
+    #[test]
+    fn rgb_to_hsl() {
+        // https://en.wikipedia.org/wiki/HSL_and_HSV#Examples
+        let red_hsl: Hsl = Color::from_rgb(1.0, 0.0, 0.0).into();
+        assert_eq!(red_hsl, Hsl::from_hsl(0.0, 1.0, 0.5));
+
+        let orange_hsl: Hsl = Color::from_rgb(1.0, 0.5, 0.0).into();
+        assert_eq!(orange_hsl, Hsl::from_hsl(30.0, 1.0, 0.5));
+
+        let dark_green_hsl: Hsl = Color::from_rgb(0.0, 0.5, 0.0).into();
+        assert_eq!(dark_green_hsl, Hsl::from_hsl(120.0, 1.0, 0.25));
+
+        let blue_hsl: Hsl = Color::from_rgb(0.0, 0.0, 1.0).into();
+        assert_eq!(blue_hsl, Hsl::from_hsl(240.0, 1.0, 0.5));
+
+        let magenta_hsl: Hsl = Color::from_rgb(1.0, 0.0, 1.0).into();
+        assert_eq!(magenta_hsl, Hsl::from_hsl(300.0, 1.0, 0.5));
+
+        let white_hsl: Hsl = Color::from_rgb(1.0, 1.0, 1.0).into();
+        assert_eq!(white_hsl, Hsl::from_hsl(0.0, 0.0, 1.0));
+
+        let black_hsl: Hsl = Color::from_rgb(0.0, 0.0, 0.0).into();
+        assert_eq!(black_hsl, Hsl::from_hsl(0.0, 0.0, 0.0));
+
+        let gray_hsl: Hsl = Color::from_rgb(0.5, 0.5, 0.5).into();
+        assert_eq!(gray_hsl, Hsl::from_hsl(0.0, 0.0, 0.5));
+    }
+
+    #[test]
+    fn hsl_to_rgb() {
+        // https://en.wikipedia.org/wiki/HSL_and_HSV#Examples
+        assert_color_eq(
+            Hsl::from_hsl(0.0, 1.0, 0.5).into(),
+            Color::from_rgb(1.0, 0.0, 0.0),
+        );
+        assert_color_eq(
+            Hsl::from_hsl(30.0, 1.0, 0.5).into(),
+            Color::from_rgb(1.0, 0.5, 0.0),
+        );
+        assert_color_eq(
+            Hsl::from_hsl(120.0, 1.0, 0.25).into(),
+            Color::from_rgb(0.0, 0.5, 0.0),
+        );
+        assert_color_eq(
+            Hsl::from_hsl(240.0, 1.0, 0.5).into(),
+            Color::from_rgb(0.0, 0.0, 1.0),
+        );
+        assert_color_eq(
+            Hsl::from_hsl(300.0, 1.0, 0.5).into(),
+            Color::from_rgb(1.0, 0.0, 1.0),
+        );
+        assert_color_eq(
+            Hsl::from_hsl(0.0, 0.0, 1.0).into(),
+            Color::from_rgb(1.0, 1.0, 1.0),
+        );
+        assert_color_eq(
+            Hsl::from_hsl(49.5, 0.893, 0.497).into(),
+            Color::from_rgb(0.941, 0.785, 0.053),
+        );
+        assert_color_eq(
+            Hsl::from_hsl(360.0, 1.0, 0.5).into(),
+            Color::from_rgb(1.0, 0.0, 0.0),
+        );
+    }
+
+    #[test]
+    fn hsl_round_trip() {
+        for color in round_trip_colors() {
+            let hsl: Hsl = color.into();
+            assert_color_eq(color, hsl.into());
+        }
+    }
+
+    #[test]
+    fn rgb_to_oklab() {
+        // https://bottosson.github.io/posts/oklab/#table-of-example-xyz-and-oklab-pairs
+        let close = |a: f32, b: f32| (a - b).abs() < 0.001;
+
+        let white: Oklab = Color::WHITE.into();
+        assert!(close(white.lightness, 1.0) && close(white.a, 0.0) && close(white.b, 0.0));
+
+        let black: Oklab = Color::BLACK.into();
+        assert!(close(black.lightness, 0.0) && close(black.a, 0.0) && close(black.b, 0.0));
+
+        let red: Oklab = Color::from_rgb(1.0, 0.0, 0.0).into();
+        assert!(close(red.lightness, 0.628) && close(red.a, 0.225) && close(red.b, 0.126));
+
+        let green: Oklab = Color::from_rgb(0.0, 1.0, 0.0).into();
+        assert!(close(green.lightness, 0.866) && close(green.a, -0.234) && close(green.b, 0.179));
+
+        let blue: Oklab = Color::from_rgb(0.0, 0.0, 1.0).into();
+        assert!(close(blue.lightness, 0.452) && close(blue.a, -0.032) && close(blue.b, -0.312));
+    }
+
+    #[test]
+    fn oklab_round_trip() {
+        for color in round_trip_colors() {
+            let oklab: Oklab = color.into();
+            assert_color_eq(color, oklab.into());
+        }
+    }
+
+    #[test]
+    fn oklab_to_oklch() {
+        let close = |a: f32, b: f32| (a - b).abs() < 0.001;
+
+        let oklch: Oklch = Oklab::from_lab(0.5, 0.1, 0.1).into();
+        assert!(close(oklch.lightness, 0.5));
+        assert!(close(oklch.chroma, 0.141));
+        assert!(close(oklch.hue, 45.0));
+
+        let oklch: Oklch = Oklab::from_lab(0.5, 0.0, -0.1).into();
+        assert!(close(oklch.hue, 270.0));
+
+        // Grays have no hue.
+        let oklch: Oklch = Oklab::from_lab(0.5, 0.0, 0.0).into();
+        assert!(close(oklch.chroma, 0.0));
+        assert!(close(oklch.hue, 0.0));
+
+        let oklab: Oklab = Oklch::from_lch(0.5, 0.2, 180.0).into();
+        assert!(close(oklab.a, -0.2));
+        assert!(close(oklab.b, 0.0));
+    }
+
+    #[test]
+    fn oklch_round_trip() {
+        for color in round_trip_colors() {
+            let oklch: Oklch = color.into();
+            assert_color_eq(color, oklch.into());
+        }
+    }
+
+    #[test]
+    fn rgb_to_cmyk() {
+        let red: Cmyk = Color::from_rgb(1.0, 0.0, 0.0).into();
+        assert_eq!(red, Cmyk::from_cmyk(0.0, 1.0, 1.0, 0.0));
+
+        let white: Cmyk = Color::from_rgb(1.0, 1.0, 1.0).into();
+        assert_eq!(white, Cmyk::from_cmyk(0.0, 0.0, 0.0, 0.0));
+
+        let black: Cmyk = Color::from_rgb(0.0, 0.0, 0.0).into();
+        assert_eq!(black, Cmyk::from_cmyk(0.0, 0.0, 0.0, 1.0));
+
+        let dark_green: Cmyk = Color::from_rgb(0.0, 0.5, 0.0).into();
+        assert_eq!(dark_green, Cmyk::from_cmyk(1.0, 0.0, 1.0, 0.5));
+    }
+
+    #[test]
+    fn cmyk_to_rgb() {
+        assert_color_eq(
+            Cmyk::from_cmyk(0.0, 1.0, 1.0, 0.0).into(),
+            Color::from_rgb(1.0, 0.0, 0.0),
+        );
+        assert_color_eq(
+            Cmyk::from_cmyk(1.0, 0.0, 1.0, 0.5).into(),
+            Color::from_rgb(0.0, 0.5, 0.0),
+        );
+        assert_color_eq(
+            Cmyk::from_cmyk(0.0, 0.0, 0.0, 1.0).into(),
+            Color::from_rgb(0.0, 0.0, 0.0),
+        );
+        assert_color_eq(
+            Cmyk::from_cmyk(0.5, 0.25, 0.0, 0.2).into(),
+            Color::from_rgb(0.4, 0.6, 0.8),
+        );
+    }
+
+    #[test]
+    fn cmyk_round_trip() {
+        for color in round_trip_colors() {
+            let cmyk: Cmyk = color.into();
+            assert_color_eq(color, cmyk.into());
+        }
+    }
+
+    #[test]
+    fn parse_hex_color() {
+        let expected = Color::from_rgb8(255, 136, 0);
+
+        assert_color_eq(parse_color("#f80").expect("Color should parse"), expected);
+        assert_color_eq(
+            parse_color("#FF8800").expect("Color should parse"),
+            expected,
+        );
+        assert_color_eq(
+            parse_color("  #ff8800ff ").expect("Color should parse"),
+            expected,
+        );
+
+        let color = parse_color("#ff880080").expect("Color should parse");
+        assert_color_eq(color, expected);
+        assert!((color.a - 128.0 / 255.0).abs() < 0.001);
+
+        let color = parse_color("#f808").expect("Color should parse");
+        assert_color_eq(color, expected);
+        assert!((color.a - 136.0 / 255.0).abs() < 0.001);
+
+        assert_eq!(
+            parse_color("#ff88"),
+            Some(Color::from_rgba8(255, 255, 136, 136.0 / 255.0))
+        );
+        assert_eq!(parse_color("#ff880"), None);
+        assert_eq!(parse_color("#gg8800"), None);
+        assert_eq!(parse_color("#"), None);
+    }
+
+    #[test]
+    fn parse_rgb_color() {
+        let expected = Color::from_rgb8(255, 136, 0);
+
+        assert_color_eq(
+            parse_color("rgb(255, 136, 0)").expect("Color should parse"),
+            expected,
+        );
+        assert_color_eq(
+            parse_color("RGB(255 136 0)").expect("Color should parse"),
+            expected,
+        );
+        assert_color_eq(
+            parse_color("rgb(100%, 53.333%, 0%)").expect("Color should parse"),
+            expected,
+        );
+
+        let color = parse_color("rgba(255, 136, 0, 0.5)").expect("Color should parse");
+        assert_color_eq(color, expected);
+        assert!((color.a - 0.5).abs() < f32::EPSILON);
+
+        let color = parse_color("rgb(255 136 0 / 25%)").expect("Color should parse");
+        assert_color_eq(color, expected);
+        assert!((color.a - 0.25).abs() < f32::EPSILON);
+
+        assert_eq!(parse_color("rgb(255, 136)"), None);
+        assert_eq!(parse_color("rgb(255, 136, 0, 1, 1)"), None);
+        assert_eq!(parse_color("rgb(255, red, 0)"), None);
+        assert_eq!(parse_color("rgb(255, 136, 0"), None);
+    }
+
+    #[test]
+    fn parse_hsl_color() {
+        assert_color_eq(
+            parse_color("hsl(0, 100%, 50%)").expect("Color should parse"),
+            Color::from_rgb(1.0, 0.0, 0.0),
+        );
+        assert_color_eq(
+            parse_color("hsl(120deg 100% 25%)").expect("Color should parse"),
+            Color::from_rgb(0.0, 0.5, 0.0),
+        );
+        assert_color_eq(
+            parse_color("hsl(-120, 100%, 50%)").expect("Color should parse"),
+            Color::from_rgb(0.0, 0.0, 1.0),
+        );
+
+        let color = parse_color("hsla(240, 100%, 50%, 0.5)").expect("Color should parse");
+        assert_color_eq(color, Color::from_rgb(0.0, 0.0, 1.0));
+        assert!((color.a - 0.5).abs() < f32::EPSILON);
+
+        assert_eq!(parse_color("hsl(240, 100%)"), None);
+        assert_eq!(parse_color("hsl(blue, 100%, 50%)"), None);
+        assert_eq!(parse_color("hsv(240, 100%, 50%)"), None);
+    }
+
+    #[test]
+    fn parse_named_color() {
+        use crate::style::colors;
+
+        assert_eq!(parse_color("red"), Some(colors::RED));
+        assert_eq!(parse_color("AliceBlue"), Some(colors::ALICE_BLUE));
+        assert_eq!(parse_color("alice blue"), Some(colors::ALICE_BLUE));
+        assert_eq!(parse_color("REBECCA_PURPLE"), Some(colors::REBECCA_PURPLE));
+        assert_eq!(parse_color("primary"), Some(colors::PRIMARY));
+        assert_eq!(parse_color("transparent"), Some(Color::TRANSPARENT));
+
+        assert_eq!(parse_color("reddish"), None);
+        assert_eq!(parse_color(""), None);
+    }
+
+    #[test]
+    fn mix_colors() {
+        let from = Color::from_rgba(0.0, 0.2, 1.0, 0.0);
+        let to = Color::from_rgba(1.0, 0.6, 0.0, 1.0);
+
+        assert_eq!(mix(from, to, 0.0), from);
+        assert_eq!(mix(from, to, -1.0), from);
+        assert_color_eq(mix(from, to, 1.0), to);
+        assert_color_eq(mix(from, to, 2.0), to);
+
+        let color = mix(from, to, 0.5);
+        assert_color_eq(color, Color::from_rgb(0.5, 0.4, 0.5));
+        assert!((color.a - 0.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn gradient_color() {
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+        let blue = Color::from_rgb(0.0, 0.0, 1.0);
+        let white = Color::WHITE;
+
+        // Unsorted on purpose
+        let stops = [
+            ColorStop {
+                offset: 0.75,
+                color: blue,
+            },
+            ColorStop {
+                offset: 0.25,
+                color: red,
+            },
+            ColorStop {
+                offset: 1.0,
+                color: white,
+            },
+        ];
+
+        assert_eq!(gradient_color_at(&stops, 0.0), Some(red));
+        assert_eq!(gradient_color_at(&stops, 0.25), Some(red));
+        assert_color_eq(
+            gradient_color_at(&stops, 0.5).expect("Stops should not be empty"),
+            Color::from_rgb(0.5, 0.0, 0.5),
+        );
+        assert_eq!(gradient_color_at(&stops, 0.75), Some(blue));
+        assert_color_eq(
+            gradient_color_at(&stops, 0.875).expect("Stops should not be empty"),
+            Color::from_rgb(0.5, 0.5, 1.0),
+        );
+        assert_eq!(gradient_color_at(&stops[..2], 1.0), Some(blue));
+        assert_eq!(gradient_color_at(&[], 0.5), None);
+    }
 }
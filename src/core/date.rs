@@ -1,6 +1,6 @@
 //! Helper functions for calculating dates
 
-use chrono::{Datelike, Duration, Local, NaiveDate};
+use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
 use once_cell::sync::Lazy;
 use std::fmt::Display;
 
@@ -143,6 +143,31 @@ pub fn succ_day(date: NaiveDate) -> NaiveDate {
     date + Duration::days(1)
 }
 
+/// The maximum number of steps taken while searching for a day that is not
+/// disabled.
+const MAX_DISABLED_STEPS: usize = 366;
+
+/// Steps from the given date with the given function until a day is reached
+/// that is not disabled. Returns `None` if no such day was found within a year
+/// of steps.
+#[must_use]
+pub fn next_enabled(
+    date: NaiveDate,
+    step: impl Fn(NaiveDate) -> NaiveDate,
+    is_disabled: impl Fn(NaiveDate) -> bool,
+) -> Option<NaiveDate> {
+    let mut date = date;
+
+    for _ in 0..MAX_DISABLED_STEPS {
+        date = step(date);
+        if !is_disabled(date) {
+            return Some(date);
+        }
+    }
+
+    None
+}
+
 /// Specifies if the calculated day lays in the previous, same or next month of
 /// the date.
 
@@ -160,14 +185,21 @@ pub enum IsInMonth {
 
 /// # Panics
 /// Calculates the day number at the given position in the calendar table based
-/// on the given year and month.
+/// on the given year and month. The columns of the table start with the given
+/// first day of the week.
 /// panics if year, month or day does not exist.
 #[must_use]
-pub fn position_to_day(x: usize, y: usize, year: i32, month: u32) -> (usize, IsInMonth) {
+pub fn position_to_day(
+    x: usize,
+    y: usize,
+    year: i32,
+    month: u32,
+    first_weekday: Weekday,
+) -> (usize, IsInMonth) {
     let (x, y) = (x as isize, y as isize);
     let first_day =
         NaiveDate::from_ymd_opt(year, month, 1).expect("Year, Month or Day doesnt Exist");
-    let day_of_week = first_day.weekday().num_days_from_monday() as isize;
+    let day_of_week = weekday_column(first_day.weekday(), first_weekday) as isize;
     let day_of_week = if day_of_week == 0 { 7 } else { day_of_week };
 
     let day = (x + 7 * y) + 1 - day_of_week;
@@ -188,6 +220,98 @@ pub fn position_to_day(x: usize, y: usize, year: i32, month: u32) -> (usize, IsI
     }
 }
 
+/// # Panics
+/// Calculates the date at the given position in the calendar table based on
+/// the month of the given date. The columns of the table start with the given
+/// first day of the week.
+/// panics if year, month or day does not exist.
+#[must_use]
+pub fn position_to_date(x: usize, y: usize, date: NaiveDate, first_weekday: Weekday) -> NaiveDate {
+    let (day, is_in_month) = position_to_day(x, y, date.year(), date.month(), first_weekday);
+
+    match is_in_month {
+        IsInMonth::Previous => pred_month(date)
+            .with_day(day as u32)
+            .expect("Previous month with day should be valid"),
+        IsInMonth::Same => date
+            .with_day(day as u32)
+            .expect("Same month with day should be valid"),
+        IsInMonth::Next => succ_month(date)
+            .with_day(day as u32)
+            .expect("Succeeding month with day should be valid"),
+    }
+}
+
+/// Calculates the ISO week number of the given row in the calendar table
+/// based on the month of the given date. The columns of the table start with
+/// the given first day of the week.
+#[must_use]
+pub fn position_to_week(y: usize, date: NaiveDate, first_weekday: Weekday) -> u32 {
+    let monday = weekday_column(Weekday::Mon, first_weekday);
+
+    position_to_date(monday, y, date, first_weekday)
+        .iso_week()
+        .week()
+}
+
+/// Gets the column of the given weekday in the calendar table starting with
+/// the given first day of the week.
+#[must_use]
+pub fn weekday_column(weekday: Weekday, first_weekday: Weekday) -> usize {
+    ((weekday.num_days_from_monday() + 7 - first_weekday.num_days_from_monday()) % 7) as usize
+}
+
+/// The number of columns of the month and year tables of the zoomed out
+/// calendar.
+pub const ZOOM_TABLE_COLUMNS: usize = 3;
+
+/// The number of rows of the month and year tables of the zoomed out calendar.
+pub const ZOOM_TABLE_ROWS: usize = 4;
+
+/// # Panics
+/// Creates a date with the given month based on the given date, clamping the
+/// day to the last day of the month.
+/// panics if year, month or day doesnt exist.
+#[must_use]
+pub fn with_month(date: NaiveDate, month: u32) -> NaiveDate {
+    let day = date.day().min(num_days_of_month(date.year(), month));
+
+    NaiveDate::from_ymd_opt(date.year(), month, day).expect("Year, Month or Day doesnt Exist")
+}
+
+/// # Panics
+/// Creates a date with the given year based on the given date, clamping the
+/// day to the last day of the month.
+/// panics if year, month or day doesnt exist.
+#[must_use]
+pub fn with_year(date: NaiveDate, year: i32) -> NaiveDate {
+    let day = date.day().min(num_days_of_month(year, date.month()));
+
+    NaiveDate::from_ymd_opt(year, date.month(), day).expect("Year, Month or Day doesnt Exist")
+}
+
+/// Gets the first year of the decade of the given year.
+#[must_use]
+pub const fn decade_start(year: i32) -> i32 {
+    year - year.rem_euclid(10)
+}
+
+/// Calculates the month at the given position in the month table of the
+/// zoomed out calendar.
+#[must_use]
+pub fn position_to_month(x: usize, y: usize) -> u32 {
+    (y * ZOOM_TABLE_COLUMNS + x + 1) as u32
+}
+
+/// Calculates the year at the given position in the year table of the zoomed
+/// out calendar based on the decade of the given year. The table starts with
+/// the last year of the previous decade and ends with the first year of the
+/// next decade.
+#[must_use]
+pub fn position_to_year(x: usize, y: usize, year: i32) -> i32 {
+    decade_start(year) - 1 + (y * ZOOM_TABLE_COLUMNS + x) as i32
+}
+
 /// Checks if the given year is a leap year.
 
 const fn is_leap_year(year: i32) -> bool {
@@ -214,76 +338,245 @@ const fn num_days_of_month(year: i32, month: u32) -> u32 {
     }
 }
 
-/// Gets the string representation of the date of the given month and date.
+/// The locale used for the names of the months and weekdays.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// English month and weekday names.
+    #[default]
+    English,
+    /// German month and weekday names.
+    German,
+    /// French month and weekday names.
+    French,
+    /// Spanish month and weekday names.
+    Spanish,
+    /// Italian month and weekday names.
+    Italian,
+    /// Dutch month and weekday names.
+    Dutch,
+    /// Russian month and weekday names.
+    Russian,
+    /// Japanese month and weekday names.
+    Japanese,
+    /// Simplified Chinese month and weekday names.
+    Chinese,
+}
+
+impl Locale {
+    /// Gets the names of the months starting with January.
+    #[must_use]
+    pub const fn month_names(self) -> &'static [&'static str; 12] {
+        match self {
+            Self::English => &[
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ],
+            Self::German => &[
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ],
+            Self::French => &[
+                "Janvier",
+                "Février",
+                "Mars",
+                "Avril",
+                "Mai",
+                "Juin",
+                "Juillet",
+                "Août",
+                "Septembre",
+                "Octobre",
+                "Novembre",
+                "Décembre",
+            ],
+            Self::Spanish => &[
+                "Enero",
+                "Febrero",
+                "Marzo",
+                "Abril",
+                "Mayo",
+                "Junio",
+                "Julio",
+                "Agosto",
+                "Septiembre",
+                "Octubre",
+                "Noviembre",
+                "Diciembre",
+            ],
+            Self::Italian => &[
+                "Gennaio",
+                "Febbraio",
+                "Marzo",
+                "Aprile",
+                "Maggio",
+                "Giugno",
+                "Luglio",
+                "Agosto",
+                "Settembre",
+                "Ottobre",
+                "Novembre",
+                "Dicembre",
+            ],
+            Self::Dutch => &[
+                "Januari",
+                "Februari",
+                "Maart",
+                "April",
+                "Mei",
+                "Juni",
+                "Juli",
+                "Augustus",
+                "September",
+                "Oktober",
+                "November",
+                "December",
+            ],
+            Self::Russian => &[
+                "Январь",
+                "Февраль",
+                "Март",
+                "Апрель",
+                "Май",
+                "Июнь",
+                "Июль",
+                "Август",
+                "Сентябрь",
+                "Октябрь",
+                "Ноябрь",
+                "Декабрь",
+            ],
+            Self::Japanese => &[
+                "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月",
+                "12月",
+            ],
+            Self::Chinese => &[
+                "一月",
+                "二月",
+                "三月",
+                "四月",
+                "五月",
+                "六月",
+                "七月",
+                "八月",
+                "九月",
+                "十月",
+                "十一月",
+                "十二月",
+            ],
+        }
+    }
+
+    /// Gets the short labels of the weekdays starting with Monday.
+    #[must_use]
+    pub const fn weekday_labels(self) -> &'static [&'static str; 7] {
+        match self {
+            Self::English => &["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"],
+            Self::German => &["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+            Self::French => &["Lu", "Ma", "Me", "Je", "Ve", "Sa", "Di"],
+            Self::Spanish => &["Lu", "Ma", "Mi", "Ju", "Vi", "Sá", "Do"],
+            Self::Italian => &["Lu", "Ma", "Me", "Gi", "Ve", "Sa", "Do"],
+            Self::Dutch => &["Ma", "Di", "Wo", "Do", "Vr", "Za", "Zo"],
+            Self::Russian => &["Пн", "Вт", "Ср", "Чт", "Пт", "Сб", "Вс"],
+            Self::Japanese => &["月", "火", "水", "木", "金", "土", "日"],
+            Self::Chinese => &["一", "二", "三", "四", "五", "六", "日"],
+        }
+    }
+}
+
+/// Gets the string representation of the year and month of the given date
+/// in the given locale.
 #[must_use]
-pub fn date_as_string(date: NaiveDate) -> String {
-    date.format("%Y %B").to_string()
+pub fn date_as_string(date: NaiveDate, locale: Locale) -> String {
+    format!(
+        "{:04} {}",
+        date.year(),
+        locale.month_names()[date.month0() as usize]
+    )
 }
 
-/// Gets the length of the longest month name.
-pub static MAX_MONTH_STR_LEN: Lazy<usize> = Lazy::new(|| {
-    let months = [
-        NaiveDate::from_ymd_opt(0, 1, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 2, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 3, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 4, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 5, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 6, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 7, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 8, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 9, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 10, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 11, 1).expect("Year, Month or Day doesnt Exist"),
-        NaiveDate::from_ymd_opt(0, 12, 1).expect("Year, Month or Day doesnt Exist"),
-    ];
-
-    let max = months
+/// Gets the longest month name of the given locale.
+#[must_use]
+pub fn longest_month_name(locale: Locale) -> &'static str {
+    locale
+        .month_names()
         .iter()
-        .map(|m| {
-            let d = date_as_string(*m);
-            let (_, s) = d.split_once(' ').expect("Date must contain space");
-            s.len()
-        })
-        .max()
-        .expect("There should be a maximum element");
-
-    max
-});
+        .max_by_key(|name| name.chars().count())
+        .expect("There should be a maximum element")
+}
+
+/// Gets the length of the longest month name of the given locale in
+/// characters.
+#[must_use]
+pub fn max_month_str_len(locale: Locale) -> usize {
+    longest_month_name(locale).chars().count()
+}
+
+/// Gets the length of the longest month name.
+#[deprecated(
+    since = "0.9.4",
+    note = "use `max_month_str_len` with a `Locale` instead"
+)]
+pub static MAX_MONTH_STR_LEN: Lazy<usize> = Lazy::new(|| max_month_str_len(Locale::English));
 
 /// Gets the labels of the weekdays containing the first two characters of
 /// the weekdays.
+#[deprecated(since = "0.9.4", note = "use `Locale::weekday_labels` instead")]
 pub static WEEKDAY_LABELS: Lazy<Vec<String>> = Lazy::new(|| {
-    let days = [
-        // Monday
-        NaiveDate::from_ymd_opt(2020, 6, 1).expect("Year, Month or Day doesnt Exist"),
-        // Tuesday
-        NaiveDate::from_ymd_opt(2020, 6, 2).expect("Year, Month or Day doesnt Exist"),
-        // Wednesday
-        NaiveDate::from_ymd_opt(2020, 6, 3).expect("Year, Month or Day doesnt Exist"),
-        // Thursday
-        NaiveDate::from_ymd_opt(2020, 6, 4).expect("Year, Month or Day doesnt Exist"),
-        // Friday
-        NaiveDate::from_ymd_opt(2020, 6, 5).expect("Year, Month or Day doesnt Exist"),
-        // Saturday
-        NaiveDate::from_ymd_opt(2020, 6, 6).expect("Year, Month or Day doesnt Exist"),
-        // Sunday
-        NaiveDate::from_ymd_opt(2020, 6, 7).expect("Year, Month or Day doesnt Exist"),
-    ];
-
-    days.iter()
-        .map(|d| d.format("%a").to_string())
-        .map(|s| s[0..2].to_owned())
+    Locale::English
+        .weekday_labels()
+        .iter()
+        .map(|&label| label.to_owned())
         .collect()
 });
 
+/// Formats the given date in the given `chrono` format, e.g. `%d.%m.%Y`.
+///
+/// Returns `None` if the format is invalid.
+#[must_use]
+pub fn format_date(date: NaiveDate, format: &str) -> Option<String> {
+    use std::fmt::Write;
+
+    let mut text = String::new();
+    write!(text, "{}", date.format(format)).ok().map(|()| text)
+}
+
+/// Parses a date typed in the given `chrono` format, ignoring surrounding
+/// whitespace.
+#[must_use]
+pub fn parse_date(text: &str, format: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text.trim(), format).ok()
+}
+
 #[cfg(test)]
 
 mod tests {
-    use chrono::NaiveDate;
+    use chrono::{Datelike, NaiveDate, Weekday};
 
     use super::{
-        is_leap_year, num_days_of_month, position_to_day, pred_month, pred_year, succ_month,
-        succ_year, IsInMonth,
+        date_as_string, decade_start, format_date, is_leap_year, max_month_str_len, next_enabled,
+        num_days_of_month, parse_date, position_to_date, position_to_day, position_to_month,
+        position_to_week, position_to_year, pred_day, pred_month, pred_week, pred_year, succ_day,
+        succ_month, succ_year, weekday_column, with_month, with_year, IsInMonth, Locale,
     };
 
     #[test]
@@ -370,58 +663,185 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn next_enabled_test() {
+        let is_weekend = |date: NaiveDate| matches!(date.weekday(), Weekday::Sat | Weekday::Sun);
+
+        // Friday
+        let date = NaiveDate::from_ymd_opt(2020, 6, 5).expect("Year, Month or Day doesnt Exist");
+        let result = next_enabled(date, succ_day, is_weekend);
+        let expected =
+            NaiveDate::from_ymd_opt(2020, 6, 8).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(result, Some(expected));
+
+        // Monday
+        let date = NaiveDate::from_ymd_opt(2020, 6, 8).expect("Year, Month or Day doesnt Exist");
+        let result = next_enabled(date, pred_day, is_weekend);
+        let expected =
+            NaiveDate::from_ymd_opt(2020, 6, 5).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(result, Some(expected));
+
+        let result = next_enabled(date, succ_day, is_weekend);
+        let expected =
+            NaiveDate::from_ymd_opt(2020, 6, 9).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(result, Some(expected));
+
+        // Saturday stays a saturday when stepping by weeks
+        let date = NaiveDate::from_ymd_opt(2020, 6, 6).expect("Year, Month or Day doesnt Exist");
+        let result = next_enabled(date, pred_week, is_weekend);
+        assert_eq!(result, None);
+    }
+
     #[allow(clippy::shadow_unrelated)]
     #[test]
     fn position_to_day_test() {
-        let (day, is_in_month) = position_to_day(0, 0, 2020, 12);
+        let (day, is_in_month) = position_to_day(0, 0, 2020, 12, Weekday::Mon);
         assert_eq!(day, 30);
         assert_eq!(is_in_month, IsInMonth::Previous);
 
-        let (day, is_in_month) = position_to_day(1, 0, 2020, 12);
+        let (day, is_in_month) = position_to_day(1, 0, 2020, 12, Weekday::Mon);
         assert_eq!(day, 1);
         assert_eq!(is_in_month, IsInMonth::Same);
 
-        let (day, is_in_month) = position_to_day(3, 4, 2020, 12);
+        let (day, is_in_month) = position_to_day(3, 4, 2020, 12, Weekday::Mon);
         assert_eq!(day, 31);
         assert_eq!(is_in_month, IsInMonth::Same);
 
-        let (day, is_in_month) = position_to_day(6, 5, 2020, 12);
+        let (day, is_in_month) = position_to_day(6, 5, 2020, 12, Weekday::Mon);
         assert_eq!(day, 10);
         assert_eq!(is_in_month, IsInMonth::Next);
 
-        let (day, is_in_month) = position_to_day(0, 0, 2020, 11);
+        let (day, is_in_month) = position_to_day(0, 0, 2020, 11, Weekday::Mon);
         assert_eq!(day, 26);
         assert_eq!(is_in_month, IsInMonth::Previous);
 
-        let (day, is_in_month) = position_to_day(6, 0, 2020, 11);
+        let (day, is_in_month) = position_to_day(6, 0, 2020, 11, Weekday::Mon);
         assert_eq!(day, 1);
         assert_eq!(is_in_month, IsInMonth::Same);
 
-        let (day, is_in_month) = position_to_day(0, 5, 2020, 11);
+        let (day, is_in_month) = position_to_day(0, 5, 2020, 11, Weekday::Mon);
         assert_eq!(day, 30);
         assert_eq!(is_in_month, IsInMonth::Same);
 
-        let (day, is_in_month) = position_to_day(6, 5, 2020, 11);
+        let (day, is_in_month) = position_to_day(6, 5, 2020, 11, Weekday::Mon);
         assert_eq!(day, 6);
         assert_eq!(is_in_month, IsInMonth::Next);
 
-        let (day, is_in_month) = position_to_day(0, 0, 2021, 2);
+        let (day, is_in_month) = position_to_day(0, 0, 2021, 2, Weekday::Mon);
         assert_eq!(day, 25);
         assert_eq!(is_in_month, IsInMonth::Previous);
 
-        let (day, is_in_month) = position_to_day(0, 1, 2021, 2);
+        let (day, is_in_month) = position_to_day(0, 1, 2021, 2, Weekday::Mon);
         assert_eq!(day, 1);
         assert_eq!(is_in_month, IsInMonth::Same);
 
-        let (day, is_in_month) = position_to_day(6, 4, 2021, 2);
+        let (day, is_in_month) = position_to_day(6, 4, 2021, 2, Weekday::Mon);
         assert_eq!(day, 28);
         assert_eq!(is_in_month, IsInMonth::Same);
 
-        let (day, is_in_month) = position_to_day(0, 5, 2021, 2);
+        let (day, is_in_month) = position_to_day(0, 5, 2021, 2, Weekday::Mon);
         assert_eq!(day, 1);
         assert_eq!(is_in_month, IsInMonth::Next);
     }
 
+    #[test]
+    fn position_to_day_first_weekday_test() {
+        // 2020-12-01 is a Tuesday
+        let expected = [
+            (Weekday::Mon, 30, (1, 0), 10),
+            (Weekday::Tue, 24, (0, 1), 4),
+            (Weekday::Wed, 25, (6, 0), 5),
+            (Weekday::Thu, 26, (5, 0), 6),
+            (Weekday::Fri, 27, (4, 0), 7),
+            (Weekday::Sat, 28, (3, 0), 8),
+            (Weekday::Sun, 29, (2, 0), 9),
+        ];
+
+        for (first_weekday, first_cell, (x, y), last_cell) in expected {
+            let (day, is_in_month) = position_to_day(0, 0, 2020, 12, first_weekday);
+            assert_eq!(day, first_cell);
+            assert_eq!(is_in_month, IsInMonth::Previous);
+
+            let (day, is_in_month) = position_to_day(x, y, 2020, 12, first_weekday);
+            assert_eq!(day, 1);
+            assert_eq!(is_in_month, IsInMonth::Same);
+
+            let (day, is_in_month) = position_to_day(6, 5, 2020, 12, first_weekday);
+            assert_eq!(day, last_cell);
+            assert_eq!(is_in_month, IsInMonth::Next);
+        }
+    }
+
+    #[test]
+    fn position_to_week_test() {
+        let date = NaiveDate::from_ymd_opt(2020, 12, 15).expect("Year, Month or Day doesnt Exist");
+
+        assert_eq!(position_to_week(0, date, Weekday::Mon), 49);
+        assert_eq!(position_to_week(4, date, Weekday::Mon), 53);
+        assert_eq!(position_to_week(5, date, Weekday::Mon), 1);
+
+        assert_eq!(position_to_week(0, date, Weekday::Sun), 49);
+        assert_eq!(position_to_week(4, date, Weekday::Sun), 53);
+        assert_eq!(position_to_week(5, date, Weekday::Sun), 1);
+
+        assert_eq!(position_to_week(1, date, Weekday::Tue), 50);
+    }
+
+    #[test]
+    fn weekday_column_test() {
+        assert_eq!(weekday_column(Weekday::Mon, Weekday::Mon), 0);
+        assert_eq!(weekday_column(Weekday::Sun, Weekday::Mon), 6);
+        assert_eq!(weekday_column(Weekday::Sun, Weekday::Sun), 0);
+        assert_eq!(weekday_column(Weekday::Mon, Weekday::Sun), 1);
+        assert_eq!(weekday_column(Weekday::Fri, Weekday::Sat), 6);
+    }
+
+    #[test]
+    fn position_to_date_test() {
+        let date = NaiveDate::from_ymd_opt(2020, 12, 15).expect("Year, Month or Day doesnt Exist");
+
+        let result = position_to_date(0, 0, date, Weekday::Mon);
+        let expected =
+            NaiveDate::from_ymd_opt(2020, 11, 30).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(result, expected);
+
+        let result = position_to_date(3, 4, date, Weekday::Mon);
+        let expected =
+            NaiveDate::from_ymd_opt(2020, 12, 31).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(result, expected);
+
+        let result = position_to_date(6, 5, date, Weekday::Mon);
+        let expected =
+            NaiveDate::from_ymd_opt(2021, 1, 10).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(result, expected);
+
+        let date = NaiveDate::from_ymd_opt(2021, 3, 31).expect("Year, Month or Day doesnt Exist");
+        let result = position_to_date(0, 0, date, Weekday::Mon);
+        let expected =
+            NaiveDate::from_ymd_opt(2021, 2, 22).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn date_as_string_test() {
+        let date = NaiveDate::from_ymd_opt(2020, 3, 6).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(date_as_string(date, Locale::English), "2020 March");
+        assert_eq!(date_as_string(date, Locale::German), "2020 März");
+        assert_eq!(date_as_string(date, Locale::Japanese), "2020 3月");
+
+        let date = NaiveDate::from_ymd_opt(987, 12, 6).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(date_as_string(date, Locale::Russian), "0987 Декабрь");
+    }
+
+    #[test]
+    fn max_month_str_len_test() {
+        assert_eq!(max_month_str_len(Locale::English), 9);
+        assert_eq!(max_month_str_len(Locale::German), 9);
+        assert_eq!(max_month_str_len(Locale::Spanish), 10);
+        assert_eq!(max_month_str_len(Locale::Japanese), 3);
+        assert_eq!(max_month_str_len(Locale::Chinese), 3);
+    }
+
     #[test]
     fn is_leap_year_test() {
         assert!(is_leap_year(2020));
@@ -447,4 +867,76 @@ mod tests {
         assert_eq!(num_days_of_month(2020, 11), 30);
         assert_eq!(num_days_of_month(2020, 12), 31);
     }
+
+    #[test]
+    fn with_month_test() {
+        let date = NaiveDate::from_ymd_opt(2020, 5, 6).expect("Year, Month or Day doesnt Exist");
+        let expected =
+            NaiveDate::from_ymd_opt(2020, 11, 6).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(with_month(date, 11), expected);
+
+        let date = NaiveDate::from_ymd_opt(2020, 3, 31).expect("Year, Month or Day doesnt Exist");
+        let expected =
+            NaiveDate::from_ymd_opt(2020, 2, 29).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(with_month(date, 2), expected);
+    }
+
+    #[test]
+    fn with_year_test() {
+        let date = NaiveDate::from_ymd_opt(2020, 5, 6).expect("Year, Month or Day doesnt Exist");
+        let expected =
+            NaiveDate::from_ymd_opt(1999, 5, 6).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(with_year(date, 1999), expected);
+
+        let date = NaiveDate::from_ymd_opt(2020, 2, 29).expect("Year, Month or Day doesnt Exist");
+        let expected =
+            NaiveDate::from_ymd_opt(2030, 2, 28).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(with_year(date, 2030), expected);
+    }
+
+    #[test]
+    fn decade_start_test() {
+        assert_eq!(decade_start(2020), 2020);
+        assert_eq!(decade_start(2029), 2020);
+        assert_eq!(decade_start(1999), 1990);
+        assert_eq!(decade_start(-1), -10);
+    }
+
+    #[test]
+    fn position_to_month_test() {
+        assert_eq!(position_to_month(0, 0), 1);
+        assert_eq!(position_to_month(2, 0), 3);
+        assert_eq!(position_to_month(0, 1), 4);
+        assert_eq!(position_to_month(2, 3), 12);
+    }
+
+    #[test]
+    fn position_to_year_test() {
+        assert_eq!(position_to_year(0, 0, 2024), 2019);
+        assert_eq!(position_to_year(1, 0, 2024), 2020);
+        assert_eq!(position_to_year(1, 3, 2020), 2029);
+        assert_eq!(position_to_year(2, 3, 2029), 2030);
+    }
+
+    #[test]
+    fn format_date_test() {
+        let date = NaiveDate::from_ymd_opt(1987, 6, 5).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(format_date(date, "%Y-%m-%d"), Some("1987-06-05".to_owned()));
+        assert_eq!(format_date(date, "%d.%m.%Y"), Some("05.06.1987".to_owned()));
+        assert_eq!(format_date(date, "%Q"), None);
+    }
+
+    #[test]
+    fn parse_date_test() {
+        let date = NaiveDate::from_ymd_opt(1987, 6, 5).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(parse_date("1987-06-05", "%Y-%m-%d"), Some(date));
+        assert_eq!(parse_date(" 05.06.1987 ", "%d.%m.%Y"), Some(date));
+        assert_eq!(parse_date("1987-06-31", "%Y-%m-%d"), None);
+        assert_eq!(parse_date("05.06.", "%d.%m.%Y"), None);
+        assert_eq!(parse_date("", "%Y-%m-%d"), None);
+
+        // Formatted dates can be parsed back.
+        let text = format_date(date, "%d/%m/%Y").expect("Format should be valid");
+        assert_eq!(parse_date(&text, "%d/%m/%Y"), Some(date));
+    }
 }
@@ -0,0 +1,290 @@
+//! Helper functions for formatting and parsing numbers.
+
+use num_format::{Format, Grouping};
+use std::str::FromStr;
+
+/// The maximum nesting of the parentheses and signs of an arithmetic expression.
+const MAX_EXPRESSION_DEPTH: usize = 64;
+
+/// Formats the text of a number, as given by its `Display` implementation,
+/// with the thousands separators, decimal mark and minus sign of the format.
+///
+/// Text that is not a plain decimal number (like `NaN` or `inf`) is returned
+/// unchanged.
+#[must_use]
+pub fn format_number(text: &str, format: &impl Format) -> String {
+    let (negative, digits) = text
+        .strip_prefix('-')
+        .map_or((false, text), |digits| (true, digits));
+    let (integer, fraction) = digits
+        .split_once('.')
+        .map_or((digits, None), |(integer, fraction)| {
+            (integer, Some(fraction))
+        });
+
+    if integer.is_empty() || !integer.chars().all(|c| c.is_ascii_digit()) {
+        return text.to_owned();
+    }
+
+    let separator = format.separator().into_str();
+    let grouping = format.grouping();
+    let mut formatted = String::with_capacity(text.len() * 2);
+
+    if negative {
+        formatted.push_str(format.minus_sign().into_str());
+    }
+
+    for (index, digit) in integer.chars().enumerate() {
+        if index > 0 && starts_group(integer.len() - index, grouping) {
+            formatted.push_str(separator);
+        }
+        formatted.push(digit);
+    }
+
+    if let Some(fraction) = fraction {
+        formatted.push_str(format.decimal().into_str());
+        formatted.push_str(fraction);
+    }
+
+    formatted
+}
+
+/// Checks if a group of digits starts at the digit followed by the given
+/// number of remaining digits of the integer part.
+const fn starts_group(remaining: usize, grouping: Grouping) -> bool {
+    match grouping {
+        Grouping::Standard => remaining % 3 == 0,
+        Grouping::Indian => remaining == 3 || (remaining > 3 && remaining % 2 == 1),
+        Grouping::Posix => false,
+    }
+}
+
+/// Converts the text of a number formatted with the format back to the text
+/// understood by `FromStr`.
+///
+/// Thousands separators and whitespace are removed, and the decimal mark and
+/// minus sign of the format are replaced by `.` and `-`.
+#[must_use]
+pub fn normalize_number(text: &str, format: &impl Format) -> String {
+    let separator = format.separator().into_str();
+    let text = if separator.is_empty() {
+        text.to_owned()
+    } else {
+        text.replace(separator, "")
+    };
+
+    text.chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .replace(format.minus_sign().into_str(), "-")
+        .replace(format.decimal().into_str(), ".")
+}
+
+/// Parses the text of a number formatted with the format.
+///
+/// # Errors
+/// Returns the error of `FromStr` if the normalized text is not a number.
+pub fn parse_number<T: FromStr>(text: &str, format: &impl Format) -> Result<T, T::Err> {
+    T::from_str(&normalize_number(text, format))
+}
+
+/// Evaluates a small arithmetic expression like `=12*4+3`, `1/3` or `(2 + 3) × 10%`.
+///
+/// It supports `+`, `-`, `*` and `/` with their typographic forms `−`, `×` and
+/// `÷`, parentheses and `%` dividing by a hundred. A leading `=` is ignored.
+/// Returns `None` if the text is not an expression, it is nested too deeply or
+/// its result is not finite.
+#[must_use]
+pub fn evaluate_expression(text: &str) -> Option<f64> {
+    let text = text.trim();
+    let text = text.strip_prefix('=').unwrap_or(text);
+    let mut expression = Expression {
+        chars: text.chars().filter(|c| !c.is_whitespace()).collect(),
+        position: 0,
+        depth: 0,
+    };
+
+    let result = expression.sum()?;
+    (expression.position == expression.chars.len() && result.is_finite()).then_some(result)
+}
+
+/// A recursive descent parser of an arithmetic expression.
+struct Expression {
+    /// The characters of the expression without whitespace.
+    chars: Vec<char>,
+    /// The position of the next character.
+    position: usize,
+    /// The nesting of the currently parsed parentheses and signs.
+    depth: usize,
+}
+
+impl Expression {
+    /// Consumes the next character if it matches the predicate.
+    fn next_if(&mut self, predicate: impl Fn(char) -> bool) -> Option<char> {
+        let c = self
+            .chars
+            .get(self.position)
+            .copied()
+            .filter(|&c| predicate(c))?;
+        self.position += 1;
+        Some(c)
+    }
+
+    /// Parses a nested part of the expression, failing if it is nested deeper
+    /// than [`MAX_EXPRESSION_DEPTH`].
+    fn nested(&mut self, parse: impl FnOnce(&mut Self) -> Option<f64>) -> Option<f64> {
+        if self.depth >= MAX_EXPRESSION_DEPTH {
+            return None;
+        }
+
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    /// Parses a sum or difference of products.
+    fn sum(&mut self) -> Option<f64> {
+        let mut result = self.product()?;
+
+        while let Some(operator) = self.next_if(|c| matches!(c, '+' | '-' | '−')) {
+            let operand = self.product()?;
+            result = if operator == '+' {
+                result + operand
+            } else {
+                result - operand
+            };
+        }
+
+        Some(result)
+    }
+
+    /// Parses a product or quotient of factors.
+    fn product(&mut self) -> Option<f64> {
+        let mut result = self.factor()?;
+
+        while let Some(operator) = self.next_if(|c| matches!(c, '*' | '×' | '/' | '÷')) {
+            let operand = self.factor()?;
+            result = if matches!(operator, '*' | '×') {
+                result * operand
+            } else {
+                result / operand
+            };
+        }
+
+        Some(result)
+    }
+
+    /// Parses a signed number or parenthesized expression followed by any
+    /// number of percent signs.
+    fn factor(&mut self) -> Option<f64> {
+        if let Some(sign) = self.next_if(|c| matches!(c, '+' | '-' | '−')) {
+            let factor = self.nested(Self::factor)?;
+            return Some(if sign == '+' { factor } else { -factor });
+        }
+
+        let mut result = if self.next_if(|c| c == '(').is_some() {
+            let result = self.nested(Self::sum)?;
+            let _ = self.next_if(|c| c == ')')?;
+            result
+        } else {
+            let start = self.position;
+            while self.next_if(|c| c.is_ascii_digit() || c == '.').is_some() {}
+            self.chars[start..self.position]
+                .iter()
+                .collect::<String>()
+                .parse()
+                .ok()?
+        };
+
+        while self.next_if(|c| c == '%').is_some() {
+            result /= 100.0;
+        }
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_format::Locale;
+
+    use super::{evaluate_expression, format_number, normalize_number, parse_number};
+
+    #[test]
+    fn format_number_test() {
+        assert_eq!(format_number("1234567", &Locale::en), "1,234,567");
+        assert_eq!(format_number("1234.5", &Locale::de), "1.234,5");
+        assert_eq!(format_number("-1234.25", &Locale::en), "-1,234.25");
+        assert_eq!(format_number("123", &Locale::en), "123");
+        assert_eq!(format_number("0.5", &Locale::de), "0,5");
+        assert_eq!(format_number("1234567", &Locale::en_IN), "12,34,567");
+        assert_eq!(format_number("NaN", &Locale::en), "NaN");
+        assert_eq!(format_number("inf", &Locale::de), "inf");
+    }
+
+    #[test]
+    fn normalize_number_test() {
+        assert_eq!(normalize_number("1,234,567", &Locale::en), "1234567");
+        assert_eq!(normalize_number("1.234,5", &Locale::de), "1234.5");
+        assert_eq!(normalize_number("1 234,5", &Locale::fr), "1234.5");
+        assert_eq!(normalize_number("-12,34,567", &Locale::en_IN), "-1234567");
+    }
+
+    #[test]
+    fn parse_number_test() {
+        assert_eq!(parse_number::<f64>("1.234,5", &Locale::de), Ok(1234.5));
+        assert_eq!(parse_number::<i32>("-1,234", &Locale::en), Ok(-1234));
+        assert_eq!(parse_number::<u8>("1,000", &Locale::en).ok(), None);
+        assert!(parse_number::<i32>("abc", &Locale::en).is_err());
+
+        for value in [0.0, 1234.5, -98765.25] {
+            let formatted = format_number(&value.to_string(), &Locale::de);
+            assert_eq!(parse_number::<f64>(&formatted, &Locale::de), Ok(value));
+        }
+    }
+
+    #[test]
+    fn evaluate_expression_test() {
+        assert_eq!(evaluate_expression("=12*4+3"), Some(51.0));
+        assert_eq!(evaluate_expression("1/4"), Some(0.25));
+        assert_eq!(evaluate_expression(" (2 + 3) × 4 "), Some(20.0));
+        assert_eq!(evaluate_expression("10÷4"), Some(2.5));
+        assert_eq!(evaluate_expression("2+3*4"), Some(14.0));
+        assert_eq!(evaluate_expression("50%"), Some(0.5));
+        assert_eq!(evaluate_expression("200*10%"), Some(20.0));
+        assert_eq!(evaluate_expression("−3+1"), Some(-2.0));
+        assert_eq!(evaluate_expression("-(1-4)"), Some(3.0));
+        assert_eq!(evaluate_expression("12.5"), Some(12.5));
+    }
+
+    #[test]
+    fn evaluate_invalid_expression_test() {
+        assert_eq!(evaluate_expression(""), None);
+        assert_eq!(evaluate_expression("2+"), None);
+        assert_eq!(evaluate_expression("2*(3"), None);
+        assert_eq!(evaluate_expression("2)"), None);
+        assert_eq!(evaluate_expression("1/0"), None);
+        assert_eq!(evaluate_expression("1.2.3"), None);
+        assert_eq!(evaluate_expression("abc"), None);
+    }
+
+    #[test]
+    fn evaluate_nested_expression_test() {
+        assert_eq!(evaluate_expression("((((1+2))))*--3"), Some(9.0));
+        assert_eq!(
+            evaluate_expression(&format!("{}1{}", "(".repeat(32), ")".repeat(32))),
+            Some(1.0)
+        );
+
+        assert_eq!(evaluate_expression(&"(".repeat(100_000)), None);
+        assert_eq!(
+            evaluate_expression(&format!("{}1{}", "(".repeat(100_000), ")".repeat(100_000))),
+            None
+        );
+        assert_eq!(
+            evaluate_expression(&format!("{}1", "-".repeat(100_000))),
+            None
+        );
+    }
+}
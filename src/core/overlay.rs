@@ -2,6 +2,9 @@
 
 use iced::{advanced::layout, Point, Size};
 
+#[cfg(any(feature = "color_picker", feature = "date_picker"))]
+pub(crate) mod text_input;
+
 /// Trait containing functions for positioning of nodes.
 pub trait Position {
     /// Centers this node around the given position. If the node is over the
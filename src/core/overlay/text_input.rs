@@ -0,0 +1,153 @@
+//! The text input in which the value of a picker overlay can be typed.
+
+use iced::{
+    advanced::{renderer, widget::Tree, Clipboard, Layout, Renderer as _, Shell, Widget},
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::TextInput,
+    Border, Color, Element, Event, Rectangle, Renderer, Shadow,
+};
+
+/// The messages of the text input of an overlay.
+#[derive(Clone, Debug)]
+pub(crate) enum TextMessage {
+    /// The text of the text input changed.
+    Input(String),
+    /// The text input was submitted.
+    Submit,
+}
+
+/// Creates the text input of an overlay showing the given text.
+pub(crate) fn text_input<'a, Theme>(
+    placeholder: &str,
+    text: &str,
+) -> TextInput<'a, TextMessage, Theme, Renderer>
+where
+    Theme: iced::widget::text_input::Catalog + 'a,
+{
+    TextInput::new(placeholder, text)
+        .on_input(TextMessage::Input)
+        .on_submit(TextMessage::Submit)
+}
+
+/// Passes the event to the text input of an overlay, returning the messages
+/// of the text input.
+///
+/// The given function is called if the text input gets pressed, so that the
+/// overlay can drop its own focus, since the keys are handled by the text
+/// input while it is focused.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn on_event<Message, Theme>(
+    text_input: &mut TextInput<'_, TextMessage, Theme, Renderer>,
+    tree: &mut Tree,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: Cursor,
+    renderer: &Renderer,
+    clipboard: &mut dyn Clipboard,
+    shell: &mut Shell<Message>,
+    on_press: impl FnOnce(),
+) -> (event::Status, Vec<TextMessage>)
+where
+    Theme: iced::widget::text_input::Catalog,
+{
+    if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+    | Event::Touch(touch::Event::FingerPressed { .. }) = event
+    {
+        if cursor.is_over(layout.bounds()) {
+            on_press();
+        }
+    }
+
+    let mut messages = Vec::new();
+    let mut sub_shell = Shell::new(&mut messages);
+    let status = Widget::<TextMessage, Theme, Renderer>::on_event(
+        text_input,
+        tree,
+        event.clone(),
+        layout,
+        cursor,
+        renderer,
+        clipboard,
+        &mut sub_shell,
+        &layout.bounds(),
+    );
+
+    if let Some(redraw) = sub_shell.redraw_request() {
+        shell.request_redraw(redraw);
+    }
+    if sub_shell.is_layout_invalid() {
+        shell.invalidate_layout();
+    }
+    if sub_shell.are_widgets_invalid() {
+        shell.invalidate_widgets();
+    }
+
+    (status, messages)
+}
+
+/// Draws the border marking the text typed into the text input as invalid.
+pub(crate) fn draw_error(renderer: &mut Renderer, bounds: Rectangle, color: Color) {
+    if (bounds.width > 0.) && (bounds.height > 0.) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    radius: 2.0.into(),
+                    width: 1.0,
+                    color,
+                },
+                shadow: Shadow::default(),
+            },
+            Color::TRANSPARENT,
+        );
+    }
+}
+
+/// Drops the typed text if the value was changed by something else than the
+/// text input.
+pub(crate) fn sync_text<T: PartialEq>(text: &mut Option<String>, value: &T, previous_value: &T) {
+    if value != previous_value {
+        *text = None;
+    }
+}
+
+/// Creates the trees of the buttons and of the text input of an overlay.
+pub(crate) fn children<Message, Theme>(
+    cancel_button: &Element<'_, Message, Theme, Renderer>,
+    submit_button: &Element<'_, Message, Theme, Renderer>,
+    text_input: &TextInput<'_, TextMessage, Theme, Renderer>,
+) -> Vec<Tree>
+where
+    Theme: iced::widget::text_input::Catalog,
+{
+    vec![
+        Tree::new(cancel_button),
+        Tree::new(submit_button),
+        Tree::new(text_input as &dyn Widget<TextMessage, Theme, Renderer>),
+    ]
+}
+
+/// Diffs the trees of the buttons and of the text input of an overlay.
+///
+/// The trees following them are managed by the overlay itself, so the
+/// children are diffed one by one instead of being truncated by
+/// `Tree::diff_children`.
+pub(crate) fn diff<Message, Theme>(
+    tree: &mut Tree,
+    cancel_button: &Element<'_, Message, Theme, Renderer>,
+    submit_button: &Element<'_, Message, Theme, Renderer>,
+    text_input: &TextInput<'_, TextMessage, Theme, Renderer>,
+) where
+    Theme: iced::widget::text_input::Catalog,
+{
+    if tree.children.len() < 3 {
+        tree.children = children(cancel_button, submit_button, text_input);
+        return;
+    }
+
+    tree.children[0].diff(cancel_button);
+    tree.children[1].diff(submit_button);
+    tree.children[2].diff(text_input as &dyn Widget<TextMessage, Theme, Renderer>);
+}
@@ -2,7 +2,7 @@
 //!
 //! *This API requires the following crate features to be activated: `time_picker`*
 
-use chrono::{Local, Timelike};
+use chrono::{DateTime, FixedOffset, Local, NaiveTime, Timelike};
 use std::fmt::Display;
 
 /// The time value
@@ -190,12 +190,135 @@ impl From<chrono::NaiveTime> for Time {
     }
 }
 
+impl From<DateTime<FixedOffset>> for Time {
+    fn from(date_time: DateTime<FixedOffset>) -> Self {
+        date_time.time_of_day().into()
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<::time::OffsetDateTime> for Time {
+    fn from(date_time: ::time::OffsetDateTime) -> Self {
+        date_time.time_of_day().into()
+    }
+}
+
+/// A date and time with an offset from UTC whose time of day can be picked.
+pub trait OffsetTime: Copy {
+    /// Gets the time of day in the offset.
+    fn time_of_day(&self) -> NaiveTime;
+
+    /// Gets the offset from UTC.
+    fn utc_offset(&self) -> FixedOffset;
+
+    /// Replaces the time of day, keeping the date and the offset.
+    #[must_use]
+    fn with_time_of_day(self, time: NaiveTime) -> Self;
+}
+
+impl OffsetTime for DateTime<FixedOffset> {
+    fn time_of_day(&self) -> NaiveTime {
+        self.time()
+    }
+
+    fn utc_offset(&self) -> FixedOffset {
+        *self.offset()
+    }
+
+    fn with_time_of_day(self, time: NaiveTime) -> Self {
+        self.date_naive()
+            .and_time(time)
+            .and_local_timezone(*self.offset())
+            .single()
+            .expect("Time Conversion failed. A fixed offset has no ambiguous times.")
+    }
+}
+
+#[cfg(feature = "time")]
+impl OffsetTime for ::time::OffsetDateTime {
+    fn time_of_day(&self) -> NaiveTime {
+        NaiveTime::from_hms_nano_opt(
+            u32::from(self.hour()),
+            u32::from(self.minute()),
+            u32::from(self.second()),
+            self.nanosecond(),
+        )
+        .expect("Time Conversion failed. H, M, S or nanosecond was too large.")
+    }
+
+    fn utc_offset(&self) -> FixedOffset {
+        FixedOffset::east_opt(self.offset().whole_seconds())
+            .expect("Time Conversion failed. The offset was out of bounds.")
+    }
+
+    fn with_time_of_day(self, time: NaiveTime) -> Self {
+        let component = |value: u32| {
+            u8::try_from(value).expect("Time Conversion failed. H, M, or S was too large.")
+        };
+
+        self.replace_time(
+            ::time::Time::from_hms_nano(
+                component(time.hour()),
+                component(time.minute()),
+                component(time.second()),
+                time.nanosecond(),
+            )
+            .expect("Time Conversion failed. H, M, S or nanosecond was too large."),
+        )
+    }
+}
+
+/// Gets the latest time at or before the given time whose minute and second
+/// are multiples of the minute and second step.
+#[must_use]
+pub fn previous_step_time(time: NaiveTime, minute_step: u8, second_step: u8) -> NaiveTime {
+    let minute_step = u32::from(minute_step.max(1));
+    let second_step = u32::from(second_step.max(1));
+
+    let minute = time.minute() - time.minute() % minute_step;
+    let second = if minute == time.minute() {
+        time.second() - time.second() % second_step
+    } else {
+        59 - 59 % second_step
+    };
+
+    NaiveTime::from_hms_opt(time.hour(), minute, second).expect("Time Conversion failed")
+}
+
+/// Gets the earliest time at or after the given time whose minute and second
+/// are multiples of the minute and second step, or `None` if there is none
+/// before midnight.
+#[must_use]
+pub fn next_step_time(time: NaiveTime, minute_step: u8, second_step: u8) -> Option<NaiveTime> {
+    let minute_step = u32::from(minute_step.max(1));
+    let second_step = u32::from(second_step.max(1));
+
+    let second = time.second().div_ceil(second_step) * second_step;
+    let (minute, second) = if time.minute() % minute_step == 0 && second < 60 {
+        (time.minute(), second)
+    } else {
+        ((time.minute() + 1).div_ceil(minute_step) * minute_step, 0)
+    };
+
+    if minute < 60 {
+        NaiveTime::from_hms_opt(time.hour(), minute, second)
+    } else {
+        NaiveTime::from_hms_opt(time.hour() + 1, 0, 0)
+    }
+}
+
+/// Gets the offset from UTC as string, e.g. `UTC+02:00`.
+#[must_use]
+pub fn offset_as_string(offset: FixedOffset) -> String {
+    format!("UTC{offset}")
+}
+
 #[cfg(test)]
 
 mod tests {
-    use chrono::NaiveTime;
+    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, TimeZone};
 
-    use super::{Period, Time};
+    use super::{next_step_time, offset_as_string, previous_step_time, OffsetTime, Period, Time};
 
     #[test]
     fn time_to_naive() {
@@ -289,4 +412,107 @@ mod tests {
             NaiveTime::from_hms_opt(17, 52, 0).expect("Time Conversion failed")
         );
     }
+
+    #[test]
+    fn offset_date_time_test() {
+        let offset = FixedOffset::east_opt(2 * 3600).expect("Offset out of bounds");
+        let date_time: DateTime<FixedOffset> = offset
+            .with_ymd_and_hms(2024, 3, 31, 23, 30, 15)
+            .single()
+            .expect("Invalid date time");
+
+        assert_eq!(
+            date_time.time_of_day(),
+            NaiveTime::from_hms_opt(23, 30, 15).expect("Time Conversion failed")
+        );
+        assert_eq!(date_time.utc_offset(), offset);
+
+        let naive: NaiveTime = Time::from(date_time).into();
+        assert_eq!(
+            naive,
+            NaiveTime::from_hms_opt(23, 30, 15).expect("Time Conversion failed")
+        );
+
+        let picked = date_time
+            .with_time_of_day(NaiveTime::from_hms_opt(8, 5, 0).expect("Time Conversion failed"));
+        assert_eq!(
+            picked.date_naive(),
+            NaiveDate::from_ymd_opt(2024, 3, 31).expect("Invalid date")
+        );
+        assert_eq!(
+            picked.time(),
+            NaiveTime::from_hms_opt(8, 5, 0).expect("Time Conversion failed")
+        );
+        assert_eq!(picked.offset(), &offset);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_offset_date_time_test() {
+        let date_time = ::time::OffsetDateTime::new_in_offset(
+            ::time::Date::from_calendar_date(2024, ::time::Month::March, 31).expect("Invalid date"),
+            ::time::Time::from_hms(23, 30, 15).expect("Invalid time"),
+            ::time::UtcOffset::from_hms(-5, -30, 0).expect("Invalid offset"),
+        );
+
+        assert_eq!(
+            date_time.time_of_day(),
+            NaiveTime::from_hms_opt(23, 30, 15).expect("Time Conversion failed")
+        );
+        assert_eq!(
+            date_time.utc_offset(),
+            FixedOffset::west_opt(5 * 3600 + 30 * 60).expect("Offset out of bounds")
+        );
+
+        let picked = date_time
+            .with_time_of_day(NaiveTime::from_hms_opt(8, 5, 0).expect("Time Conversion failed"));
+        assert_eq!(picked.date(), date_time.date());
+        assert_eq!(
+            picked.time(),
+            ::time::Time::from_hms(8, 5, 0).expect("Invalid time")
+        );
+        assert_eq!(picked.offset(), date_time.offset());
+    }
+
+    #[test]
+    fn step_time_test() {
+        let time = |hour, minute, second| {
+            NaiveTime::from_hms_opt(hour, minute, second).expect("Time Conversion failed")
+        };
+
+        assert_eq!(previous_step_time(time(10, 7, 40), 5, 1), time(10, 5, 59));
+        assert_eq!(previous_step_time(time(10, 5, 40), 5, 15), time(10, 5, 30));
+        assert_eq!(previous_step_time(time(10, 7, 40), 15, 20), time(10, 0, 40));
+        assert_eq!(previous_step_time(time(10, 15, 0), 15, 1), time(10, 15, 0));
+
+        assert_eq!(next_step_time(time(10, 7, 40), 5, 1), Some(time(10, 10, 0)));
+        assert_eq!(
+            next_step_time(time(10, 5, 40), 5, 15),
+            Some(time(10, 5, 45))
+        );
+        assert_eq!(
+            next_step_time(time(10, 5, 50), 5, 15),
+            Some(time(10, 10, 0))
+        );
+        assert_eq!(next_step_time(time(10, 50, 0), 15, 1), Some(time(11, 0, 0)));
+        assert_eq!(
+            next_step_time(time(10, 15, 0), 15, 1),
+            Some(time(10, 15, 0))
+        );
+        assert_eq!(next_step_time(time(23, 50, 0), 15, 1), None);
+    }
+
+    #[test]
+    fn offset_as_string_test() {
+        assert_eq!(
+            offset_as_string(FixedOffset::east_opt(2 * 3600).expect("Offset out of bounds")),
+            "UTC+02:00"
+        );
+        assert_eq!(
+            offset_as_string(
+                FixedOffset::west_opt(5 * 3600 + 30 * 60).expect("Offset out of bounds")
+            ),
+            "UTC-05:30"
+        );
+    }
 }
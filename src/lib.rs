@@ -85,10 +85,26 @@ mod platform {
     #[cfg(feature = "color_picker")]
     pub use {crate::widgets::color_picker, color_picker::ColorPicker};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "hsv_picker")]
+    pub use {crate::widgets::hsv_picker, hsv_picker::HsvPicker};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "gradient_editor")]
+    pub use {crate::widgets::gradient_editor, gradient_editor::GradientEditor};
+
     #[doc(no_inline)]
     #[cfg(feature = "date_picker")]
     pub use {crate::widgets::date_picker, date_picker::DatePicker};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "calendar")]
+    pub use {crate::widgets::calendar, calendar::Calendar};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "date_time_picker")]
+    pub use {crate::widgets::date_time_picker, date_time_picker::DateTimePicker};
+
     #[doc(no_inline)]
     #[cfg(feature = "grid")]
     pub use crate::widgets::grid::{Grid, GridRow};
@@ -18,6 +18,9 @@ pub mod color_picker;
 #[cfg(feature = "date_picker")]
 pub mod date_picker;
 
+#[cfg(feature = "date_time_picker")]
+pub mod date_time_picker;
+
 #[cfg(feature = "tab_bar")]
 pub mod tab_bar;
 
@@ -27,6 +30,9 @@ pub mod time_picker;
 #[cfg(feature = "number_input")]
 pub mod number_input;
 
+#[cfg(feature = "typed_input")]
+pub mod typed_input;
+
 #[cfg(feature = "selection_list")]
 pub mod selection_list;
 
@@ -28,6 +28,11 @@ pub struct Style {
 
     /// The border color of the bars of the [`ColorPicker`](crate::widgets::ColorPicker).
     pub bar_border_color: Color,
+
+    /// The color of the border around the text input of the
+    /// [`ColorPicker`](crate::widgets::ColorPicker) if the typed text is not a
+    /// color.
+    pub error_color: Color,
 }
 
 /// The Catalog of a [`ColorPicker`](crate::widgets::ColorPicker).
@@ -68,6 +73,7 @@ pub fn primary(theme: &Theme, status: Status) -> Style {
         bar_border_radius: 5.0,
         bar_border_width: 1.0,
         bar_border_color: foreground.text,
+        error_color: palette.danger.base.color,
     };
 
     match status {
@@ -474,3 +474,180 @@ pub const YELLOW: Color = Color::from_rgb(1.0, 1.0, 0.0);
 
 /// Yellow Green <span style="color:yellowGreen">Color</span>.
 pub const YELLOW_GREEN: Color = Color::from_rgb(0.604, 0.804, 0.196);
+
+/// Looks up the color with the given name.
+///
+/// The lookup accepts the CSS color names as well as the names of the
+/// constants of this module, ignoring the case, spaces, hyphens and
+/// underscores (e.g. `"aliceblue"`, `"Alice Blue"` and `"ALICE_BLUE"`).
+#[must_use]
+pub fn from_name(name: &str) -> Option<Color> {
+    let name: String = name
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+
+    let color = match name.as_str() {
+        "transparent" => Color::TRANSPARENT,
+        "primary" => PRIMARY,
+        "secondary" => SECONDARY,
+        "success" => SUCCESS,
+        "danger" => DANGER,
+        "warning" => WARNING,
+        "info" => INFO,
+        "light" => LIGHT,
+        "dark" => DARK,
+        "aliceblue" => ALICE_BLUE,
+        "antiquewhite" => ANTIQUE_WHITE,
+        "aqua" => AQUA,
+        "aquamarine" => AQUAMARINE,
+        "azure" => AZURE,
+        "beige" => BEIGE,
+        "bisque" => BISQUE,
+        "black" => BLACK,
+        "blanchedalmond" => BLANCHED_ALMOND,
+        "blue" => BLUE,
+        "blueviolet" => BLUE_VIOLET,
+        "brown" => BROWN,
+        "burlywood" => BURLY_WOOD,
+        "cadetblue" => CADET_BLUE,
+        "chartreuse" => CHARTREUSE,
+        "chocolate" => CHOCOLATE,
+        "coral" => CORAL,
+        "cornflowerblue" => CORNFLOWER_BLUE,
+        "cornsilk" => CORNSILK,
+        "crimson" => CRIMSON,
+        "cyan" => CYAN,
+        "darkblue" => DARK_BLUE,
+        "darkcyan" => DARK_CYAN,
+        "darkgoldenrod" => DARK_GOLDEN_ROD,
+        "darkgray" => DARK_GRAY,
+        "darkgrey" => DARK_GREY,
+        "darkgreen" => DARK_GREEN,
+        "darkkhaki" => DARK_KHAKI,
+        "darkmagenta" => DARK_MAGENTA,
+        "darkolivegreen" => DARK_OLIVE_GREEN,
+        "darkorange" => DARK_ORANGE,
+        "darkorchid" => DARK_ORCHID,
+        "darkred" => DARK_RED,
+        "darksalmon" => DARK_SALMON,
+        "darkseagreen" => DARK_SEA_GREEN,
+        "darkslateblue" => DARK_SLATE_BLUE,
+        "darkslategray" => DARK_SLATE_GRAY,
+        "darkslategrey" => DARK_SLATE_GREY,
+        "darkturquoise" => DARK_TURQUOISE,
+        "darkviolet" => DARK_VIOLET,
+        "deeppink" => DEEP_PINK,
+        "deepskyblue" => DEEP_SKY_BLUE,
+        "dimgray" => DIM_GRAY,
+        "dimgrey" => DIM_GREY,
+        "dodgerblue" => DODGER_BLUE,
+        "firebrick" => FIRE_BRICK,
+        "floralwhite" => FLORAL_WHITE,
+        "forestgreen" => FOREST_GREEN,
+        "fuchsia" => FUCHSIA,
+        "gainsboro" => GAINSBORO,
+        "ghostwhite" => GHOST_WHITE,
+        "gold" => GOLD,
+        "goldenrod" => GOLDEN_ROD,
+        "gray" => GRAY,
+        "grey" => GREY,
+        "green" => GREEN,
+        "greenyellow" => GREEN_YELLOW,
+        "honeydew" => HONEY_DEW,
+        "hotpink" => HOT_PINK,
+        "indianred" => INDIAN_RED,
+        "indigo" => INDIGO,
+        "ivory" => IVORY,
+        "khaki" => KHAKI,
+        "lavender" => LAVENDER,
+        "lavenderblush" => LAVENDER_BLUSH,
+        "lawngreen" => LAWN_GREEN,
+        "lemonchiffon" => LEMON_CHIFFON,
+        "lightblue" => LIGHT_BLUE,
+        "lightcoral" => LIGHT_CORAL,
+        "lightcyan" => LIGHT_CYAN,
+        "lightgoldenrodyellow" => LIGHT_GOLDEN_ROD_YELLOW,
+        "lightgray" => LIGHT_GRAY,
+        "lightgrey" => LIGHT_GREY,
+        "lightgreen" => LIGHT_GREEN,
+        "lightpink" => LIGHT_PINK,
+        "lightsalmon" => LIGHT_SALMON,
+        "lightseagreen" => LIGHT_SEA_GREEN,
+        "lightskyblue" => LIGHT_SKY_BLUE,
+        "lightslategray" => LIGHT_SLATE_GRAY,
+        "lightslategrey" => LIGHT_SLATE_GREY,
+        "lightsteelblue" => LIGHT_STEEL_BLUE,
+        "lightyellow" => LIGHT_YELLOW,
+        "lime" => LIME,
+        "limegreen" => LIME_GREEN,
+        "linen" => LINEN,
+        "magenta" => MAGENTA,
+        "maroon" => MAROON,
+        "mediumaquamarine" => MEDIUM_AQUA_MARINE,
+        "mediumblue" => MEDIUM_BLUE,
+        "mediumorchid" => MEDIUM_ORCHID,
+        "mediumpurple" => MEDIUM_PURPLE,
+        "mediumseagreen" => MEDIUM_SEA_GREEN,
+        "mediumslateblue" => MEDIUM_SLATE_BLUE,
+        "mediumspringgreen" => MEDIUM_SPRING_GREEN,
+        "mediumturquoise" => MEDIUM_TURQUOISE,
+        "mediumvioletred" => MEDIUM_VIOLET_RED,
+        "midnightblue" => MIDNIGHT_BLUE,
+        "mintcream" => MINT_CREAM,
+        "mistyrose" => MISTY_ROSE,
+        "moccasin" => MOCCASIN,
+        "navajowhite" => NAVAJO_WHITE,
+        "navy" => NAVY,
+        "oldlace" => OLD_LACE,
+        "olive" => OLIVE,
+        "olivedrab" => OLIVE_DRAB,
+        "orange" => ORANGE,
+        "orangered" => ORANGE_RED,
+        "orchid" => ORCHID,
+        "palegoldenrod" => PALE_GOLDEN_ROD,
+        "palegreen" => PALE_GREEN,
+        "paleturquoise" => PALE_TURQUOISE,
+        "palevioletred" => PALE_VIOLET_RED,
+        "papayawhip" => PAPAYA_WHIP,
+        "peachpuff" => PEACH_PUFF,
+        "peru" => PERU,
+        "pink" => PINK,
+        "plum" => PLUM,
+        "powderblue" => POWDER_BLUE,
+        "purple" => PURPLE,
+        "rebeccapurple" => REBECCA_PURPLE,
+        "red" => RED,
+        "rosybrown" => ROSY_BROWN,
+        "royalblue" => ROYAL_BLUE,
+        "saddlebrown" => SADDLE_BROWN,
+        "salmon" => SALMON,
+        "sandybrown" => SANDY_BROWN,
+        "seagreen" => SEA_GREEN,
+        "seashell" => SEA_SHELL,
+        "sienna" => SIENNA,
+        "silver" => SILVER,
+        "skyblue" => SKY_BLUE,
+        "slateblue" => SLATE_BLUE,
+        "slategray" => SLATE_GRAY,
+        "slategrey" => SLATE_GREY,
+        "snow" => SNOW,
+        "springgreen" => SPRING_GREEN,
+        "steelblue" => STEEL_BLUE,
+        "tan" => TAN,
+        "teal" => TEAL,
+        "thistle" => THISTLE,
+        "tomato" => TOMATO,
+        "turquoise" => TURQUOISE,
+        "violet" => VIOLET,
+        "wheat" => WHEAT,
+        "white" => WHITE,
+        "whitesmoke" => WHITE_SMOKE,
+        "yellow" => YELLOW,
+        "yellowgreen" => YELLOW_GREEN,
+        _ => return None,
+    };
+
+    Some(color)
+}
@@ -29,6 +29,27 @@ pub struct Style {
     /// The background of the days in the calender of the
     /// [`DatePicker`](crate::widgets::DatePicker).
     pub day_background: Background,
+
+    /// The background of the days lying between the start and the end of a
+    /// selected range of the [`DatePicker`](crate::widgets::DatePicker).
+    pub range_background: Background,
+
+    /// The text color of the days lying between the start and the end of a
+    /// selected range of the [`DatePicker`](crate::widgets::DatePicker).
+    pub range_text_color: Color,
+
+    /// The color of the dots and counts marking days in the calendar of the
+    /// [`DatePicker`](crate::widgets::DatePicker).
+    pub marker_color: Color,
+
+    /// The background of the days tinted by a marker in the calendar of the
+    /// [`DatePicker`](crate::widgets::DatePicker).
+    pub marker_background: Background,
+
+    /// The color of the border around the text input of the
+    /// [`DatePicker`](crate::widgets::DatePicker) if the typed text is not a
+    /// date that can be picked.
+    pub error_color: Color,
 }
 
 /// The Catalog of a [`DatePicker`](crate::widgets::DatePicker).
@@ -72,23 +93,53 @@ pub fn primary(theme: &Theme, status: Status) -> Style {
             ..foreground.text
         },
         day_background: palette.background.base.color.into(),
+        range_background: Color {
+            a: 0.4,
+            ..palette.primary.base.color
+        }
+        .into(),
+        range_text_color: foreground.text,
+        marker_color: palette.primary.strong.color,
+        marker_background: Color {
+            a: 0.3,
+            ..palette.secondary.base.color
+        }
+        .into(),
+        error_color: palette.danger.base.color,
     };
 
     match status {
         Status::Selected => Style {
             day_background: palette.primary.strong.color.into(),
             text_color: palette.primary.strong.text,
+            marker_color: palette.primary.strong.text,
             ..base
         },
         Status::Hovered => Style {
             day_background: palette.primary.weak.color.into(),
             text_color: palette.primary.weak.text,
+            marker_color: palette.primary.weak.text,
             ..base
         },
         Status::Focused => Style {
             border_color: Color::from_rgb(0.5, 0.5, 0.5),
             ..base
         },
+        Status::Disabled => Style {
+            text_color: Color {
+                a: foreground.text.a * 0.25,
+                ..foreground.text
+            },
+            text_attenuated_color: Color {
+                a: foreground.text.a * 0.15,
+                ..foreground.text
+            },
+            marker_color: Color {
+                a: palette.primary.strong.color.a * 0.25,
+                ..palette.primary.strong.color
+            },
+            ..base
+        },
         _ => base,
     }
 }
@@ -0,0 +1,58 @@
+//! Use a date time picker as an input element for picking dates and times.
+//!
+//! *This API requires the following crate features to be activated: `date_time_picker`*
+#![allow(clippy::doc_markdown)]
+use super::{date_picker, time_picker, Status, StyleFn};
+use iced::Theme;
+
+/// The style of a [`DateTimePicker`](crate::widgets::DateTimePicker).
+///
+/// The background and the border of the
+/// [`DateTimePicker`](crate::widgets::DateTimePicker) are taken from the style
+/// of the calendar.
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The style of the calendar of the
+    /// [`DateTimePicker`](crate::widgets::DateTimePicker).
+    pub date_picker: date_picker::Style,
+
+    /// The style of the clock of the
+    /// [`DateTimePicker`](crate::widgets::DateTimePicker).
+    pub time_picker: time_picker::Style,
+}
+
+/// The Catalog of a [`DateTimePicker`](crate::widgets::DateTimePicker).
+pub trait Catalog {
+    ///Style for the trait to use.
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self, Style>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(primary)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The primary theme of a [`DateTimePicker`](crate::widgets::DateTimePicker),
+/// combining the primary themes of the
+/// [`DatePicker`](crate::widgets::DatePicker) and the
+/// [`TimePicker`](crate::widgets::TimePicker).
+#[must_use]
+pub fn primary(theme: &Theme, status: Status) -> Style {
+    Style {
+        date_picker: date_picker::primary(theme, status),
+        time_picker: time_picker::primary(theme, status),
+    }
+}
@@ -49,7 +49,11 @@ impl Catalog for Theme {
 
 /// The Extended Catalog of a [`NumberInput`](crate::widgets::number_input::NumberInput).
 pub trait ExtendedCatalog:
-    widget::text_input::Catalog + widget::container::Catalog + widget::text::Catalog + self::Catalog
+    widget::text_input::Catalog
+    + widget::container::Catalog
+    + widget::text::Catalog
+    + self::Catalog
+    + super::typed_input::Catalog
 {
     /// The default class produced by the [`Catalog`].
     #[must_use]
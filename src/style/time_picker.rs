@@ -102,6 +102,21 @@ pub fn primary(theme: &Theme, status: Status) -> Style {
             clock_number_background: palette.primary.strong.color,
             ..base
         },
+        Status::Disabled => Style {
+            text_color: Color {
+                a: foreground.text.a * 0.3,
+                ..foreground.text
+            },
+            clock_number_color: Color {
+                a: foreground.text.a * 0.3,
+                ..foreground.text
+            },
+            clock_dots_color: Color {
+                a: 0.3,
+                ..base.clock_dots_color
+            },
+            ..base
+        },
         _ => base,
     }
 }
@@ -0,0 +1,41 @@
+//! Display fields that can only be filled with a specific type.
+//!
+//! *This API requires the following crate features to be activated: `typed_input`*
+
+use iced::{
+    widget::text_input::{self, Status, Style},
+    Border, Theme,
+};
+
+/// The Catalog of a [`TypedInput`](crate::widgets::typed_input::TypedInput).
+pub trait Catalog: text_input::Catalog {
+    /// The default class of the input holding text that is not a valid value.
+    #[must_use]
+    fn default_error<'a>() -> <Self as text_input::Catalog>::Class<'a> {
+        <Self as text_input::Catalog>::default()
+    }
+}
+
+impl Catalog for Theme {
+    fn default_error<'a>() -> <Self as text_input::Catalog>::Class<'a> {
+        Box::new(error)
+    }
+}
+
+/// The error theme of a [`TypedInput`](crate::widgets::typed_input::TypedInput) holding text
+/// that is not a valid value.
+#[must_use]
+pub fn error(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    let base = text_input::default(theme, status);
+
+    Style {
+        border: Border {
+            color: palette.danger.base.color,
+            width: base.border.width.max(1.0),
+            ..base.border
+        },
+        value: palette.danger.strong.color,
+        ..base
+    }
+}
@@ -41,11 +41,31 @@ pub mod color_picker;
 #[cfg(feature = "color_picker")]
 pub use color_picker::ColorPicker;
 
+#[cfg(feature = "hsv_picker")]
+pub mod hsv_picker;
+#[cfg(feature = "hsv_picker")]
+pub use hsv_picker::HsvPicker;
+
+#[cfg(feature = "gradient_editor")]
+pub mod gradient_editor;
+#[cfg(feature = "gradient_editor")]
+pub use gradient_editor::GradientEditor;
+
 #[cfg(feature = "date_picker")]
 pub mod date_picker;
 #[cfg(feature = "date_picker")]
 pub use date_picker::DatePicker;
 
+#[cfg(feature = "calendar")]
+pub mod calendar;
+#[cfg(feature = "calendar")]
+pub use calendar::Calendar;
+
+#[cfg(feature = "date_time_picker")]
+pub mod date_time_picker;
+#[cfg(feature = "date_time_picker")]
+pub use date_time_picker::DateTimePicker;
+
 #[cfg(feature = "selection_list")]
 pub mod selection_list;
 #[cfg(feature = "selection_list")]
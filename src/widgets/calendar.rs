@@ -0,0 +1,540 @@
+//! Use a calendar embedded in the layout for picking dates.
+//!
+//! *This API requires the following crate features to be activated: `calendar`*
+
+use super::{
+    date_picker::{DayDecorator, Marker, OnSubmit, Restrictions},
+    overlay::date_picker::{self, CalendarOptions, Focus, View},
+};
+use crate::style::style_state::StyleState;
+
+use chrono::{NaiveDate, Weekday};
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        text::Renderer as _,
+        widget::{
+            self,
+            tree::{Tag, Tree},
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    keyboard,
+    mouse::{self, Cursor},
+    touch,
+    Border,
+    Element,
+    Event,
+    Length,
+    Padding,
+    Pixels,
+    Point,
+    Rectangle,
+    Renderer, // the actual type
+    Shadow,
+    Size,
+};
+
+pub use crate::{
+    core::date::{Date, Locale},
+    style::{date_picker::Style, Status, StyleFn},
+};
+
+/// The padding around the elements.
+const PADDING: f32 = 10.0;
+
+/// A calendar for picking dates that is embedded in the layout.
+///
+/// Unlike the [`DatePicker`](crate::DatePicker) it has no cancel and submit
+/// buttons, every picked date is published right away.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{Calendar, date_picker::Date};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Select(Date),
+/// }
+///
+/// let calendar = Calendar::new(Date::today(), Message::Select);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Calendar<'a, Message, Theme>
+where
+    Theme: crate::style::date_picker::Catalog,
+{
+    /// The selected date.
+    date: Date,
+    /// The end of the selected range if the [`Calendar`] is picking a range.
+    end_date: Option<Date>,
+    /// The function that produces a message when a date is picked.
+    on_select: OnSubmit<Message>,
+    /// The restrictions on the days that can be picked.
+    restrictions: Restrictions,
+    /// The style of the [`Calendar`].
+    class: <Theme as crate::style::date_picker::Catalog>::Class<'a>,
+    /// The font and icon size of the [`Calendar`] or `None` for the default
+    font_size: Option<Pixels>,
+    /// The locale of the month and weekday names of the [`Calendar`].
+    locale: Locale,
+    /// The first day of the week of the [`Calendar`].
+    first_weekday: Weekday,
+    /// Show the ISO week numbers in the [`Calendar`].
+    week_numbers: bool,
+    /// The function deciding which days get a [`Marker`] in the [`Calendar`].
+    day_decorator: Option<Box<DayDecorator>>,
+}
+
+impl<'a, Message, Theme> Calendar<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + crate::style::date_picker::Catalog
+        + iced::widget::button::Catalog
+        + iced::widget::text::Catalog
+        + iced::widget::container::Catalog,
+{
+    /// Creates a new [`Calendar`].
+    ///
+    /// It expects:
+    ///     * the selected date.
+    ///     * a function that will be called when a date is picked, which
+    ///         takes the picked [`Date`](crate::date_picker::Date) value.
+    pub fn new<F>(date: impl Into<Date>, on_select: F) -> Self
+    where
+        F: 'static + Fn(Date) -> Message,
+    {
+        Self {
+            date: date.into(),
+            end_date: None,
+            on_select: OnSubmit::Single(Box::new(on_select)),
+            restrictions: Restrictions::default(),
+            class: <Theme as crate::style::date_picker::Catalog>::default(),
+            font_size: None,
+            locale: Locale::default(),
+            first_weekday: Weekday::Mon,
+            week_numbers: false,
+            day_decorator: None,
+        }
+    }
+
+    /// Creates a new [`Calendar`] picking a range of dates.
+    ///
+    /// It expects:
+    ///     * the start date of the selected range.
+    ///     * the end date of the selected range.
+    ///     * a function that will be called when both ends of a range are
+    ///         picked, which takes the picked start and end
+    ///         [`Date`](crate::date_picker::Date) values.
+    pub fn new_range<F>(start: impl Into<Date>, end: impl Into<Date>, on_select: F) -> Self
+    where
+        F: 'static + Fn(Date, Date) -> Message,
+    {
+        Self {
+            date: start.into(),
+            end_date: Some(end.into()),
+            on_select: OnSubmit::Range(Box::new(on_select)),
+            restrictions: Restrictions::default(),
+            class: <Theme as crate::style::date_picker::Catalog>::default(),
+            font_size: None,
+            locale: Locale::default(),
+            first_weekday: Weekday::Mon,
+            week_numbers: false,
+            day_decorator: None,
+        }
+    }
+
+    /// Sets the style of the [`Calendar`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as crate::style::date_picker::Catalog>::Class<'a>: From<StyleFn<'a, Theme, Style>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme, Style>).into();
+        self
+    }
+
+    /// Sets the font and icon size of the [`Calendar`].
+    #[must_use]
+    pub fn font_size<P: Into<Pixels>>(mut self, size: P) -> Self {
+        self.font_size = Some(size.into());
+        self
+    }
+
+    /// Sets the locale of the month and weekday names of the [`Calendar`].
+    #[must_use]
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets the first day of the week of the [`Calendar`].
+    #[must_use]
+    pub fn first_weekday(mut self, first_weekday: Weekday) -> Self {
+        self.first_weekday = first_weekday;
+        self
+    }
+
+    /// Shows the ISO week numbers on the left of the days of the [`Calendar`].
+    #[must_use]
+    pub fn week_numbers(mut self) -> Self {
+        self.week_numbers = true;
+        self
+    }
+
+    /// Sets the earliest date that can be picked in the [`Calendar`].
+    #[must_use]
+    pub fn min_date(mut self, date: impl Into<Date>) -> Self {
+        self.restrictions.min_date = Some(date.into().into());
+        self
+    }
+
+    /// Sets the latest date that can be picked in the [`Calendar`].
+    #[must_use]
+    pub fn max_date(mut self, date: impl Into<Date>) -> Self {
+        self.restrictions.max_date = Some(date.into().into());
+        self
+    }
+
+    /// Sets the function deciding which days can not be picked in the
+    /// [`Calendar`], e.g. weekends or holidays.
+    #[must_use]
+    pub fn disable_day<F>(mut self, disable_day: F) -> Self
+    where
+        F: 'static + Fn(NaiveDate) -> bool,
+    {
+        self.restrictions.disable_day = Some(Box::new(disable_day));
+        self
+    }
+
+    /// Sets the function deciding which days of the [`Calendar`] get a
+    /// [`Marker`], e.g. the days having events.
+    #[must_use]
+    pub fn day_decorator<F>(mut self, day_decorator: F) -> Self
+    where
+        F: 'static + Fn(NaiveDate) -> Option<Marker>,
+    {
+        self.day_decorator = Some(Box::new(day_decorator));
+        self
+    }
+
+    /// Sets the class of the input of the [`Calendar`].
+    #[must_use]
+    pub fn class(
+        mut self,
+        class: impl Into<<Theme as crate::style::date_picker::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Gets the options of the calendar of the [`Calendar`].
+    fn options(&self, renderer: &Renderer) -> CalendarOptions<'_> {
+        CalendarOptions {
+            range: self.on_select.is_range(),
+            restrictions: &self.restrictions,
+            font_size: self.font_size.unwrap_or_else(|| renderer.default_size()),
+            locale: self.locale,
+            first_weekday: self.first_weekday,
+            week_numbers: self.week_numbers,
+            day_decorator: self.day_decorator.as_deref(),
+        }
+    }
+
+    /// Gets the selected date and range end given to the [`Calendar`].
+    fn selection(&self) -> (NaiveDate, Option<NaiveDate>) {
+        (self.date.into(), self.end_date.map(Into::into))
+    }
+}
+
+/// The state of the [`Calendar`].
+#[derive(Debug)]
+pub struct State {
+    /// The state of the calendar.
+    pub(crate) calendar_state: date_picker::State,
+    /// The date and range end last given to the [`Calendar`].
+    selection: (NaiveDate, Option<NaiveDate>),
+}
+
+impl State {
+    /// Creates a new [`State`] with the given date and optional range end.
+    #[must_use]
+    pub fn new(date: NaiveDate, end_date: Option<NaiveDate>) -> Self {
+        Self {
+            calendar_state: match end_date {
+                Some(end_date) => date_picker::State::with_range(date, end_date),
+                None => date_picker::State::new(date),
+            },
+            selection: (date, end_date),
+        }
+    }
+}
+
+impl<'a, Message, Theme> Widget<Message, Theme, Renderer> for Calendar<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + crate::style::date_picker::Catalog
+        + iced::widget::button::Catalog
+        + iced::widget::text::Catalog
+        + iced::widget::container::Catalog,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        let (date, end_date) = self.selection();
+        widget::tree::State::new(State::new(date, end_date))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state: &mut State = tree.state.downcast_mut();
+        let selection = self.selection();
+
+        // Only a changed selection of the application replaces the one of the calendar.
+        if state.selection != selection {
+            let (date, end_date) = selection;
+            let focus = state.calendar_state.focus;
+            let keyboard_modifiers = state.calendar_state.keyboard_modifiers;
+            let view = state.calendar_state.view;
+
+            *state = State::new(date, end_date);
+            state.calendar_state.focus = focus;
+            state.calendar_state.keyboard_modifiers = keyboard_modifiers;
+            state.calendar_state.view = view;
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits
+            .shrink(Padding::from(PADDING))
+            .width(Length::Shrink)
+            .height(Length::Shrink);
+
+        let element = date_picker::calendar_element::<Message, Theme>(&self.options(renderer));
+        let calendar_tree = if let Some(child_tree) = tree.children.first_mut() {
+            child_tree.diff(element.as_widget());
+            child_tree
+        } else {
+            tree.children.push(Tree::new(element.as_widget()));
+            &mut tree.children[0]
+        };
+
+        let calendar = element
+            .as_widget()
+            .layout(calendar_tree, renderer, &limits)
+            .move_to(Point::new(PADDING, PADDING));
+
+        Node::with_children(
+            Size::new(
+                calendar.bounds().width + (2.0 * PADDING),
+                calendar.bounds().height + (2.0 * PADDING),
+            ),
+            vec![calendar],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let options = self.options(renderer);
+        let state: &mut State = tree.state.downcast_mut();
+        let calendar_state = &mut state.calendar_state;
+
+        let previous_range = calendar_state.range();
+        let calendar_layout = layout
+            .children()
+            .next()
+            .expect("widgets: Layout should have a calendar layout");
+
+        // Only clicking a day or pressing Enter or Space on it selects a day,
+        // browsing the months, years and the focused day does not.
+        let picks_day = match &event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) => {
+                matches!(
+                    key,
+                    keyboard::key::Named::Enter | keyboard::key::Named::Space
+                ) && calendar_state.focus == Focus::Day
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                calendar_state.view == View::Days
+                    && calendar_layout
+                        .children()
+                        .nth(1)
+                        .is_some_and(|days_layout| cursor.is_over(days_layout.bounds()))
+            }
+            _ => false,
+        };
+
+        let status = match &event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if calendar_state.focus != Focus::None => match key {
+                keyboard::key::Named::Tab => {
+                    // The calendar has no buttons, so the focus only cycles through
+                    // the month, the year and the days.
+                    let shift = calendar_state.keyboard_modifiers.shift();
+                    calendar_state.focus = match (calendar_state.focus, shift) {
+                        (Focus::Month, false)
+                        | (Focus::Day | Focus::Months | Focus::Years, true) => Focus::Year,
+                        (Focus::Year, false) | (Focus::Month, true) => calendar_state.view.focus(),
+                        _ => Focus::Month,
+                    };
+                    event::Status::Captured
+                }
+                key => date_picker::on_event_calendar_keyboard(calendar_state, &options, *key),
+            },
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                calendar_state.keyboard_modifiers = *modifiers;
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if !cursor.is_over(layout.bounds()) =>
+            {
+                calendar_state.focus = Focus::None;
+                event::Status::Ignored
+            }
+            _ => date_picker::on_event_calendar(
+                calendar_state,
+                &options,
+                &event,
+                calendar_layout,
+                cursor,
+            ),
+        };
+
+        match &self.on_select {
+            OnSubmit::Single(on_select) => {
+                let is_picked = match &event {
+                    Event::Keyboard(_) => true,
+                    _ => status == event::Status::Captured,
+                };
+
+                if picks_day && is_picked {
+                    state.selection = (calendar_state.date, None);
+                    shell.publish(on_select(calendar_state.date.into()));
+                }
+            }
+            OnSubmit::Range(on_select) => {
+                let range = calendar_state.range();
+                if range != previous_range && calendar_state.range_end.is_some() {
+                    if let Some((start, end)) = range {
+                        state.selection = (start, Some(end));
+                        shell.publish(on_select(start.into(), end.into()));
+                    }
+                }
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state: &State = tree.state.downcast_ref();
+
+        date_picker::calendar_mouse_interaction(
+            &state.calendar_state,
+            &self.options(renderer),
+            layout
+                .children()
+                .next()
+                .expect("Graphics: Layout should have a calendar layout"),
+            cursor,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state: &State = tree.state.downcast_ref();
+        let options = self.options(renderer);
+        let bounds = layout.bounds();
+
+        let style_sheet = date_picker::style_sheet(theme, &self.class);
+
+        let style_state = if cursor.is_over(bounds) {
+            StyleState::Hovered
+        } else {
+            StyleState::Active
+        };
+
+        // Background
+        if (bounds.width > 0.) && (bounds.height > 0.) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: style_sheet[&style_state].border_radius.into(),
+                        width: style_sheet[&style_state].border_width,
+                        color: style_sheet[&style_state].border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                style_sheet[&style_state].background,
+            );
+        }
+
+        date_picker::draw_calendar(
+            renderer,
+            &state.calendar_state,
+            &options,
+            layout
+                .children()
+                .next()
+                .expect("Graphics: Layout should have a calendar layout"),
+            cursor,
+            &style_sheet,
+        );
+    }
+}
+
+impl<'a, Message, Theme> From<Calendar<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + crate::style::date_picker::Catalog
+        + iced::widget::button::Catalog
+        + iced::widget::text::Catalog
+        + iced::widget::container::Catalog,
+{
+    fn from(calendar: Calendar<'a, Message, Theme>) -> Self {
+        Element::new(calendar)
+    }
+}
@@ -27,6 +27,7 @@ use iced::{
     Vector,
 };
 
+pub use super::overlay::color_picker::ColorModel;
 pub use crate::style::{self, color_picker::Style};
 
 //TODO: Remove ignore when Null is updated. Temp fix for Test runs
@@ -57,7 +58,9 @@ pub use crate::style::{self, color_picker::Style};
 pub struct ColorPicker<'a, Message, Theme = iced::Theme>
 where
     Message: Clone,
-    Theme: style::color_picker::Catalog + iced::widget::button::Catalog,
+    Theme: style::color_picker::Catalog
+        + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog,
 {
     /// Show the picker.
     show_picker: bool,
@@ -69,10 +72,18 @@ where
     on_cancel: Message,
     /// The function that produces a message when the submit button of the [`ColorPickerOverlay`] is pressed.
     on_submit: Box<dyn Fn(Color) -> Message>,
+    /// The function that produces a message while the color of the [`ColorPickerOverlay`] is changed.
+    on_change: Option<Box<dyn Fn(Color) -> Message>>,
     /// The style of the [`ColorPickerOverlay`].
     class: <Theme as style::color_picker::Catalog>::Class<'a>,
     /// The buttons of the overlay.
     overlay_state: Element<'a, Message, Theme, Renderer>,
+    /// The color model initially shown by the bars of the [`ColorPickerOverlay`].
+    color_model: ColorModel,
+    /// The palette shown as swatches in the [`ColorPickerOverlay`].
+    palette: Vec<Color>,
+    /// The maximum number of recently submitted colors shown as swatches.
+    recent_colors: usize,
 }
 
 impl<'a, Message, Theme> ColorPicker<'a, Message, Theme>
@@ -81,6 +92,7 @@ where
     Theme: 'a
         + style::color_picker::Catalog
         + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog
         + iced::widget::text::Catalog,
 {
     /// Creates a new [`ColorPicker`] wrapping around the given underlay.
@@ -111,8 +123,12 @@ where
             underlay: underlay.into(),
             on_cancel,
             on_submit: Box::new(on_submit),
+            on_change: None,
             class: <Theme as style::color_picker::Catalog>::default(),
             overlay_state: ColorPickerOverlayButtons::default().into(),
+            color_model: ColorModel::default(),
+            palette: Vec::new(),
+            recent_colors: 0,
         }
     }
 
@@ -126,6 +142,49 @@ where
         self
     }
 
+    /// Sets the color model initially shown by the bars of the
+    /// [`ColorPicker`]. It can be switched in the overlay.
+    #[must_use]
+    pub fn color_model(mut self, color_model: ColorModel) -> Self {
+        self.color_model = color_model;
+        self
+    }
+
+    /// Sets the function that will be called while the color is changed in
+    /// the overlay of the [`ColorPicker`], by dragging, typing, picking a
+    /// swatch or through the keyboard.
+    ///
+    /// This allows previewing the color before it is submitted.
+    #[must_use]
+    pub fn on_change<F>(mut self, on_change: F) -> Self
+    where
+        F: 'static + Fn(Color) -> Message,
+    {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the palette shown as a row of swatches in the overlay of the
+    /// [`ColorPicker`], e.g. made of the [`colors`](crate::style::colors) or
+    /// of the colors of the [`Palette`](iced::theme::Palette) of the theme.
+    ///
+    /// Clicking a swatch picks its color.
+    #[must_use]
+    pub fn palette(mut self, palette: impl IntoIterator<Item = Color>) -> Self {
+        self.palette = palette.into_iter().collect();
+        self
+    }
+
+    /// Sets the maximum number of recently submitted colors shown as swatches
+    /// after the palette in the overlay of the [`ColorPicker`].
+    ///
+    /// The recent colors are kept in the state of the [`ColorPicker`].
+    #[must_use]
+    pub fn recent_colors(mut self, count: usize) -> Self {
+        self.recent_colors = count;
+        self
+    }
+
     /// Sets the class of the input of the [`ColorPicker`].
     #[must_use]
     pub fn class(
@@ -142,6 +201,8 @@ where
 pub struct State {
     /// The state of the overlay.
     pub(crate) overlay_state: color_picker::State,
+    /// The color last given to the [`ColorPicker`].
+    pub(crate) color: Color,
 }
 
 impl State {
@@ -150,6 +211,7 @@ impl State {
     pub fn new(color: Color) -> Self {
         Self {
             overlay_state: color_picker::State::new(color),
+            color,
         }
     }
 
@@ -157,6 +219,7 @@ impl State {
     pub fn reset(&mut self) {
         self.overlay_state.color = Color::from_rgb(0.5, 0.25, 0.25);
         self.overlay_state.color_bar_dragged = ColorBarDragged::None;
+        self.overlay_state.text = None;
     }
 }
 
@@ -166,6 +229,7 @@ where
     Theme: 'a
         + style::color_picker::Catalog
         + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog
         + iced::widget::text::Catalog,
 {
     fn tag(&self) -> Tag {
@@ -173,7 +237,9 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new(self.color))
+        let mut state = State::new(self.color);
+        state.overlay_state.color_model = self.color_model;
+        tree::State::new(state)
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -183,9 +249,18 @@ where
     fn diff(&self, tree: &mut Tree) {
         let color_picker_state = tree.state.downcast_mut::<State>();
 
-        if color_picker_state.overlay_state.color != self.color {
+        // While the overlay is shown only a changed color of the application
+        // replaces the picked one, so the picked color can be previewed. The
+        // typed text is kept if the application just passes the picked color
+        // back.
+        let color_changed = color_picker_state.color != self.color;
+        if (color_changed || !self.show_picker)
+            && color_picker_state.overlay_state.color != self.color
+        {
             color_picker_state.overlay_state.color = self.color;
+            color_picker_state.overlay_state.text = None;
         }
+        color_picker_state.color = self.color;
 
         tree.diff_children(&[&self.underlay, &self.overlay_state]);
     }
@@ -287,6 +362,9 @@ where
                 picker_state,
                 self.on_cancel.clone(),
                 &self.on_submit,
+                self.on_change.as_deref(),
+                &self.palette,
+                self.recent_colors,
                 position,
                 &self.class,
                 &mut state.children[1],
@@ -303,6 +381,7 @@ where
     Theme: 'a
         + style::color_picker::Catalog
         + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog
         + iced::widget::text::Catalog,
 {
     fn from(color_picker: ColorPicker<'a, Message, Theme>) -> Self {
@@ -2,9 +2,11 @@
 //!
 //! *This API requires the following crate features to be activated: `date_picker`*
 
-use super::overlay::date_picker::{self, DatePickerOverlay, DatePickerOverlayButtons};
+use super::overlay::date_picker::{
+    self, CalendarOptions, DatePickerOverlay, DatePickerOverlayButtons, View,
+};
 
-use chrono::Local;
+use chrono::{Local, NaiveDate, Weekday};
 use iced::{
     advanced::{
         layout::{Limits, Node},
@@ -30,7 +32,7 @@ use iced::{
 };
 
 pub use crate::{
-    core::date::Date,
+    core::date::{Date, Locale},
     style::{date_picker::Style, Status, StyleFn},
 };
 
@@ -62,18 +64,24 @@ pub use crate::{
 pub struct DatePicker<'a, Message, Theme>
 where
     Message: Clone,
-    Theme: crate::style::date_picker::Catalog + iced::widget::button::Catalog,
+    Theme: crate::style::date_picker::Catalog
+        + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog,
 {
     /// Show the picker.
     show_picker: bool,
     /// The date to show.
     date: Date,
+    /// The initial end of the range if the [`DatePicker`] is picking a range.
+    end_date: Option<Date>,
     /// The underlying element.
     underlay: Element<'a, Message, Theme, Renderer>,
     /// The message that is send if the cancel button of the [`DatePickerOverlay`] is pressed.
     on_cancel: Message,
     /// The function that produces a message when the submit button of the [`DatePickerOverlay`] is pressed.
-    on_submit: Box<dyn Fn(Date) -> Message>,
+    on_submit: OnSubmit<Message>,
+    /// The restrictions on the days that can be picked.
+    restrictions: Restrictions,
     /// The style of the [`DatePickerOverlay`].
     class: <Theme as crate::style::date_picker::Catalog>::Class<'a>,
     /// The buttons of the overlay.
@@ -81,6 +89,17 @@ where
     //button_style: <Renderer as button::Renderer>::Style, // clone not satisfied
     /// The font and icon size of the [`DatePickerOverlay`] or `None` for the default
     font_size: Option<Pixels>,
+    /// The locale of the month and weekday names of the [`DatePickerOverlay`].
+    locale: Locale,
+    /// The first day of the week of the [`DatePickerOverlay`].
+    first_weekday: Weekday,
+    /// Show the ISO week numbers in the [`DatePickerOverlay`].
+    week_numbers: bool,
+    /// The function deciding which days get a [`Marker`] in the [`DatePickerOverlay`].
+    day_decorator: Option<Box<DayDecorator>>,
+    /// The format of the dates typed into the [`DatePickerOverlay`] or `None`
+    /// if no text input is shown.
+    text_format: Option<String>,
 }
 
 impl<'a, Message, Theme> DatePicker<'a, Message, Theme>
@@ -90,6 +109,7 @@ where
         + crate::style::date_picker::Catalog
         + iced::widget::button::Catalog
         + iced::widget::text::Catalog
+        + iced::widget::text_input::Catalog
         + iced::widget::container::Catalog,
 {
     /// Creates a new [`DatePicker`] wrapping around the given underlay.
@@ -117,13 +137,64 @@ where
         Self {
             show_picker,
             date: date.into(),
+            end_date: None,
             underlay: underlay.into(),
             on_cancel,
-            on_submit: Box::new(on_submit),
+            on_submit: OnSubmit::Single(Box::new(on_submit)),
+            restrictions: Restrictions::default(),
             class: <Theme as crate::style::date_picker::Catalog>::default(),
             overlay_state: DatePickerOverlayButtons::default().into(),
             //button_style: <Renderer as button::Renderer>::Style::default(),
             font_size: None,
+            locale: Locale::default(),
+            first_weekday: Weekday::Mon,
+            week_numbers: false,
+            day_decorator: None,
+            text_format: None,
+        }
+    }
+
+    /// Creates a new [`DatePicker`] picking a range of dates wrapping around
+    /// the given underlay.
+    ///
+    /// It expects:
+    ///     * if the overlay of the date picker is visible.
+    ///     * the initial start date of the range.
+    ///     * the initial end date of the range.
+    ///     * the underlay [`Element`] on which this [`DatePicker`]
+    ///         will be wrapped around.
+    ///     * a message that will be send when the cancel button of the [`DatePicker`]
+    ///         is pressed.
+    ///     * a function that will be called when the submit button of the [`DatePicker`]
+    ///         is pressed, which takes the picked start and end [`Date`](crate::date_picker::Date) values.
+    pub fn new_range<U, F>(
+        show_picker: bool,
+        start: impl Into<Date>,
+        end: impl Into<Date>,
+        underlay: U,
+        on_cancel: Message,
+        on_submit: F,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Theme, Renderer>>,
+        F: 'static + Fn(Date, Date) -> Message,
+    {
+        Self {
+            show_picker,
+            date: start.into(),
+            end_date: Some(end.into()),
+            underlay: underlay.into(),
+            on_cancel,
+            on_submit: OnSubmit::Range(Box::new(on_submit)),
+            restrictions: Restrictions::default(),
+            class: <Theme as crate::style::date_picker::Catalog>::default(),
+            overlay_state: DatePickerOverlayButtons::default().into(),
+            font_size: None,
+            locale: Locale::default(),
+            first_weekday: Weekday::Mon,
+            week_numbers: false,
+            day_decorator: None,
+            text_format: None,
         }
     }
 
@@ -144,6 +215,76 @@ where
         self
     }
 
+    /// Sets the locale of the month and weekday names of the [`DatePicker`].
+    #[must_use]
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets the first day of the week of the [`DatePicker`].
+    #[must_use]
+    pub fn first_weekday(mut self, first_weekday: Weekday) -> Self {
+        self.first_weekday = first_weekday;
+        self
+    }
+
+    /// Shows the ISO week numbers on the left of the days of the [`DatePicker`].
+    #[must_use]
+    pub fn week_numbers(mut self) -> Self {
+        self.week_numbers = true;
+        self
+    }
+
+    /// Sets the earliest date that can be picked in the [`DatePicker`].
+    #[must_use]
+    pub fn min_date(mut self, date: impl Into<Date>) -> Self {
+        self.restrictions.min_date = Some(date.into().into());
+        self
+    }
+
+    /// Sets the latest date that can be picked in the [`DatePicker`].
+    #[must_use]
+    pub fn max_date(mut self, date: impl Into<Date>) -> Self {
+        self.restrictions.max_date = Some(date.into().into());
+        self
+    }
+
+    /// Sets the function deciding which days can not be picked in the
+    /// [`DatePicker`], e.g. weekends or holidays.
+    #[must_use]
+    pub fn disable_day<F>(mut self, disable_day: F) -> Self
+    where
+        F: 'static + Fn(NaiveDate) -> bool,
+    {
+        self.restrictions.disable_day = Some(Box::new(disable_day));
+        self
+    }
+
+    /// Sets the function deciding which days of the [`DatePicker`] get a
+    /// [`Marker`], e.g. the days having events.
+    #[must_use]
+    pub fn day_decorator<F>(mut self, day_decorator: F) -> Self
+    where
+        F: 'static + Fn(NaiveDate) -> Option<Marker>,
+    {
+        self.day_decorator = Some(Box::new(day_decorator));
+        self
+    }
+
+    /// Shows a text input above the calendar of the [`DatePicker`] in which
+    /// the date can be typed in the given
+    /// [`chrono` format](chrono::format::strftime), e.g. `"%Y-%m-%d"`.
+    ///
+    /// The text input is kept in sync with the calendar and is highlighted
+    /// while its text is not a date that can be picked. It is not shown when
+    /// picking a range of dates.
+    #[must_use]
+    pub fn text_input(mut self, format: impl Into<String>) -> Self {
+        self.text_format = Some(format.into());
+        self
+    }
+
     /// Sets the class of the input of the [`DatePicker`].
     #[must_use]
     pub fn class(
@@ -155,6 +296,100 @@ where
     }
 }
 
+/// The function that produces a message when the submit button of the
+/// [`DatePickerOverlay`] is pressed.
+#[allow(missing_debug_implementations)]
+pub enum OnSubmit<Message> {
+    /// A single date is picked.
+    Single(Box<dyn Fn(Date) -> Message>),
+    /// A range of dates is picked, given as its start and end.
+    Range(Box<dyn Fn(Date, Date) -> Message>),
+}
+
+impl<Message> OnSubmit<Message> {
+    /// Returns `true` if a range of dates is picked.
+    #[must_use]
+    pub const fn is_range(&self) -> bool {
+        matches!(self, Self::Range(_))
+    }
+}
+
+/// A marker drawn in a day of the [`DatePicker`].
+///
+/// The colors are taken from the [`Style`] of the [`DatePicker`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Marker {
+    /// A dot below the number of the day in the marker color.
+    Dot,
+    /// A count, e.g. of events, in the upper right corner of the day in the
+    /// marker color.
+    Count(u32),
+    /// A tint of the background of the day with the marker background.
+    Tint,
+}
+
+/// The function deciding which days get a [`Marker`].
+pub type DayDecorator = dyn Fn(NaiveDate) -> Option<Marker>;
+
+/// The restrictions on the days that can be picked in the [`DatePicker`].
+#[allow(missing_debug_implementations)]
+#[derive(Default)]
+pub struct Restrictions {
+    /// The earliest date that can be picked.
+    pub(crate) min_date: Option<NaiveDate>,
+    /// The latest date that can be picked.
+    pub(crate) max_date: Option<NaiveDate>,
+    /// The function returning `true` for days that can not be picked.
+    pub(crate) disable_day: Option<Box<dyn Fn(NaiveDate) -> bool>>,
+}
+
+impl Restrictions {
+    /// Checks if the given day can not be picked.
+    #[must_use]
+    pub fn is_disabled(&self, day: NaiveDate) -> bool {
+        self.min_date.is_some_and(|min_date| day < min_date)
+            || self.max_date.is_some_and(|max_date| day > max_date)
+            || self
+                .disable_day
+                .as_ref()
+                .is_some_and(|disable_day| disable_day(day))
+    }
+
+    /// Checks if no day from the given first to the given last day can be
+    /// picked because of the earliest and the latest date.
+    #[must_use]
+    pub fn is_outside(&self, first: NaiveDate, last: NaiveDate) -> bool {
+        self.min_date.is_some_and(|min_date| last < min_date)
+            || self.max_date.is_some_and(|max_date| first > max_date)
+    }
+
+    /// Steps from the given day with the given function over all days that
+    /// can not be picked. Stays on the given day if no day can be picked.
+    #[must_use]
+    pub fn step(&self, day: NaiveDate, step: impl Fn(NaiveDate) -> NaiveDate) -> NaiveDate {
+        crate::core::date::next_enabled(day, step, |day| self.is_disabled(day)).unwrap_or(day)
+    }
+
+    /// Gets the day closest to the given day that can be picked, preferring
+    /// the following days.
+    #[must_use]
+    pub fn nearest(&self, day: NaiveDate) -> NaiveDate {
+        let day = self.min_date.map_or(day, |min_date| day.max(min_date));
+        let day = self.max_date.map_or(day, |max_date| day.min(max_date));
+
+        if !self.is_disabled(day) {
+            return day;
+        }
+
+        let is_disabled = |day| self.is_disabled(day);
+        crate::core::date::next_enabled(day, crate::core::date::succ_day, is_disabled)
+            .or_else(|| {
+                crate::core::date::next_enabled(day, crate::core::date::pred_day, is_disabled)
+            })
+            .unwrap_or(day)
+    }
+}
+
 /// The state of the [`DatePicker`] / [`DatePickerOverlay`].
 #[derive(Debug)]
 pub struct State {
@@ -179,9 +414,22 @@ impl State {
         }
     }
 
-    /// Resets the date of the state to the current date.
+    /// Creates a new [`State`] with the given range of dates.
+    #[must_use]
+    pub fn with_range(start: Date, end: Date) -> Self {
+        Self {
+            overlay_state: date_picker::State::with_range(start.into(), end.into()),
+        }
+    }
+
+    /// Resets the date of the state to the current date and clears the
+    /// selected range.
     pub fn reset(&mut self) {
         self.overlay_state.date = Local::now().naive_local().date();
+        self.overlay_state.range_start = None;
+        self.overlay_state.range_end = None;
+        self.overlay_state.text = None;
+        self.overlay_state.view = View::Days;
     }
 }
 
@@ -191,6 +439,7 @@ where
     Theme: crate::style::date_picker::Catalog
         + iced::widget::button::Catalog
         + iced::widget::text::Catalog
+        + iced::widget::text_input::Catalog
         + iced::widget::container::Catalog,
 {
     fn tag(&self) -> Tag {
@@ -198,7 +447,10 @@ where
     }
 
     fn state(&self) -> widget::tree::State {
-        widget::tree::State::new(State::new(self.date))
+        widget::tree::State::new(match self.end_date {
+            Some(end_date) => State::with_range(self.date, end_date),
+            None => State::new(self.date),
+        })
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -309,7 +561,16 @@ where
                 position,
                 &self.class,
                 &mut state.children[1],
-                self.font_size.unwrap_or_else(|| renderer.default_size()),
+                CalendarOptions {
+                    range: self.on_submit.is_range(),
+                    restrictions: &self.restrictions,
+                    font_size: self.font_size.unwrap_or_else(|| renderer.default_size()),
+                    locale: self.locale,
+                    first_weekday: self.first_weekday,
+                    week_numbers: self.week_numbers,
+                    day_decorator: self.day_decorator.as_deref(),
+                },
+                self.text_format.as_deref(),
             )
             .overlay(),
         )
@@ -324,6 +585,7 @@ where
         + crate::style::date_picker::Catalog
         + iced::widget::button::Catalog
         + iced::widget::text::Catalog
+        + iced::widget::text_input::Catalog
         + iced::widget::container::Catalog,
 {
     fn from(date_picker: DatePicker<'a, Message, Theme>) -> Self {
@@ -0,0 +1,484 @@
+//! Use a date time picker as an input element for picking dates and times.
+//!
+//! *This API requires the following crate features to be activated: `date_time_picker`*
+
+use super::overlay::{
+    date_picker::CalendarOptions,
+    date_time_picker::{self, DateTimePickerOverlay, DateTimePickerOverlayButtons},
+};
+
+use chrono::{NaiveDate, NaiveDateTime, Weekday};
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay, renderer,
+        text::Renderer as _,
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    widget::{button, container, text},
+    Element,
+    Event,
+    Length,
+    Pixels,
+    Point,
+    Rectangle,
+    Renderer, // the actual type
+    Size,
+    Vector,
+};
+
+pub use crate::{
+    core::{date::Locale, time::Time},
+    date_picker::{Date, DayDecorator, Marker},
+    style::{
+        date_time_picker::{Catalog, Style},
+        Status, StyleFn,
+    },
+};
+
+//TODO: Remove ignore when Null is updated. Temp fix for Test runs
+/// An input element for picking a date together with a time.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::DateTimePicker;
+/// # use iced::widget::{button, Button, Text};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Open,
+///     Cancel,
+///     Submit(chrono::NaiveDateTime),
+/// }
+///
+/// let date_time_picker = DateTimePicker::new(
+///     true,
+///     chrono::Local::now().naive_local(),
+///     Button::new(Text::new("Pick date and time"))
+///         .on_press(Message::Open),
+///     Message::Cancel,
+///     Message::Submit,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DateTimePicker<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog + button::Catalog,
+{
+    /// Show the picker.
+    show_picker: bool,
+    /// The date and time to show.
+    date_time: NaiveDateTime,
+    /// The underlying element.
+    underlay: Element<'a, Message, Theme, Renderer>,
+    /// The message that is send if the cancel button of the [`DateTimePickerOverlay`] is pressed.
+    on_cancel: Message,
+    /// The function that produces a message when the submit button of the [`DateTimePickerOverlay`] is pressed.
+    on_submit: Box<dyn Fn(NaiveDateTime) -> Message>,
+    /// The style of the [`DateTimePickerOverlay`].
+    class: <Theme as Catalog>::Class<'a>,
+    /// The buttons of the overlay.
+    overlay_state: Element<'a, Message, Theme, Renderer>,
+    /// The font size of the calendar of the [`DateTimePickerOverlay`].
+    font_size: Option<Pixels>,
+    /// The locale of the month and weekday names of the calendar.
+    locale: Locale,
+    /// The first day of the week of the calendar.
+    first_weekday: Weekday,
+    /// Toggle the ISO week numbers of the calendar.
+    week_numbers: bool,
+    /// The function deciding which days of the calendar get a [`Marker`].
+    day_decorator: Option<Box<DayDecorator>>,
+    /// The restrictions on the dates that can be picked.
+    date_restrictions: crate::date_picker::Restrictions,
+    /// Toggle the use of the 24h clock of the [`DateTimePickerOverlay`].
+    use_24h: bool,
+    /// Toggle the use of the seconds of the [`DateTimePickerOverlay`].
+    show_seconds: bool,
+    /// The step in minutes the minutes of the [`DateTimePickerOverlay`] can be picked in.
+    minute_step: u8,
+    /// The step in seconds the seconds of the [`DateTimePickerOverlay`] can be picked in.
+    second_step: u8,
+    /// The restrictions on the times that can be picked.
+    time_restrictions: crate::time_picker::Restrictions,
+}
+
+impl<'a, Message, Theme> DateTimePicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a + Catalog + button::Catalog + text::Catalog,
+{
+    /// Creates a new [`DateTimePicker`] wrapping around the given underlay.
+    ///
+    /// It expects:
+    ///     * if the overlay of the date time picker is visible.
+    ///     * the initial date and time to show.
+    ///     * the underlay [`Element`] on which this [`DateTimePicker`]
+    ///         will be wrapped around.
+    ///     * a message that will be send when the cancel button of the [`DateTimePicker`]
+    ///         is pressed.
+    ///     * a function that will be called when the submit button of the [`DateTimePicker`]
+    ///         is pressed, which takes the picked date and time.
+    pub fn new<U, F>(
+        show_picker: bool,
+        date_time: NaiveDateTime,
+        underlay: U,
+        on_cancel: Message,
+        on_submit: F,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Theme, Renderer>>,
+        F: 'static + Fn(NaiveDateTime) -> Message,
+    {
+        Self {
+            show_picker,
+            date_time,
+            underlay: underlay.into(),
+            on_cancel,
+            on_submit: Box::new(on_submit),
+            class: <Theme as Catalog>::default(),
+            overlay_state: DateTimePickerOverlayButtons::default().into(),
+            font_size: None,
+            locale: Locale::default(),
+            first_weekday: Weekday::Mon,
+            week_numbers: false,
+            day_decorator: None,
+            date_restrictions: crate::date_picker::Restrictions::default(),
+            use_24h: false,
+            show_seconds: false,
+            minute_step: 1,
+            second_step: 1,
+            time_restrictions: crate::time_picker::Restrictions::default(),
+        }
+    }
+
+    /// Sets the style of the [`DateTimePicker`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme, Style>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme, Style>).into();
+        self
+    }
+
+    /// Sets the font and icon size of the calendar of the [`DateTimePicker`].
+    #[must_use]
+    pub fn font_size<P: Into<Pixels>>(mut self, size: P) -> Self {
+        self.font_size = Some(size.into());
+        self
+    }
+
+    /// Sets the locale of the month and weekday names of the [`DateTimePicker`].
+    #[must_use]
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets the first day of the week of the [`DateTimePicker`].
+    #[must_use]
+    pub fn first_weekday(mut self, first_weekday: Weekday) -> Self {
+        self.first_weekday = first_weekday;
+        self
+    }
+
+    /// Shows the ISO week numbers on the left of the days of the [`DateTimePicker`].
+    #[must_use]
+    pub fn week_numbers(mut self) -> Self {
+        self.week_numbers = true;
+        self
+    }
+
+    /// Sets the earliest date that can be picked in the [`DateTimePicker`].
+    #[must_use]
+    pub fn min_date(mut self, date: impl Into<Date>) -> Self {
+        self.date_restrictions.min_date = Some(date.into().into());
+        self
+    }
+
+    /// Sets the latest date that can be picked in the [`DateTimePicker`].
+    #[must_use]
+    pub fn max_date(mut self, date: impl Into<Date>) -> Self {
+        self.date_restrictions.max_date = Some(date.into().into());
+        self
+    }
+
+    /// Sets the function deciding which days can not be picked in the
+    /// [`DateTimePicker`], e.g. weekends or holidays.
+    #[must_use]
+    pub fn disable_day<F>(mut self, disable_day: F) -> Self
+    where
+        F: 'static + Fn(NaiveDate) -> bool,
+    {
+        self.date_restrictions.disable_day = Some(Box::new(disable_day));
+        self
+    }
+
+    /// Sets the function deciding which days of the [`DateTimePicker`] get a
+    /// [`Marker`], e.g. the days having events.
+    #[must_use]
+    pub fn day_decorator<F>(mut self, day_decorator: F) -> Self
+    where
+        F: 'static + Fn(NaiveDate) -> Option<Marker>,
+    {
+        self.day_decorator = Some(Box::new(day_decorator));
+        self
+    }
+
+    /// Use 24 hour format instead of AM/PM.
+    #[must_use]
+    pub fn use_24h(mut self) -> Self {
+        self.use_24h = true;
+        self
+    }
+
+    /// Enables the picker to also pick seconds.
+    #[must_use]
+    pub fn show_seconds(mut self) -> Self {
+        self.show_seconds = true;
+        self
+    }
+
+    /// Sets the step in minutes the minutes of the [`DateTimePicker`] can be picked in,
+    /// e.g. 5, 15 or 30. The step should be a divisor of 60.
+    #[must_use]
+    pub fn minute_step(mut self, step: u8) -> Self {
+        self.minute_step = step.clamp(1, 60);
+        self.time_restrictions.minute_step = self.minute_step;
+        self
+    }
+
+    /// Sets the step in seconds the seconds of the [`DateTimePicker`] can be picked in,
+    /// e.g. 5, 15 or 30. The step should be a divisor of 60.
+    #[must_use]
+    pub fn second_step(mut self, step: u8) -> Self {
+        self.second_step = step.clamp(1, 60);
+        self.time_restrictions.second_step = self.second_step;
+        self
+    }
+
+    /// Sets the earliest time of the day that can be picked in the [`DateTimePicker`].
+    #[must_use]
+    pub fn min_time(mut self, time: impl Into<Time>) -> Self {
+        self.time_restrictions.min_time = Some(time.into().into());
+        self
+    }
+
+    /// Sets the latest time of the day that can be picked in the [`DateTimePicker`].
+    #[must_use]
+    pub fn max_time(mut self, time: impl Into<Time>) -> Self {
+        self.time_restrictions.max_time = Some(time.into().into());
+        self
+    }
+
+    /// Blocks the times of the day from the start up to, but excluding, the end
+    /// from being picked in the [`DateTimePicker`], e.g. a lunch break.
+    #[must_use]
+    pub fn block_interval(mut self, start: impl Into<Time>, end: impl Into<Time>) -> Self {
+        self.time_restrictions
+            .blocked
+            .push((start.into().into(), end.into().into()));
+        self
+    }
+
+    /// Sets the class of the input of the [`DateTimePicker`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// The state of the [`DateTimePicker`] / [`DateTimePickerOverlay`].
+#[derive(Debug)]
+pub struct State {
+    /// The state of the overlay.
+    pub(crate) overlay_state: date_time_picker::State,
+}
+
+impl State {
+    /// Creates a new [`State`] with the current date and time.
+    #[must_use]
+    pub fn now() -> Self {
+        Self {
+            overlay_state: date_time_picker::State::default(),
+        }
+    }
+
+    /// Creates a new [`State`] with the given date and time.
+    #[must_use]
+    pub fn new(date_time: NaiveDateTime, use_24h: bool, show_seconds: bool) -> Self {
+        Self {
+            overlay_state: date_time_picker::State::new(date_time, use_24h, show_seconds),
+        }
+    }
+
+    /// Resets the date and the time of the state to the current date and time.
+    pub fn reset(&mut self) {
+        self.overlay_state.reset();
+    }
+}
+
+impl<'a, Message, Theme> Widget<Message, Theme, Renderer> for DateTimePicker<'a, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: Catalog + button::Catalog + text::Catalog + container::Catalog,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let mut state = State::new(self.date_time, self.use_24h, self.show_seconds);
+        state
+            .overlay_state
+            .time_state
+            .set_clock(None, self.minute_step, self.second_step);
+        state.overlay_state.time_state.align_to_steps();
+        tree::State::new(state)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.underlay), Tree::new(&self.overlay_state)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.state
+            .downcast_mut::<State>()
+            .overlay_state
+            .time_state
+            .set_clock(None, self.minute_step, self.second_step);
+        tree.diff_children(&[&self.underlay, &self.overlay_state]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.underlay.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.underlay
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.underlay.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let picker_state: &mut State = state.state.downcast_mut();
+
+        if !self.show_picker {
+            return self.underlay.as_widget_mut().overlay(
+                &mut state.children[0],
+                layout,
+                renderer,
+                translation,
+            );
+        }
+
+        let bounds = layout.bounds();
+        let position = Point::new(bounds.center_x(), bounds.center_y());
+
+        Some(
+            DateTimePickerOverlay::new(
+                picker_state,
+                self.on_cancel.clone(),
+                &self.on_submit,
+                position,
+                &self.class,
+                &mut state.children[1],
+                CalendarOptions {
+                    range: false,
+                    restrictions: &self.date_restrictions,
+                    font_size: self.font_size.unwrap_or_else(|| renderer.default_size()),
+                    locale: self.locale,
+                    first_weekday: self.first_weekday,
+                    week_numbers: self.week_numbers,
+                    day_decorator: self.day_decorator.as_deref(),
+                },
+                &self.time_restrictions,
+            )
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Theme> From<DateTimePicker<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'static + Clone,
+    Theme: 'a + Catalog + button::Catalog + text::Catalog + container::Catalog,
+{
+    fn from(date_time_picker: DateTimePicker<'a, Message, Theme>) -> Self {
+        Element::new(date_time_picker)
+    }
+}
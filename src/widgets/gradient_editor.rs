@@ -0,0 +1,896 @@
+//! Use a gradient editor for authoring linear gradients.
+//!
+//! *This API requires the following crate features to be activated: `gradient_editor`*
+
+use super::overlay::color_picker::{
+    draw_hue, draw_sat_value, hue_at, sat_value_at, HUE_STEP, SAT_VALUE_STEP,
+};
+use crate::core::color::{gradient_color_at, Hsv};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        text::Renderer as _,
+        widget::{
+            self,
+            tree::{Tag, Tree},
+        },
+        Clipboard, Layout, Renderer as _, Shell, Text, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    gradient::{ColorStop, Linear},
+    keyboard,
+    mouse::{self, Cursor},
+    touch,
+    widget::{canvas, text},
+    Background,
+    Border,
+    Color,
+    Element,
+    Event,
+    Gradient,
+    Length,
+    Point,
+    Radians,
+    Rectangle,
+    Renderer, // the actual type
+    Shadow,
+    Size,
+};
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+pub use crate::style::{color_picker::Style, Status, StyleFn};
+
+/// The spacing between the elements.
+const SPACING: f32 = 10.0;
+/// The height of the bar showing the gradient.
+const BAR_HEIGHT: f32 = 24.0;
+/// The width and height of the handle of a color stop.
+const HANDLE_SIZE: f32 = 12.0;
+/// The height of the saturation and value area and the size of the angle dial.
+const AREA_SIZE: f32 = 120.0;
+/// The height of the hue bar.
+const HUE_HEIGHT: f32 = 20.0;
+/// The maximum number of color stops of a [`Linear`] gradient.
+const MAX_STOPS: usize = 8;
+/// The minimum number of color stops kept when removing stops.
+const MIN_STOPS: usize = 2;
+/// The step value of the keyboard change of the offset of a color stop.
+const OFFSET_STEP: f32 = 0.01;
+/// The distance a color stop is nudged away from another stop at its offset.
+const OFFSET_NUDGE: f32 = 0.001;
+/// The step value of the keyboard and scroll change of the angle in degrees.
+const ANGLE_STEP: f32 = 1.0;
+
+/// An editor for linear gradients.
+///
+/// The color stops are dragged along the bar showing the gradient. Clicking
+/// the bar adds a stop, a right click on a stop or pressing delete removes the
+/// selected stop. The color of the selected stop is edited with the
+/// saturation/value area and the hue bar, the angle with the dial.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::GradientEditor;
+/// # use iced::{gradient::Linear, Color, Radians};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     GradientChanged(Linear),
+/// }
+///
+/// let gradient = Linear::new(Radians(0.0))
+///     .add_stop(0.0, Color::BLACK)
+///     .add_stop(1.0, Color::WHITE);
+///
+/// let gradient_editor = GradientEditor::new(gradient, Message::GradientChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct GradientEditor<'a, Message, Theme = iced::Theme>
+where
+    Theme: crate::style::color_picker::Catalog,
+{
+    /// The edited gradient.
+    gradient: Linear,
+    /// The function that produces a message when the gradient is changed.
+    on_change: Box<dyn Fn(Linear) -> Message>,
+    /// The width of the [`GradientEditor`].
+    width: Length,
+    /// The style of the [`GradientEditor`].
+    class: <Theme as crate::style::color_picker::Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme> GradientEditor<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: crate::style::color_picker::Catalog,
+{
+    /// Creates a new [`GradientEditor`].
+    ///
+    /// It expects:
+    ///     * the edited gradient.
+    ///     * a function that will be called while the gradient is changed,
+    ///         which takes the changed [`Linear`] gradient.
+    pub fn new<F>(gradient: Linear, on_change: F) -> Self
+    where
+        F: 'static + Fn(Linear) -> Message,
+    {
+        Self {
+            gradient,
+            on_change: Box::new(on_change),
+            width: Length::Fill,
+            class: <Theme as crate::style::color_picker::Catalog>::default(),
+        }
+    }
+
+    /// Sets the width of the [`GradientEditor`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the style of the [`GradientEditor`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as crate::style::color_picker::Catalog>::Class<'a>: From<StyleFn<'a, Theme, Style>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme, Style>).into();
+        self
+    }
+
+    /// Sets the class of the input of the [`GradientEditor`].
+    #[must_use]
+    pub fn class(
+        mut self,
+        class: impl Into<<Theme as crate::style::color_picker::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// The state of the [`GradientEditor`].
+#[derive(Debug)]
+pub struct State {
+    /// The gradient last given to or published by the [`GradientEditor`].
+    gradient: Linear,
+    /// The color stops in the order they were added.
+    stops: Vec<ColorStop>,
+    /// The angle of the gradient.
+    angle: Radians,
+    /// The index of the selected color stop.
+    selected: usize,
+    /// The color of the selected color stop in HSV, keeping the hue of grays
+    /// and the saturation of black while it is edited.
+    hsv_color: Hsv,
+    /// The dragged element.
+    dragged: Dragged,
+    /// Whether the [`GradientEditor`] is focused.
+    is_focused: bool,
+    /// The cache of the saturation and value area.
+    sat_value_cache: canvas::Cache,
+    /// The cache of the hue bar.
+    hue_cache: canvas::Cache,
+}
+
+impl State {
+    /// Creates a new [`State`] with the given gradient.
+    #[must_use]
+    pub fn new(gradient: Linear) -> Self {
+        let stops: Vec<ColorStop> = gradient.stops.iter().flatten().copied().collect();
+        let hsv_color = stops.first().map_or(Color::BLACK, |stop| stop.color).into();
+
+        Self {
+            gradient,
+            stops,
+            angle: gradient.angle,
+            selected: 0,
+            hsv_color,
+            dragged: Dragged::None,
+            is_focused: false,
+            sat_value_cache: canvas::Cache::default(),
+            hue_cache: canvas::Cache::default(),
+        }
+    }
+
+    /// Gets the gradient made of the color stops sorted by their offset.
+    fn linear(&self) -> Linear {
+        let mut stops = self.stops.clone();
+        stops.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+
+        Linear::new(self.angle).add_stops(stops)
+    }
+
+    /// Gets the color of the selected color stop.
+    fn selected_color(&self) -> Color {
+        self.stops
+            .get(self.selected)
+            .map_or(Color::BLACK, |stop| stop.color)
+    }
+
+    /// Selects the color stop with the given index.
+    fn select(&mut self, index: usize) {
+        self.selected = index;
+        self.hsv_color = self.selected_color().into();
+    }
+
+    /// Sets the color of the selected color stop.
+    fn set_selected_color(&mut self, hsv_color: Hsv) {
+        self.hsv_color = hsv_color;
+
+        if let Some(stop) = self.stops.get_mut(self.selected) {
+            stop.color = Color {
+                a: stop.color.a,
+                ..hsv_color.into()
+            };
+        }
+    }
+
+    /// Adds a color stop at the given offset with the color of the gradient
+    /// at this offset and selects it.
+    ///
+    /// Returns `false` if no stop was added, because there are already
+    /// [`MAX_STOPS`] stops.
+    fn add_stop(&mut self, offset: f32) -> bool {
+        if self.stops.len() >= MAX_STOPS {
+            return false;
+        }
+
+        let offset = self.free_offset(self.stops.len(), offset.clamp(0.0, 1.0));
+        let color = gradient_color_at(&self.stops, offset).unwrap_or(Color::BLACK);
+        self.stops.push(ColorStop { offset, color });
+        self.select(self.stops.len() - 1);
+        true
+    }
+
+    /// Removes the color stop with the given index, keeping at least
+    /// [`MIN_STOPS`] stops.
+    fn remove_stop(&mut self, index: usize) {
+        if self.stops.len() > MIN_STOPS && index < self.stops.len() {
+            let _ = self.stops.remove(index);
+            self.select(self.selected.min(self.stops.len() - 1));
+        }
+    }
+
+    /// Moves the selected color stop to the given offset.
+    fn move_selected(&mut self, offset: f32) {
+        let offset = self.free_offset(self.selected, offset.clamp(0.0, 1.0));
+
+        if let Some(stop) = self.stops.get_mut(self.selected) {
+            stop.offset = offset;
+        }
+    }
+
+    /// Nudges the offset of the color stop with the given index towards the
+    /// middle of the gradient until no other stop is at this offset, since a
+    /// [`Linear`] gradient keeps only one stop per offset.
+    fn free_offset(&self, index: usize, offset: f32) -> f32 {
+        let nudge = if offset < 0.5 {
+            OFFSET_NUDGE
+        } else {
+            -OFFSET_NUDGE
+        };
+        let is_taken = |offset: f32| {
+            self.stops
+                .iter()
+                .enumerate()
+                .any(|(other, stop)| other != index && (stop.offset - offset).abs() < f32::EPSILON)
+        };
+
+        let mut offset = offset;
+        while is_taken(offset) {
+            offset += nudge;
+        }
+        offset
+    }
+
+    /// Rotates the gradient by the given degrees.
+    fn rotate(&mut self, degrees: f32) {
+        self.angle = Radians((self.angle.0 + degrees.to_radians()).rem_euclid(TAU));
+    }
+}
+
+/// The element of the [`GradientEditor`] that is dragged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Dragged {
+    /// Nothing is dragged.
+    None,
+    /// The selected color stop is dragged.
+    Stop,
+    /// The saturation and value of the selected color stop are dragged.
+    SatValue,
+    /// The hue of the selected color stop is dragged.
+    Hue,
+    /// The angle of the gradient is dragged.
+    Angle,
+}
+
+/// The bounds of the elements of the [`GradientEditor`].
+struct Elements {
+    /// The bar showing the gradient.
+    bar: Rectangle,
+    /// The track of the handles of the color stops below the bar.
+    track: Rectangle,
+    /// The saturation and value area of the selected color stop.
+    sat_value: Rectangle,
+    /// The dial of the angle.
+    dial: Rectangle,
+    /// The hue bar of the selected color stop.
+    hue: Rectangle,
+}
+
+impl Elements {
+    /// Gets the bounds of the elements from the layout.
+    fn new(layout: Layout<'_>) -> Self {
+        let mut children = layout.children().map(|child| child.bounds());
+        let mut next = || {
+            children.next().expect(
+                "widgets: Layout should have a layout for every element of a GradientEditor",
+            )
+        };
+
+        Self {
+            bar: next(),
+            track: next(),
+            sat_value: next(),
+            dial: next(),
+            hue: next(),
+        }
+    }
+
+    /// Gets the offset of the gradient at the given position.
+    fn offset_at(&self, position: Point) -> f32 {
+        ((position.x - self.bar.x) / self.bar.width).clamp(0.0, 1.0)
+    }
+
+    /// Gets the bounds of the handle of a color stop at the given offset.
+    fn handle(&self, offset: f32) -> Rectangle {
+        Rectangle::new(
+            Point::new(
+                self.track.x + offset * self.track.width - HANDLE_SIZE / 2.0,
+                self.track.y,
+            ),
+            Size::new(HANDLE_SIZE, HANDLE_SIZE),
+        )
+    }
+
+    /// Gets the index of the color stop whose handle is closest to the given
+    /// position if the position is on a handle.
+    fn stop_at(&self, stops: &[ColorStop], position: Point) -> Option<usize> {
+        stops
+            .iter()
+            .enumerate()
+            .filter(|(_, stop)| self.handle(stop.offset).contains(position))
+            .min_by(|(_, a), (_, b)| {
+                let distance =
+                    |stop: &ColorStop| (self.handle(stop.offset).center_x() - position.x).abs();
+                distance(a).total_cmp(&distance(b))
+            })
+            .map(|(index, _)| index)
+    }
+}
+
+/// Gets the angle picked at the given position in the bounds of the dial.
+///
+/// An angle of 0 points from the bottom to the top, rotating clockwise.
+fn angle_at(bounds: Rectangle, position: Point) -> Radians {
+    let center = bounds.center();
+    Radians(((position.y - center.y).atan2(position.x - center.x) + FRAC_PI_2).rem_euclid(TAU))
+}
+
+impl<'a, Message, Theme> Widget<Message, Theme, Renderer> for GradientEditor<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: crate::style::color_picker::Catalog,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::new(self.gradient))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state: &mut State = tree.state.downcast_mut();
+
+        // Only a changed gradient of the application replaces the edited one.
+        if state.gradient != self.gradient {
+            let selected = state.selected;
+            let is_focused = state.is_focused;
+
+            *state = State::new(self.gradient);
+            state.select(selected.min(state.stops.len().saturating_sub(1)));
+            state.is_focused = is_focused;
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let height = BAR_HEIGHT + HANDLE_SIZE + AREA_SIZE + HUE_HEIGHT + 2.0 * SPACING;
+        let width = limits
+            .width(self.width)
+            .resolve(self.width, Length::Shrink, Size::new(300.0, height))
+            .width;
+
+        let areas_y = BAR_HEIGHT + HANDLE_SIZE + SPACING;
+
+        Node::with_children(
+            Size::new(width, height),
+            vec![
+                Node::new(Size::new(width, BAR_HEIGHT)),
+                Node::new(Size::new(width, HANDLE_SIZE)).move_to(Point::new(0.0, BAR_HEIGHT)),
+                Node::new(Size::new((width - AREA_SIZE - SPACING).max(0.0), AREA_SIZE))
+                    .move_to(Point::new(0.0, areas_y)),
+                Node::new(Size::new(AREA_SIZE, AREA_SIZE))
+                    .move_to(Point::new((width - AREA_SIZE).max(0.0), areas_y)),
+                Node::new(Size::new(width, HUE_HEIGHT))
+                    .move_to(Point::new(0.0, areas_y + AREA_SIZE + SPACING)),
+            ],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state: &mut State = tree.state.downcast_mut();
+        let elements = Elements::new(layout);
+        let previous_selected = state.selected;
+        let previous_hsv_color = state.hsv_color;
+
+        let status = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = cursor.is_over(layout.bounds());
+
+                let Some(position) = cursor.position_over(layout.bounds()) else {
+                    return event::Status::Ignored;
+                };
+
+                if let Some(index) = elements.stop_at(&state.stops, position) {
+                    state.select(index);
+                    state.dragged = Dragged::Stop;
+                } else if elements.bar.contains(position) || elements.track.contains(position) {
+                    // Nothing is dragged on a full bar, so the selected stop stays put.
+                    if state.add_stop(elements.offset_at(position)) {
+                        state.dragged = Dragged::Stop;
+                    }
+                } else if elements.sat_value.contains(position) {
+                    state.dragged = Dragged::SatValue;
+                } else if elements.hue.contains(position) {
+                    state.dragged = Dragged::Hue;
+                } else if elements.dial.contains(position) {
+                    state.dragged = Dragged::Angle;
+                }
+
+                drag(state, &elements, position);
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                match cursor
+                    .position()
+                    .and_then(|position| elements.stop_at(&state.stops, position))
+                {
+                    Some(index) => {
+                        state.remove_stop(index);
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if state.dragged != Dragged::None =>
+            {
+                if let Some(position) = cursor.position() {
+                    drag(state, &elements, position);
+                }
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.dragged != Dragged::None =>
+            {
+                state.dragged = Dragged::None;
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. },
+            }) if cursor.is_over(elements.dial) => {
+                state.rotate(y.signum() * ANGLE_STEP);
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                modifiers,
+                ..
+            }) if state.is_focused => on_event_keyboard(state, key, modifiers),
+            _ => event::Status::Ignored,
+        };
+
+        let gradient = state.linear();
+        if gradient != state.gradient
+            || state.selected != previous_selected
+            || state.hsv_color != previous_hsv_color
+        {
+            state.sat_value_cache.clear();
+            state.hue_cache.clear();
+        }
+        if gradient != state.gradient {
+            state.gradient = gradient;
+            shell.publish((self.on_change)(gradient));
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state: &State = tree.state.downcast_ref();
+        let elements = Elements::new(layout);
+
+        if state.dragged == Dragged::Stop {
+            return mouse::Interaction::Grabbing;
+        }
+
+        match cursor.position_over(layout.bounds()) {
+            Some(position) if elements.stop_at(&state.stops, position).is_some() => {
+                mouse::Interaction::Grab
+            }
+            Some(position)
+                if [
+                    elements.bar,
+                    elements.track,
+                    elements.sat_value,
+                    elements.dial,
+                    elements.hue,
+                ]
+                .iter()
+                .any(|bounds| bounds.contains(position)) =>
+            {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state: &State = tree.state.downcast_ref();
+        let elements = Elements::new(layout);
+
+        let style = |status| crate::style::color_picker::Catalog::style(theme, &self.class, status);
+        let active = style(Status::Active);
+        let focused = style(Status::Focused);
+        let hovered = style(Status::Hovered);
+        let bar_style = if state.is_focused { focused } else { active };
+
+        // ----------- Gradient bar --------------------
+        let mut stops = state.stops.clone();
+        stops.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: elements.bar,
+                border: Border {
+                    radius: bar_style.bar_border_radius.into(),
+                    width: bar_style.bar_border_width,
+                    color: bar_style.bar_border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            Background::Gradient(Gradient::Linear(
+                Linear::new(Radians(FRAC_PI_2)).add_stops(stops),
+            )),
+        );
+
+        // ----------- Color stops ---------------------
+        for (index, stop) in state.stops.iter().enumerate() {
+            let bounds = elements.handle(stop.offset);
+            let handle_style = if index == state.selected {
+                focused
+            } else if cursor.is_over(bounds) {
+                hovered
+            } else {
+                active
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: 2.0.into(),
+                        width: if index == state.selected {
+                            2.0 * handle_style.bar_border_width
+                        } else {
+                            handle_style.bar_border_width
+                        },
+                        color: handle_style.bar_border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                Color {
+                    a: 1.0,
+                    ..stop.color
+                },
+            );
+        }
+
+        // ----------- Selected color ------------------
+        let hsv_color = state.hsv_color;
+        draw_sat_value(
+            renderer,
+            &state.sat_value_cache,
+            elements.sat_value,
+            hsv_color,
+            bar_style.bar_border_color,
+        );
+        draw_hue(
+            renderer,
+            &state.hue_cache,
+            elements.hue,
+            hsv_color,
+            bar_style.bar_border_color,
+        );
+
+        // ----------- Angle dial ----------------------
+        let dial = elements.dial;
+        let radius = dial.width.min(dial.height) / 2.0;
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: dial,
+                border: Border {
+                    radius: radius.into(),
+                    width: bar_style.bar_border_width,
+                    color: bar_style.bar_border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            Background::Gradient(Gradient::Linear(state.linear())),
+        );
+
+        let direction = state.angle.0 - FRAC_PI_2;
+        let marker_radius = 5.0;
+        let marker_center = Point::new(
+            dial.center_x() + direction.cos() * (radius - 2.0 * marker_radius),
+            dial.center_y() + direction.sin() * (radius - 2.0 * marker_radius),
+        );
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle::new(
+                    Point::new(
+                        marker_center.x - marker_radius,
+                        marker_center.y - marker_radius,
+                    ),
+                    Size::new(2.0 * marker_radius, 2.0 * marker_radius),
+                ),
+                border: Border {
+                    radius: marker_radius.into(),
+                    width: 2.0,
+                    color: Color::BLACK,
+                },
+                shadow: Shadow::default(),
+            },
+            Color::WHITE,
+        );
+
+        renderer.fill_text(
+            Text {
+                content: format!("{:.0}°", state.angle.0.to_degrees()),
+                bounds: dial.size(),
+                size: renderer.default_size(),
+                font: renderer.default_font(),
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                line_height: text::LineHeight::Relative(1.3),
+                shaping: text::Shaping::Basic,
+            },
+            dial.center(),
+            active.bar_border_color,
+            dial,
+        );
+    }
+}
+
+impl<'a, Message, Theme> From<GradientEditor<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a + crate::style::color_picker::Catalog,
+{
+    fn from(gradient_editor: GradientEditor<'a, Message, Theme>) -> Self {
+        Element::new(gradient_editor)
+    }
+}
+
+/// Applies the dragging of the dragged element to the given position.
+fn drag(state: &mut State, elements: &Elements, position: Point) {
+    let hsv_color = state.hsv_color;
+
+    match state.dragged {
+        Dragged::None => {}
+        Dragged::Stop => state.move_selected(elements.offset_at(position)),
+        Dragged::SatValue => {
+            state.set_selected_color(sat_value_at(hsv_color, elements.sat_value, position));
+        }
+        Dragged::Hue => state.set_selected_color(hue_at(hsv_color, elements.hue, position)),
+        Dragged::Angle => state.angle = angle_at(elements.dial, position),
+    }
+}
+
+/// The keyboard handling of the [`GradientEditor`].
+fn on_event_keyboard(
+    state: &mut State,
+    key: keyboard::key::Named,
+    modifiers: keyboard::Modifiers,
+) -> event::Status {
+    use keyboard::key::Named;
+
+    let offset = state
+        .stops
+        .get(state.selected)
+        .map_or(0.0, |stop| stop.offset);
+    let hsv_color = state.hsv_color;
+
+    match key {
+        Named::Tab if !state.stops.is_empty() => {
+            let count = state.stops.len();
+            state.select(if modifiers.shift() {
+                (state.selected + count - 1) % count
+            } else {
+                (state.selected + 1) % count
+            });
+        }
+        Named::ArrowLeft => state.move_selected(offset - OFFSET_STEP),
+        Named::ArrowRight => state.move_selected(offset + OFFSET_STEP),
+        Named::ArrowUp if modifiers.shift() => {
+            state.set_selected_color(Hsv {
+                value: (hsv_color.value + SAT_VALUE_STEP).min(1.0),
+                ..hsv_color
+            });
+        }
+        Named::ArrowDown if modifiers.shift() => {
+            state.set_selected_color(Hsv {
+                value: (hsv_color.value - SAT_VALUE_STEP).max(0.0),
+                ..hsv_color
+            });
+        }
+        Named::ArrowUp => state.set_selected_color(Hsv {
+            hue: (i32::from(hsv_color.hue) + HUE_STEP).rem_euclid(360) as u16,
+            ..hsv_color
+        }),
+        Named::ArrowDown => state.set_selected_color(Hsv {
+            hue: (i32::from(hsv_color.hue) - HUE_STEP).rem_euclid(360) as u16,
+            ..hsv_color
+        }),
+        Named::PageUp => state.rotate(ANGLE_STEP),
+        Named::PageDown => state.rotate(-ANGLE_STEP),
+        Named::Delete | Named::Backspace => state.remove_stop(state.selected),
+        Named::Escape => state.is_focused = false,
+        _ => return event::Status::Ignored,
+    }
+
+    event::Status::Captured
+}
+
+#[cfg(test)]
+mod tests {
+    use iced::{gradient::Linear, Color, Point, Radians, Rectangle, Size};
+
+    use super::{angle_at, Elements, State, MAX_STOPS, MIN_STOPS};
+
+    /// Creates the state of a gradient from black to white.
+    fn black_to_white() -> State {
+        State::new(
+            Linear::new(Radians(0.0))
+                .add_stop(0.0, Color::BLACK)
+                .add_stop(1.0, Color::WHITE),
+        )
+    }
+
+    #[test]
+    fn angle() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+        let degrees = |position: Point| angle_at(bounds, position).0.to_degrees();
+
+        assert!(degrees(Point::new(50.0, 0.0)).abs() < 1e-3);
+        assert!((degrees(Point::new(100.0, 50.0)) - 90.0).abs() < 1e-3);
+        assert!((degrees(Point::new(50.0, 100.0)) - 180.0).abs() < 1e-3);
+        assert!((degrees(Point::new(0.0, 50.0)) - 270.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn offset() {
+        let bar = Rectangle::new(Point::new(10.0, 0.0), Size::new(100.0, 20.0));
+        let elements = Elements {
+            bar,
+            track: bar,
+            sat_value: bar,
+            dial: bar,
+            hue: bar,
+        };
+
+        assert!(elements.offset_at(Point::new(0.0, 0.0)).abs() < f32::EPSILON);
+        assert!((elements.offset_at(Point::new(35.0, 0.0)) - 0.25).abs() < f32::EPSILON);
+        assert!((elements.offset_at(Point::new(200.0, 0.0)) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn add_stop() {
+        let mut state = black_to_white();
+
+        assert!(state.add_stop(0.5));
+        assert_eq!(state.stops.len(), 3);
+        assert_eq!(state.selected, 2);
+        assert!((state.stops[2].color.r - 0.5).abs() < 1e-3);
+
+        // A stop at the offset of another stop is nudged away from it.
+        assert!(state.add_stop(0.5));
+        assert_eq!(state.stops.len(), 4);
+        assert!((state.stops[3].offset - 0.5).abs() > f32::EPSILON);
+        assert_eq!(state.linear().stops.iter().flatten().count(), 4);
+
+        assert!(state.add_stop(1.0));
+        assert!(state.stops[4].offset < 1.0);
+
+        for _ in 0..MAX_STOPS {
+            let _ = state.add_stop(0.25);
+        }
+        assert_eq!(state.stops.len(), MAX_STOPS);
+        assert_eq!(state.linear().stops.iter().flatten().count(), MAX_STOPS);
+
+        // A click on the full bar neither adds a stop nor changes the selection.
+        let selected = state.selected;
+        assert!(!state.add_stop(0.75));
+        assert_eq!(state.stops.len(), MAX_STOPS);
+        assert_eq!(state.selected, selected);
+    }
+
+    #[test]
+    fn remove_stop() {
+        let mut state = black_to_white();
+        assert!(state.add_stop(0.5));
+
+        state.remove_stop(2);
+        assert_eq!(state.stops.len(), 2);
+        assert_eq!(state.selected, 1);
+
+        // An unknown stop is not removed.
+        assert!(state.add_stop(0.5));
+        state.remove_stop(3);
+        assert_eq!(state.stops.len(), 3);
+
+        for _ in 0..3 {
+            state.remove_stop(0);
+        }
+        assert_eq!(state.stops.len(), MIN_STOPS);
+    }
+}
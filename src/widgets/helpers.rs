@@ -193,6 +193,7 @@ where
     Theme: 'a
         + crate::style::color_picker::Catalog
         + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog
         + iced::widget::text::Catalog,
     F: 'static + Fn(Color) -> Message,
 {
@@ -216,12 +217,145 @@ where
         + crate::style::date_picker::Catalog
         + iced::widget::button::Catalog
         + iced::widget::text::Catalog
+        + iced::widget::text_input::Catalog
         + iced::widget::container::Catalog,
     F: 'static + Fn(crate::core::date::Date) -> Message,
 {
     crate::DatePicker::new(show_picker, date, underlay, on_cancel, on_submit)
 }
 
+#[cfg(feature = "date_picker")]
+/// Shortcut helper to create a [`DatePicker`] Widget picking a range of dates.
+///
+/// [`DatePicker`]: crate::DatePicker
+pub fn date_range_picker<'a, Message, Theme, F>(
+    show_picker: bool,
+    start: impl Into<crate::core::date::Date>,
+    end: impl Into<crate::core::date::Date>,
+    underlay: impl Into<Element<'a, Message, Theme, iced::Renderer>>,
+    on_cancel: Message,
+    on_submit: F,
+) -> crate::DatePicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + crate::style::date_picker::Catalog
+        + iced::widget::button::Catalog
+        + iced::widget::text::Catalog
+        + iced::widget::text_input::Catalog
+        + iced::widget::container::Catalog,
+    F: 'static + Fn(crate::core::date::Date, crate::core::date::Date) -> Message,
+{
+    crate::DatePicker::new_range(show_picker, start, end, underlay, on_cancel, on_submit)
+}
+
+#[cfg(feature = "calendar")]
+/// Shortcut helper to create a [`Calendar`] Widget.
+///
+/// [`Calendar`]: crate::Calendar
+pub fn calendar<'a, Message, Theme, F>(
+    date: impl Into<crate::core::date::Date>,
+    on_select: F,
+) -> crate::Calendar<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + crate::style::date_picker::Catalog
+        + iced::widget::button::Catalog
+        + iced::widget::text::Catalog
+        + iced::widget::container::Catalog,
+    F: 'static + Fn(crate::core::date::Date) -> Message,
+{
+    crate::Calendar::new(date, on_select)
+}
+
+#[cfg(feature = "hsv_picker")]
+/// Shortcut helper to create a circular color wheel [`HsvPicker`] Widget.
+///
+/// [`HsvPicker`]: crate::HsvPicker
+pub fn color_wheel<'a, Message, Theme, F>(
+    color: Color,
+    on_change: F,
+) -> crate::HsvPicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a + crate::style::color_picker::Catalog,
+    F: 'static + Fn(Color) -> Message,
+{
+    crate::HsvPicker::wheel(color, on_change)
+}
+
+#[cfg(feature = "hsv_picker")]
+/// Shortcut helper to create a saturation and value square [`HsvPicker`] Widget.
+///
+/// [`HsvPicker`]: crate::HsvPicker
+pub fn sat_value_square<'a, Message, Theme, F>(
+    color: Color,
+    on_change: F,
+) -> crate::HsvPicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a + crate::style::color_picker::Catalog,
+    F: 'static + Fn(Color) -> Message,
+{
+    crate::HsvPicker::sat_value(color, on_change)
+}
+
+#[cfg(feature = "hsv_picker")]
+/// Shortcut helper to create a hue bar [`HsvPicker`] Widget.
+///
+/// [`HsvPicker`]: crate::HsvPicker
+pub fn hue_bar<'a, Message, Theme, F>(
+    color: Color,
+    on_change: F,
+) -> crate::HsvPicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a + crate::style::color_picker::Catalog,
+    F: 'static + Fn(Color) -> Message,
+{
+    crate::HsvPicker::hue(color, on_change)
+}
+
+#[cfg(feature = "gradient_editor")]
+/// Shortcut helper to create a [`GradientEditor`] Widget.
+///
+/// [`GradientEditor`]: crate::GradientEditor
+pub fn gradient_editor<'a, Message, Theme, F>(
+    gradient: iced::gradient::Linear,
+    on_change: F,
+) -> crate::GradientEditor<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a + crate::style::color_picker::Catalog,
+    F: 'static + Fn(iced::gradient::Linear) -> Message,
+{
+    crate::GradientEditor::new(gradient, on_change)
+}
+
+#[cfg(feature = "date_time_picker")]
+/// Shortcut helper to create a [`DateTimePicker`] Widget.
+///
+/// [`DateTimePicker`]: crate::DateTimePicker
+pub fn date_time_picker<'a, Message, Theme, U, F>(
+    show_picker: bool,
+    date_time: chrono::NaiveDateTime,
+    underlay: U,
+    on_cancel: Message,
+    on_submit: F,
+) -> crate::DateTimePicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + crate::style::date_time_picker::Catalog
+        + iced::widget::button::Catalog
+        + iced::widget::text::Catalog,
+    U: Into<Element<'a, Message, Theme, iced::Renderer>>,
+    F: 'static + Fn(chrono::NaiveDateTime) -> Message,
+{
+    crate::DateTimePicker::new(show_picker, date_time, underlay, on_cancel, on_submit)
+}
+
 #[cfg(feature = "time_picker")]
 /// Shortcut helper to create a [`DatePicker`] Widget.
 ///
@@ -341,7 +475,7 @@ pub fn typed_input<'a, T, Message, Theme, Renderer, F>(
 where
     Message: Clone,
     Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
-    Theme: iced::widget::text_input::Catalog,
+    Theme: crate::style::typed_input::Catalog,
     F: 'static + Fn(T) -> Message + Copy,
     T: 'static + std::fmt::Display + std::str::FromStr + Clone,
 {
@@ -0,0 +1,620 @@
+//! Use the HSV areas of the color picker embedded in the layout for editing
+//! colors live.
+//!
+//! *This API requires the following crate features to be activated: `hsv_picker`*
+
+use super::overlay::color_picker::{
+    draw_hue, draw_sat_value, hue_at, sat_value_at, HUE_STEP, SAT_VALUE_STEP,
+};
+use crate::core::color::Hsv;
+
+use iced::{
+    advanced::{
+        graphics::geometry::Renderer as _,
+        layout::{Limits, Node},
+        renderer,
+        widget::{
+            self,
+            tree::{Tag, Tree},
+        },
+        Clipboard, Layout, Renderer as _, Shell, Widget,
+    },
+    event,
+    keyboard,
+    mouse::{self, Cursor},
+    touch,
+    widget::canvas::{self, LineCap, Path, Stroke},
+    Color,
+    Element,
+    Event,
+    Length,
+    Point,
+    Rectangle,
+    Renderer, // the actual type
+    Size,
+    Vector,
+};
+
+pub use crate::style::{color_picker::Style, Status, StyleFn};
+
+/// An area of the HSV color space picking a color that is embedded in the
+/// layout.
+///
+/// Unlike the [`ColorPicker`](crate::ColorPicker) it has no cancel and submit
+/// buttons, the color is published continuously while it is dragged.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::HsvPicker;
+/// # use iced::Color;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     ColorChanged(Color),
+/// }
+///
+/// let wheel = HsvPicker::wheel(Color::from_rgb(0.5, 0.25, 0.25), Message::ColorChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct HsvPicker<'a, Message, Theme = iced::Theme>
+where
+    Theme: crate::style::color_picker::Catalog,
+{
+    /// The color to show.
+    color: Color,
+    /// The area of the HSV color space shown by the [`HsvPicker`].
+    area: HsvArea,
+    /// The function that produces a message when the color is changed.
+    on_change: Box<dyn Fn(Color) -> Message>,
+    /// The width of the [`HsvPicker`].
+    width: Length,
+    /// The height of the [`HsvPicker`].
+    height: Length,
+    /// The style of the [`HsvPicker`].
+    class: <Theme as crate::style::color_picker::Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme> HsvPicker<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: crate::style::color_picker::Catalog,
+{
+    /// Creates a new [`HsvPicker`] showing the given area.
+    fn new<F>(color: Color, area: HsvArea, on_change: F, width: Length, height: Length) -> Self
+    where
+        F: 'static + Fn(Color) -> Message,
+    {
+        Self {
+            color,
+            area,
+            on_change: Box::new(on_change),
+            width,
+            height,
+            class: <Theme as crate::style::color_picker::Catalog>::default(),
+        }
+    }
+
+    /// Creates a new circular color wheel picking the hue by the angle and
+    /// the saturation by the distance to its center.
+    ///
+    /// The value of the color is kept, so the wheel is usually paired with a
+    /// control for the value.
+    ///
+    /// It expects:
+    ///     * the color to show.
+    ///     * a function that will be called while the color is changed, which
+    ///         takes the changed [`Color`] value.
+    pub fn wheel<F>(color: Color, on_change: F) -> Self
+    where
+        F: 'static + Fn(Color) -> Message,
+    {
+        Self::new(
+            color,
+            HsvArea::Wheel,
+            on_change,
+            Length::Fixed(200.0),
+            Length::Fixed(200.0),
+        )
+    }
+
+    /// Creates a new square picking the saturation from left to right and the
+    /// value from top to bottom for the hue of the color.
+    ///
+    /// It expects:
+    ///     * the color to show.
+    ///     * a function that will be called while the color is changed, which
+    ///         takes the changed [`Color`] value.
+    pub fn sat_value<F>(color: Color, on_change: F) -> Self
+    where
+        F: 'static + Fn(Color) -> Message,
+    {
+        Self::new(
+            color,
+            HsvArea::SatValue,
+            on_change,
+            Length::Fill,
+            Length::Fixed(200.0),
+        )
+    }
+
+    /// Creates a new bar picking the hue from left to right.
+    ///
+    /// It expects:
+    ///     * the color to show.
+    ///     * a function that will be called while the color is changed, which
+    ///         takes the changed [`Color`] value.
+    pub fn hue<F>(color: Color, on_change: F) -> Self
+    where
+        F: 'static + Fn(Color) -> Message,
+    {
+        Self::new(
+            color,
+            HsvArea::Hue,
+            on_change,
+            Length::Fill,
+            Length::Fixed(20.0),
+        )
+    }
+
+    /// Sets the width of the [`HsvPicker`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`HsvPicker`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`HsvPicker`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as crate::style::color_picker::Catalog>::Class<'a>: From<StyleFn<'a, Theme, Style>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme, Style>).into();
+        self
+    }
+
+    /// Sets the class of the input of the [`HsvPicker`].
+    #[must_use]
+    pub fn class(
+        mut self,
+        class: impl Into<<Theme as crate::style::color_picker::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// The area of the HSV color space shown by a [`HsvPicker`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HsvArea {
+    /// A circular wheel picking the hue by the angle and the saturation by the
+    /// distance to its center.
+    Wheel,
+    /// A square picking the saturation from left to right and the value from
+    /// top to bottom.
+    SatValue,
+    /// A bar picking the hue from left to right.
+    Hue,
+}
+
+impl HsvArea {
+    /// Gets the color picked at the given position in the bounds of the area.
+    fn pick(self, hsv_color: Hsv, bounds: Rectangle, position: Point) -> Hsv {
+        match self {
+            Self::Wheel => wheel_at(hsv_color, bounds, position),
+            Self::SatValue => sat_value_at(hsv_color, bounds, position),
+            Self::Hue => hue_at(hsv_color, bounds, position),
+        }
+    }
+
+    /// Gets the color changed by the given key or `None` if the key is not
+    /// handled by the area.
+    fn step(self, hsv_color: Hsv, key: keyboard::key::Named) -> Option<Hsv> {
+        use keyboard::key::Named;
+
+        let hue = |steps: i32| (i32::from(hsv_color.hue) + steps * HUE_STEP).rem_euclid(360) as u16;
+
+        let hsv_color = match (self, key) {
+            (Self::SatValue, Named::ArrowLeft) | (Self::Wheel, Named::ArrowDown) => Hsv {
+                saturation: hsv_color.saturation - SAT_VALUE_STEP,
+                ..hsv_color
+            },
+            (Self::SatValue, Named::ArrowRight) | (Self::Wheel, Named::ArrowUp) => Hsv {
+                saturation: hsv_color.saturation + SAT_VALUE_STEP,
+                ..hsv_color
+            },
+            (Self::SatValue, Named::ArrowUp) => Hsv {
+                value: hsv_color.value - SAT_VALUE_STEP,
+                ..hsv_color
+            },
+            (Self::SatValue, Named::ArrowDown) => Hsv {
+                value: hsv_color.value + SAT_VALUE_STEP,
+                ..hsv_color
+            },
+            (Self::Wheel | Self::Hue, Named::ArrowLeft) | (Self::Hue, Named::ArrowDown) => Hsv {
+                hue: hue(-1),
+                ..hsv_color
+            },
+            (Self::Wheel | Self::Hue, Named::ArrowRight) | (Self::Hue, Named::ArrowUp) => Hsv {
+                hue: hue(1),
+                ..hsv_color
+            },
+            _ => return None,
+        };
+
+        Some(Hsv {
+            saturation: hsv_color.saturation.clamp(0.0, 1.0),
+            value: hsv_color.value.clamp(0.0, 1.0),
+            ..hsv_color
+        })
+    }
+}
+
+/// The state of the [`HsvPicker`].
+#[derive(Debug)]
+pub struct State {
+    /// The color last given to or published by the [`HsvPicker`].
+    color: Color,
+    /// The color in HSV, keeping the hue and the saturation of grays.
+    hsv_color: Hsv,
+    /// The cache of the canvas of the [`HsvPicker`].
+    cache: canvas::Cache,
+    /// Whether the color is being dragged.
+    is_dragging: bool,
+    /// Whether the [`HsvPicker`] is focused.
+    is_focused: bool,
+    /// Whether the cursor is over the [`HsvPicker`].
+    is_hovered: bool,
+}
+
+impl State {
+    /// Creates a new [`State`] with the given color.
+    #[must_use]
+    pub fn new(color: Color) -> Self {
+        Self {
+            color,
+            hsv_color: color.into(),
+            cache: canvas::Cache::default(),
+            is_dragging: false,
+            is_focused: false,
+            is_hovered: false,
+        }
+    }
+
+    /// Takes the given color, keeping the hue of grays and the saturation of
+    /// black since they can not be told from the color.
+    fn set_color(&mut self, color: Color) {
+        let hsv_color = Hsv::from(color);
+
+        self.hsv_color = Hsv {
+            hue: if hsv_color.saturation > 0.0 && hsv_color.value > 0.0 {
+                hsv_color.hue
+            } else {
+                self.hsv_color.hue
+            },
+            saturation: if hsv_color.value > 0.0 {
+                hsv_color.saturation
+            } else {
+                self.hsv_color.saturation
+            },
+            value: hsv_color.value,
+        };
+        self.color = color;
+        self.cache.clear();
+    }
+
+    /// Changes the color to the given HSV color, returning the changed color
+    /// if it differs.
+    fn change(&mut self, hsv_color: Hsv) -> Option<Color> {
+        if hsv_color == self.hsv_color {
+            return None;
+        }
+
+        self.hsv_color = hsv_color;
+        self.color = Color {
+            a: self.color.a,
+            ..hsv_color.into()
+        };
+        self.cache.clear();
+
+        Some(self.color)
+    }
+}
+
+impl<'a, Message, Theme> Widget<Message, Theme, Renderer> for HsvPicker<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: crate::style::color_picker::Catalog,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::new(self.color))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state: &mut State = tree.state.downcast_mut();
+
+        // Only a changed color of the application replaces the one of the picker.
+        if state.color != self.color {
+            state.set_color(self.color);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.width(self.width).height(self.height).resolve(
+            self.width,
+            self.height,
+            Size::new(f32::INFINITY, f32::INFINITY),
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state: &mut State = tree.state.downcast_mut();
+        let bounds = layout.bounds();
+
+        // The border of the area is drawn into the cache.
+        let is_hovered = cursor.is_over(bounds);
+        if state.is_hovered != is_hovered {
+            state.is_hovered = is_hovered;
+            state.cache.clear();
+        }
+
+        let mut picked = None;
+
+        let status = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let is_focused = is_hovered;
+                if state.is_focused != is_focused {
+                    state.is_focused = is_focused;
+                    state.cache.clear();
+                }
+
+                if is_hovered {
+                    state.is_dragging = true;
+                    picked = cursor
+                        .position()
+                        .map(|position| self.area.pick(state.hsv_color, bounds, position));
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if state.is_dragging =>
+            {
+                picked = cursor
+                    .position()
+                    .map(|position| self.area.pick(state.hsv_color, bounds, position));
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.is_dragging =>
+            {
+                state.is_dragging = false;
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. },
+            }) if self.area == HsvArea::Hue && is_hovered => {
+                picked = Some(Hsv {
+                    hue: (i32::from(state.hsv_color.hue) + y as i32).rem_euclid(360) as u16,
+                    ..state.hsv_color
+                });
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if state.is_focused => {
+                picked = self.area.step(state.hsv_color, key);
+                if picked.is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        };
+
+        if let Some(color) = picked.and_then(|hsv_color| state.change(hsv_color)) {
+            shell.publish((self.on_change)(color));
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state: &State = tree.state.downcast_ref();
+        let bounds = layout.bounds();
+
+        let status = if state.is_focused {
+            Status::Focused
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+        let style = crate::style::color_picker::Catalog::style(theme, &self.class, status);
+
+        match self.area {
+            HsvArea::Wheel => draw_wheel(
+                renderer,
+                &state.cache,
+                bounds,
+                state.hsv_color,
+                style.bar_border_color,
+            ),
+            HsvArea::SatValue => draw_sat_value(
+                renderer,
+                &state.cache,
+                bounds,
+                state.hsv_color,
+                style.bar_border_color,
+            ),
+            HsvArea::Hue => draw_hue(
+                renderer,
+                &state.cache,
+                bounds,
+                state.hsv_color,
+                style.bar_border_color,
+            ),
+        }
+    }
+}
+
+impl<'a, Message, Theme> From<HsvPicker<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a + crate::style::color_picker::Catalog,
+{
+    fn from(hsv_picker: HsvPicker<'a, Message, Theme>) -> Self {
+        Element::new(hsv_picker)
+    }
+}
+
+/// Gets the center and the radius of the wheel in the given bounds.
+fn wheel_circle(bounds: Rectangle) -> (Point, f32) {
+    (bounds.center(), bounds.width.min(bounds.height) / 2.0)
+}
+
+/// Gets the color with the hue and the saturation picked at the given
+/// position in the bounds of a wheel.
+fn wheel_at(hsv_color: Hsv, bounds: Rectangle, position: Point) -> Hsv {
+    let (center, radius) = wheel_circle(bounds);
+    let x = position.x - center.x;
+    let y = center.y - position.y;
+
+    Hsv {
+        hue: (y.atan2(x).to_degrees().rem_euclid(360.0) as u16) % 360,
+        saturation: (x.hypot(y) / radius).min(1.0),
+        ..hsv_color
+    }
+}
+
+/// Draws the wheel of the value of the given color, marking the hue and the
+/// saturation of the color.
+fn draw_wheel(
+    renderer: &mut Renderer,
+    cache: &canvas::Cache,
+    bounds: Rectangle,
+    hsv_color: Hsv,
+    border_color: Color,
+) {
+    let (center, radius) = wheel_circle(bounds);
+    let origin = Point::new(center.x - bounds.x, center.y - bounds.y);
+
+    let geometry = cache.draw(renderer, bounds.size(), |frame| {
+        let diameter = (2.0 * radius) as u16;
+        let corner = Point::new(origin.x - radius, origin.y - radius);
+
+        for column in 0..diameter {
+            for row in 0..diameter {
+                let x = f32::from(column) + 0.5 - radius;
+                let y = radius - f32::from(row) - 0.5;
+                let distance = x.hypot(y);
+
+                if distance > radius {
+                    continue;
+                }
+
+                let hue = (y.atan2(x).to_degrees().rem_euclid(360.0) as u16) % 360;
+
+                frame.fill_rectangle(
+                    Point::new(corner.x + f32::from(column), corner.y + f32::from(row)),
+                    Size::new(1.0, 1.0),
+                    Color::from(Hsv::from_hsv(hue, distance / radius, hsv_color.value)),
+                );
+            }
+        }
+
+        let angle = f32::from(hsv_color.hue).to_radians();
+        let marker = Point::new(
+            origin.x + angle.cos() * hsv_color.saturation * radius,
+            origin.y - angle.sin() * hsv_color.saturation * radius,
+        );
+
+        frame.stroke(
+            &Path::circle(marker, 5.0),
+            Stroke {
+                style: canvas::Style::Solid(
+                    Hsv {
+                        hue: 0,
+                        saturation: 0.0,
+                        value: if hsv_color.value < 0.5 { 1.0 } else { 0.0 },
+                    }
+                    .into(),
+                ),
+                width: 2.0,
+                line_cap: LineCap::Round,
+                ..Stroke::default()
+            },
+        );
+
+        frame.stroke(
+            &Path::circle(origin, radius),
+            Stroke {
+                style: canvas::Style::Solid(border_color),
+                width: 2.0,
+                line_cap: LineCap::Round,
+                ..Stroke::default()
+            },
+        );
+    });
+
+    let translation = Vector::new(bounds.x, bounds.y);
+    renderer.with_translation(translation, |renderer| {
+        renderer.draw_geometry(geometry);
+    });
+}
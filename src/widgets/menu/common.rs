@@ -75,6 +75,20 @@ pub(super) enum RecEvent {
     None,
 }
 
+/// A key navigating the open menus with the keyboard,
+/// handled by the deepest open menu
+#[derive(Debug, Clone, Copy)]
+pub(super) enum Navigation {
+    /// Focuses the previous item
+    Previous,
+    /// Focuses the next item
+    Next,
+    /// Opens the menu of the focused item
+    Open,
+    /// Closes the deepest open menu
+    Close,
+}
+
 #[derive(Debug, Clone, Copy)]
 /// Scroll speed
 pub struct ScrollSpeed {
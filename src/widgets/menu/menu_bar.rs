@@ -12,7 +12,7 @@ use iced::{
         widget::{tree, Operation, Tree},
         Clipboard, Layout, Shell, Widget,
     },
-    alignment, event, Element, Event, Length, Padding, Rectangle, Size,
+    alignment, event, keyboard, Element, Event, Length, Padding, Rectangle, Size,
 };
 
 use super::{common::*, flex, menu_bar_overlay::MenuBarOverlay, menu_tree::*};
@@ -24,6 +24,10 @@ pub(super) struct MenuBarState {
     pub(super) active_root: Index,
     pub(super) open: bool,
     pub(super) is_pressed: bool,
+    /// the bar is navigated with the keyboard
+    pub(super) is_focused: bool,
+    /// alt is pressed without another key
+    pub(super) alt_pressed: bool,
 }
 
 /// menu bar
@@ -128,10 +132,177 @@ where
         self.class = class.into();
         self
     }
+
+    /// tree: Tree{bar_state, \[item_tree...]}
+    ///
+    /// F10 or Alt focuses the bar, the arrow keys move between the roots and items,
+    /// Right and Left open and close menus, Enter activates and Escape closes.
+    ///
+    /// Enter on an item without a menu publishes its [`Item::on_activate`] message
+    fn keyboard_event(
+        &self,
+        tree: &mut Tree,
+        event: &keyboard::Event,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status
+    where
+        Message: Clone,
+    {
+        use event::Status::*;
+        use keyboard::key::Named;
+
+        let bar = tree.state.downcast_mut::<MenuBarState>();
+
+        let key = match event {
+            keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(Named::Alt),
+                ..
+            } => {
+                bar.alt_pressed = true;
+                return Ignored;
+            }
+            keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            } => {
+                bar.alt_pressed = false;
+                *key
+            }
+            keyboard::Event::KeyReleased {
+                key: keyboard::Key::Named(Named::Alt),
+                ..
+            } if bar.alt_pressed => {
+                bar.alt_pressed = false;
+                Named::F10
+            }
+            keyboard::Event::KeyPressed { .. } => {
+                bar.alt_pressed = false;
+                return Ignored;
+            }
+            _ => return Ignored,
+        };
+
+        if self.roots.is_empty() {
+            return Ignored;
+        }
+
+        if key == Named::F10 {
+            if bar.is_focused {
+                if let Some(active) = bar.active_root {
+                    if let Some(menu) = self.roots[active].menu.as_ref() {
+                        menu.close(&mut tree.children[active].children[1]);
+                    }
+                }
+                bar.open = false;
+                bar.is_focused = false;
+            } else {
+                bar.is_focused = true;
+                bar.active_root = Some(bar.active_root.unwrap_or(0));
+            }
+            return Captured;
+        }
+
+        if !bar.is_focused {
+            return Ignored;
+        }
+
+        let active = bar.active_root.unwrap_or(0);
+        let root = &self.roots[active];
+        let root_tree = &mut tree.children[active];
+
+        match (key, root.menu.as_ref()) {
+            (Named::Escape, Some(menu)) if bar.open => {
+                if !menu.navigate(&mut root_tree.children[1], Navigation::Close) {
+                    menu.close(&mut root_tree.children[1]);
+                    bar.open = false;
+                }
+            }
+            (Named::Escape, _) => {
+                bar.open = false;
+                bar.is_focused = false;
+            }
+            (Named::ArrowUp | Named::ArrowDown, Some(menu)) => {
+                let navigation = if key == Named::ArrowUp {
+                    Navigation::Previous
+                } else {
+                    Navigation::Next
+                };
+
+                if !bar.open {
+                    menu.close(&mut root_tree.children[1]);
+                    bar.open = true;
+                }
+                let _ = menu.navigate(&mut root_tree.children[1], navigation);
+            }
+            (Named::ArrowLeft | Named::ArrowRight, menu) => {
+                let navigation = if key == Named::ArrowLeft {
+                    Navigation::Close
+                } else {
+                    Navigation::Open
+                };
+
+                if let Some(menu) = menu.filter(|_| bar.open) {
+                    if menu.navigate(&mut root_tree.children[1], navigation) {
+                        return Captured;
+                    }
+                    menu.close(&mut root_tree.children[1]);
+                }
+
+                let count = self.roots.len();
+                let active = if key == Named::ArrowLeft {
+                    (active + count - 1) % count
+                } else {
+                    (active + 1) % count
+                };
+                bar.active_root = Some(active);
+
+                if let Some(menu) = self.roots[active].menu.as_ref().filter(|_| bar.open) {
+                    let menu_tree = &mut tree.children[active].children[1];
+                    menu.close(menu_tree);
+                    let _ = menu.navigate(menu_tree, Navigation::Next);
+                } else {
+                    bar.open = false;
+                }
+            }
+            (Named::Enter, Some(menu)) => {
+                if bar.open {
+                    if menu.navigate(&mut root_tree.children[1], Navigation::Open) {
+                        return Captured;
+                    }
+                    let Some(message) = root.focused_activation(root_tree) else {
+                        return Ignored;
+                    };
+
+                    shell.publish(message.clone());
+                    menu.close(&mut root_tree.children[1]);
+                    bar.open = false;
+                    bar.is_focused = false;
+                    return Captured;
+                }
+
+                menu.close(&mut root_tree.children[1]);
+                bar.open = true;
+                let _ = menu.navigate(&mut root_tree.children[1], Navigation::Next);
+            }
+            (Named::Enter, None) => {
+                let Some(message) = root.on_activate.as_ref() else {
+                    return Ignored;
+                };
+
+                shell.publish(message.clone());
+                bar.open = false;
+                bar.is_focused = false;
+            }
+            _ => return Ignored,
+        }
+
+        Captured
+    }
 }
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for MenuBar<'a, Message, Theme, Renderer>
 where
+    Message: Clone,
     Theme: Catalog,
     Renderer: renderer::Renderer,
 {
@@ -209,11 +380,19 @@ where
             })
             .fold(Ignored, event::Status::merge);
 
+        if let Event::Keyboard(keyboard_event) = &event {
+            return self
+                .keyboard_event(tree, keyboard_event, shell)
+                .merge(status);
+        }
+
         let bar = tree.state.downcast_mut::<MenuBarState>();
         let bar_bounds = layout.bounds();
 
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                bar.is_focused = false;
+                bar.alt_pressed = false;
                 if cursor.is_over(bar_bounds) {
                     bar.is_pressed = true;
                     Captured
@@ -316,7 +495,7 @@ where
         );
 
         let state = tree.state.downcast_ref::<MenuBarState>();
-        if state.open {
+        if state.open || state.is_focused {
             if let Some(active) = state.active_root {
                 let Some(active_bounds) = layout.children().nth(active).map(|l| l.bounds()) else {
                     return;
@@ -386,7 +565,7 @@ where
 impl<'a, Message, Theme, Renderer> From<MenuBar<'a, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
-    Message: 'a,
+    Message: 'a + Clone,
     Theme: 'a + Catalog,
     Renderer: 'a + renderer::Renderer,
 {
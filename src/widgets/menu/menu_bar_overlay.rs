@@ -207,6 +207,12 @@ where
 
         let active_root = &mut self.roots[active];
         let active_tree = &mut self.tree.children[active];
+
+        if bar.is_focused && matches!(event, Event::Keyboard(_)) {
+            // the keyboard navigation is handled by the menu bar
+            return Ignored;
+        }
+
         let mut prev_bounds_list = vec![bar_bounds];
 
         #[rustfmt::skip]
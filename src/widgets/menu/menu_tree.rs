@@ -62,6 +62,8 @@ Tree{
 pub(super) struct MenuState {
     scroll_offset: f32,
     pub(super) active: Index,
+    /// the item focused with the keyboard
+    pub(super) focused: Index,
     pub(super) slice: MenuSlice,
     pub(super) pressed: bool,
 }
@@ -70,6 +72,7 @@ impl Default for MenuState {
         Self {
             scroll_offset: 0.0,
             active: None,
+            focused: None,
             slice: MenuSlice {
                 start_index: 0,
                 end_index: usize::MAX - 1,
@@ -502,15 +505,13 @@ where
         }
 
         // draw path
-        if let Some(active) = menu_state.active {
-            let Some(active_bounds) = slice_layout
-                .children()
-                .nth(active - menu_state.slice.start_index)
-                .map(|l| l.bounds())
-            else {
-                return;
-            };
-
+        if let Some(active_bounds) = menu_state
+            .active
+            .or(menu_state.focused)
+            .and_then(|active| active.checked_sub(slice.start_index))
+            .and_then(|i| slice_layout.children().nth(i))
+            .map(|l| l.bounds())
+        {
             match draw_path {
                 DrawPath::Backdrop => {
                     if active_bounds.intersects(viewport) {
@@ -665,6 +666,88 @@ where
             menu_state.pressed = false;
         }
     }
+
+    /// tree: Tree{ menu_state, \[item_tree...] }
+    ///
+    /// Handles a keyboard navigation in the deepest open menu,
+    /// returns false if the navigation has to be handled by the parent
+    pub(super) fn navigate(&self, tree: &mut Tree, navigation: Navigation) -> bool {
+        let menu_state = tree.state.downcast_mut::<MenuState>();
+
+        if let Some(active) = menu_state.active {
+            let Some(menu) = self.items[active].menu.as_ref() else {
+                return false;
+            };
+            let menu_tree = &mut tree.children[active].children[1];
+
+            if menu.navigate(menu_tree, navigation) {
+                return true;
+            }
+
+            return match navigation {
+                Navigation::Close => {
+                    menu.close(menu_tree);
+                    menu_state.active = None;
+                    true
+                }
+                _ => false,
+            };
+        }
+
+        let Some(last) = self.items.len().checked_sub(1) else {
+            return matches!(navigation, Navigation::Previous | Navigation::Next);
+        };
+        let first = menu_state.slice.start_index.min(last);
+        let last = menu_state.slice.end_index.min(last);
+
+        match navigation {
+            Navigation::Previous => {
+                menu_state.focused = match menu_state.focused {
+                    Some(i) if i > first && i <= last => Some(i - 1),
+                    _ => Some(last),
+                };
+                true
+            }
+            Navigation::Next => {
+                menu_state.focused = match menu_state.focused {
+                    Some(i) if i >= first && i < last => Some(i + 1),
+                    _ => Some(first),
+                };
+                true
+            }
+            Navigation::Open => {
+                let Some((focused, menu)) = menu_state.focused.and_then(|i| {
+                    self.items
+                        .get(i)
+                        .and_then(|item| item.menu.as_ref())
+                        .map(|menu| (i, menu))
+                }) else {
+                    return false;
+                };
+
+                menu_state.active = Some(focused);
+                let menu_tree = &mut tree.children[focused].children[1];
+                menu.close(menu_tree);
+                menu.navigate(menu_tree, Navigation::Next)
+            }
+            Navigation::Close => false,
+        }
+    }
+
+    /// tree: Tree{ menu_state, \[item_tree...] }
+    ///
+    /// Closes the menu and its open submenus
+    pub(super) fn close(&self, tree: &mut Tree) {
+        let menu_state = tree.state.downcast_mut::<MenuState>();
+
+        if let Some(active) = menu_state.active {
+            if let Some(menu) = self.items[active].menu.as_ref() {
+                menu.close(&mut tree.children[active].children[1]);
+            }
+        }
+
+        *menu_state = MenuState::default();
+    }
 }
 
 /// Item inside a [`Menu`]
@@ -676,6 +759,7 @@ where
 {
     pub(super) item: Element<'a, Message, Theme, Renderer>,
     pub(super) menu: Option<Box<Menu<'a, Message, Theme, Renderer>>>,
+    pub(super) on_activate: Option<Message>,
 }
 impl<'a, Message, Theme, Renderer> Item<'a, Message, Theme, Renderer>
 where
@@ -687,6 +771,7 @@ where
         Self {
             item: item.into(),
             menu: None,
+            on_activate: None,
         }
     }
 
@@ -698,9 +783,17 @@ where
         Self {
             item: item.into(),
             menu: Some(Box::new(menu)),
+            on_activate: None,
         }
     }
 
+    /// Sets the message that is produced when the [`Item`] is focused with the
+    /// keyboard and the enter key is pressed.
+    pub fn on_activate(mut self, message: Message) -> Self {
+        self.on_activate = Some(message);
+        self
+    }
+
     /// Rebuild state tree
     pub(super) fn tree(&self) -> Tree {
         Tree {
@@ -790,6 +883,26 @@ where
         )
     }
 
+    /// tree: Tree{stateless, \[widget_tree, menu_tree]}
+    ///
+    /// Gets the message activating the focused item of the open submenus of
+    /// the item. Returns `None` if no item without a menu is focused.
+    pub(super) fn focused_activation(&self, tree: &Tree) -> Option<&Message> {
+        let menu = self.menu.as_ref()?;
+        let menu_tree = &tree.children[1];
+        let menu_state = menu_tree.state.downcast_ref::<MenuState>();
+
+        if let Some(active) = menu_state.active {
+            return menu.items[active].focused_activation(&menu_tree.children[active]);
+        }
+
+        menu_state
+            .focused
+            .map(|focused| &menu.items[focused])
+            .filter(|item| item.menu.is_none())
+            .and_then(|item| item.on_activate.as_ref())
+    }
+
     /// tree: Tree{stateless, \[widget_tree, menu_tree]}
     ///
     pub(super) fn mouse_interaction(
@@ -19,18 +19,21 @@ use iced::{
         text_input::{self, cursor, Value},
         Column, Container, Row, Text,
     },
-    Alignment, Background, Border, Color, Element, Event, Length, Padding, Pixels, Point,
+    window, Alignment, Background, Border, Color, Element, Event, Length, Padding, Pixels, Point,
     Rectangle, Shadow, Size,
 };
 use num_traits::{bounds::Bounded, Num, NumAssignOps};
 use std::{
     fmt::Display,
     ops::{Bound, RangeBounds},
+    rc::Rc,
     str::FromStr,
+    time::{Duration, Instant},
 };
 
+use crate::core::number::{evaluate_expression, format_number, normalize_number, parse_number};
 use crate::style::{self, Status};
-use crate::widgets::typed_input::TypedInput;
+use crate::widgets::typed_input::{self, TypedInput};
 pub use crate::{
     core::icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
     style::{
@@ -38,10 +41,32 @@ pub use crate::{
         StyleFn,
     },
 };
+pub use num_format::Locale;
 
 /// The default padding
 const DEFAULT_PADDING: f32 = 5.0;
 
+/// The default distance in pixels of dragging one step while scrubbing
+const DEFAULT_SCRUB_DISTANCE: f32 = 5.0;
+
+/// The factor of the distance of a step with Shift and of the steps with Ctrl while scrubbing
+const SCRUB_FACTOR: i32 = 10;
+
+/// The default number of steps of PageUp, PageDown and Shift with the arrow keys
+const DEFAULT_STEP_MULTIPLIER: u32 = 10;
+
+/// The delay before a held increase or decrease button starts repeating
+const REPEAT_DELAY: Duration = Duration::from_millis(400);
+
+/// The interval of the first repeated steps of a held button
+const REPEAT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The shortest interval the repeated steps of a held button accelerate to
+const MIN_REPEAT_INTERVAL: Duration = Duration::from_millis(20);
+
+/// The factor shortening the interval after each repeated step of a held button
+const REPEAT_ACCELERATION: f32 = 0.85;
+
 /// A field that can only be filled with numeric type.
 ///
 /// # Example
@@ -66,6 +91,7 @@ const DEFAULT_PADDING: f32 = 5.0;
 #[allow(missing_debug_implementations)]
 pub struct NumberInput<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer>
 where
+    T: FromStr,
     Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
     Theme: number_input::ExtendedCatalog,
 {
@@ -95,6 +121,18 @@ where
     ignore_scroll_events: bool,
     /// Ignore drawing increase and decrease buttons [`NumberInput`] Default is ``false``.
     ignore_buttons: bool,
+    /// Change the value by dragging horizontally on the [`NumberInput`] Default is ``false``.
+    scrub_events: bool,
+    /// The distance in pixels of dragging one step while scrubbing the [`NumberInput`].
+    scrub_distance: f32,
+    /// Evaluate arithmetic expressions typed into the [`NumberInput`] Default is ``false``.
+    expressions: bool,
+    /// The number of steps of PageUp and PageDown on the [`NumberInput`].
+    page_multiplier: u32,
+    /// The number of steps of the arrow keys with Shift on the [`NumberInput`].
+    shift_multiplier: u32,
+    /// The formatting of the text of the [`NumberInput`].
+    format: NumberFormat<'a, T>,
 }
 
 impl<'a, T, Message, Theme, Renderer> NumberInput<'a, T, Message, Theme, Renderer>
@@ -135,6 +173,12 @@ where
             width: Length::Shrink,
             ignore_scroll_events: false,
             ignore_buttons: false,
+            scrub_events: false,
+            scrub_distance: DEFAULT_SCRUB_DISTANCE,
+            expressions: false,
+            page_multiplier: DEFAULT_STEP_MULTIPLIER,
+            shift_multiplier: DEFAULT_STEP_MULTIPLIER,
+            format: NumberFormat::default(),
         }
     }
 
@@ -187,6 +231,117 @@ where
         self
     }
 
+    /// Enable or disable changing the value by dragging horizontally on the [`NumberInput`], by
+    /// default this is set to ``false``.
+    ///
+    /// The value changes by one step every [`scrub_distance`](Self::scrub_distance) pixels,
+    /// holding Shift makes the distance of a step ten times longer and holding Ctrl makes the
+    /// steps ten times larger. Scrubbing starts only while the input is not focused, so the text
+    /// of a focused input can still be selected by dragging.
+    #[must_use]
+    pub fn scrub(mut self, scrub: bool) -> Self {
+        self.scrub_events = scrub;
+        self
+    }
+
+    /// Sets the distance in pixels of dragging one step while scrubbing the [`NumberInput`], by
+    /// default this is set to ``5.0``.
+    #[must_use]
+    pub fn scrub_distance(mut self, pixels: f32) -> Self {
+        self.scrub_distance = pixels.max(1.0);
+        self
+    }
+
+    /// Enable or disable typing arithmetic expressions like ``=12*4+3`` or ``1/3`` into the
+    /// [`NumberInput`], by default this is set to ``false``.
+    ///
+    /// The expression supports ``+``, ``-``, ``*``, ``/`` (also as ``−``, ``×`` and ``÷``),
+    /// parentheses and ``%``. It is evaluated when the enter key is pressed or the input loses
+    /// its focus, and the result is clamped to the bounds. An expression that cannot be
+    /// evaluated is kept and drawn with the error style of the input.
+    #[must_use]
+    pub fn expressions(mut self, expressions: bool) -> Self {
+        self.expressions = expressions;
+        self
+    }
+
+    /// Sets the locale formatting the value of the [`NumberInput`] with its thousands separators,
+    /// decimal mark and minus sign, e.g. ``1.234,5`` for [`Locale::de`].
+    ///
+    /// Text typed in this format is parsed back into the value.
+    #[must_use]
+    pub fn locale(mut self, locale: Locale) -> Self
+    where
+        T: 'a,
+    {
+        self.format.locale = Some(locale);
+        self.apply_format()
+    }
+
+    /// Sets the number of decimal places shown for the value of the [`NumberInput`].
+    #[must_use]
+    pub fn precision(mut self, precision: usize) -> Self
+    where
+        T: 'a,
+    {
+        self.format.precision = Some(precision);
+        self.apply_format()
+    }
+
+    /// Sets the text shown in front of the value of the [`NumberInput`], e.g. a currency sign.
+    ///
+    /// Typed text may omit it.
+    #[must_use]
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self
+    where
+        T: 'a,
+    {
+        self.format.prefix = prefix.into();
+        self.apply_format()
+    }
+
+    /// Sets the text shown after the value of the [`NumberInput`], e.g. a unit like ``" %"``.
+    ///
+    /// Typed text may omit it.
+    #[must_use]
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self
+    where
+        T: 'a,
+    {
+        self.format.suffix = suffix.into();
+        self.apply_format()
+    }
+
+    /// Sets the functions formatting the value of the [`NumberInput`] and parsing the typed text
+    /// back, instead of its [`Display`] and [`FromStr`] implementations.
+    ///
+    /// They replace the [`precision`](Self::precision) and the [`locale`](Self::locale), while
+    /// the prefix and the suffix are still added to and removed from the text around them.
+    #[must_use]
+    pub fn format_with<F, P>(mut self, formatter: F, parser: P) -> Self
+    where
+        T: 'a,
+        F: 'a + Fn(T) -> String,
+        P: 'a + Fn(&str) -> Result<T, T::Err>,
+    {
+        self.format.formatter = Some(Rc::new(formatter));
+        self.format.parser = Some(Rc::new(parser));
+        self.apply_format()
+    }
+
+    /// Applies the formatting to the text of the [`NumberInput`].
+    fn apply_format(mut self) -> Self
+    where
+        T: 'a,
+    {
+        let format = self.format.clone();
+        self.content = self
+            .content
+            .formatter(|value| self.format.format(*value))
+            .parser(move |text| format.parse(text));
+        self
+    }
+
     /// Sets the message that should be produced when the [`NumberInput`] is
     /// focused and the enter key is pressed.
     #[must_use]
@@ -218,6 +373,22 @@ where
         self
     }
 
+    /// Sets the number of steps PageUp and PageDown change the value of the [`NumberInput`] by,
+    /// by default this is set to ``10``.
+    #[must_use]
+    pub fn page_multiplier(mut self, multiplier: u32) -> Self {
+        self.page_multiplier = multiplier.max(1);
+        self
+    }
+
+    /// Sets the number of steps the arrow keys change the value of the [`NumberInput`] by while
+    /// Shift is held, by default this is set to ``10``.
+    #[must_use]
+    pub fn shift_multiplier(mut self, multiplier: u32) -> Self {
+        self.shift_multiplier = multiplier.max(1);
+        self
+    }
+
     /// Sets the style of the [`NumberInput`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -256,6 +427,101 @@ where
         shell.publish((self.on_change)(self.value));
     }
 
+    /// Change current value by the given number of steps, within the bounds of the [`NumberInput`].
+    fn step_value(&mut self, steps: i32, shell: &mut Shell<Message>) {
+        let previous = self.value;
+
+        for _ in 0..steps.unsigned_abs() {
+            if steps > 0 {
+                if self.value > self.max - self.step {
+                    self.value = self.max;
+                } else {
+                    self.value += self.step;
+                }
+            } else if self.value < self.min + self.step {
+                self.value = self.min;
+            } else {
+                self.value -= self.step;
+            }
+        }
+
+        if self.value != previous {
+            shell.publish((self.on_change)(self.value));
+        }
+    }
+
+    /// Handles the event changing the text of the [`NumberInput`] to the given text, passing it to
+    /// the input if the text is a value within the bounds or part of an expression.
+    #[allow(clippy::too_many_arguments)]
+    fn on_event_typed_text(
+        &mut self,
+        text: &str,
+        event: Event,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+        viewport: &Rectangle,
+    ) -> event::Status
+    where
+        Message: 'a,
+        Renderer: 'a,
+    {
+        match self.format.parse(text) {
+            Ok(value) if value >= self.min && value <= self.max => {
+                self.value = value;
+                self.content.on_event(
+                    tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+                )
+            }
+            Ok(_) => event::Status::Captured,
+            Err(_) if self.expressions && self.format.is_expression(text) => {
+                let status = self.content.on_event(
+                    tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+                );
+                // Expressions are only checked once they are evaluated.
+                tree.state
+                    .downcast_mut::<typed_input::State>()
+                    .set_invalid(false);
+                status
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    /// Evaluates the expression typed into the [`NumberInput`], showing and publishing its result
+    /// clamped to the bounds.
+    fn evaluate_expression(&mut self, tree: &mut Tree, shell: &mut Shell<Message>) {
+        let typed = tree.state.downcast_mut::<typed_input::State>();
+
+        if self.format.parse(typed.text()).is_ok() {
+            return;
+        }
+
+        match self.format.evaluate(typed.text(), self.min, self.max) {
+            Some(value) => {
+                let value = if value < self.min {
+                    self.min
+                } else if value > self.max {
+                    self.max
+                } else {
+                    value
+                };
+
+                typed.set_text(&self.format.format(value));
+                if value != self.value {
+                    self.value = value;
+                    shell.publish((self.on_change)(value));
+                }
+            }
+            None => typed.set_invalid(true),
+        }
+
+        shell.invalidate_layout();
+    }
+
     fn set_min(min: Bound<&T>) -> T {
         match min {
             Bound::Included(n) | Bound::Excluded(n) => *n,
@@ -445,6 +711,27 @@ where
             return event::Status::Ignored;
         }
 
+        if self.expressions {
+            let child = &mut state.children[0];
+            let is_focused = child.children[0]
+                .state
+                .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+                .is_focused();
+            let modifiers = state.state.downcast_mut::<ModifierState>();
+            let was_focused = std::mem::replace(&mut modifiers.is_focused, is_focused);
+            let is_submitted = matches!(
+                event,
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(keyboard::key::Named::Enter),
+                    ..
+                })
+            );
+
+            if (was_focused && !is_focused) || (is_focused && is_submitted) {
+                self.evaluate_expression(child, shell);
+            }
+        }
+
         let cursor_position = cursor.position().unwrap_or_default();
         let mouse_over_widget = layout.bounds().contains(cursor_position);
         let mouse_over_inc = inc_bounds.contains(cursor_position);
@@ -452,12 +739,22 @@ where
         let mouse_over_button = mouse_over_inc || mouse_over_dec;
 
         let child = state.children.get_mut(0).expect("fail to get child");
-        let text_input = child
+        let text_input = child.children[0]
             .state
             .downcast_mut::<text_input::State<Renderer::Paragraph>>();
         let modifiers = state.state.downcast_mut::<ModifierState>();
 
-        let current_text = self.content.text().to_owned();
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(keyboard_modifiers)) = event {
+            modifiers.keyboard_modifiers = keyboard_modifiers;
+        }
+
+        let current_text = child
+            .state
+            .downcast_ref::<typed_input::State>()
+            .text()
+            .to_owned();
+        let format = &self.format;
+        let zero_text = format.format(T::zero());
 
         let mut forward_to_text = |event, shell, child, clipboard| {
             self.content.on_event(
@@ -484,39 +781,34 @@ where
                             forward_to_text(event, shell, child, clipboard)
                         } else if text == "\u{8}" {
                             // Backspace
-                            if current_text == T::zero().to_string() {
+                            if current_text == zero_text {
                                 return event::Status::Ignored;
                             }
                             let mut new_val = current_text;
                             match text_input.cursor().state(&Value::new(&new_val)) {
                                 cursor::State::Index(idx) if idx >= 1 && idx <= new_val.len() => {
-                                    _ = new_val.remove(idx - 1);
+                                    _ = new_val.remove(byte_index(&new_val, idx - 1));
                                 }
                                 cursor::State::Selection { start, end }
                                     if start <= new_val.len() && end <= new_val.len() =>
                                 {
-                                    new_val.replace_range(start.min(end)..start.max(end), "");
+                                    new_val.replace_range(
+                                        byte_index(&new_val, start.min(end))
+                                            ..byte_index(&new_val, start.max(end)),
+                                        "",
+                                    );
                                 }
                                 _ => return event::Status::Ignored,
                             }
 
                             if new_val.is_empty() {
-                                new_val = T::zero().to_string();
+                                new_val = zero_text;
                             }
 
-                            match T::from_str(&new_val) {
-                                Ok(val)
-                                    if val >= self.min && val <= self.max && val != self.value =>
-                                {
-                                    self.value = val;
-                                    forward_to_text(event, shell, child, clipboard)
-                                }
-                                Ok(val) if val >= self.min && val <= self.max => {
-                                    forward_to_text(event, shell, child, clipboard)
-                                }
-                                Ok(_) => event::Status::Captured,
-                                _ => event::Status::Ignored,
-                            }
+                            self.on_event_typed_text(
+                                &new_val, event, child, content, cursor, renderer, clipboard,
+                                shell, viewport,
+                            )
                         } else {
                             let input = if text == "\u{16}" {
                                 // CTRL + v
@@ -524,7 +816,9 @@ where
                                     Some(paste) => paste,
                                     None => return event::Status::Ignored,
                                 }
-                            } else if text.parse::<i64>().is_err() && text != "-" && text != "." {
+                            } else if !format.accepts(text)
+                                && !(self.expressions && format.is_expression(text))
+                            {
                                 return event::Status::Ignored;
                             } else {
                                 text.to_string()
@@ -535,32 +829,39 @@ where
                             let mut new_val = current_text;
                             match text_input.cursor().state(&Value::new(&new_val)) {
                                 cursor::State::Index(idx) if idx <= new_val.len() => {
-                                    new_val.insert_str(idx, input);
+                                    new_val.insert_str(byte_index(&new_val, idx), input);
                                 }
                                 cursor::State::Selection { start, end }
                                     if start <= new_val.len() && end <= new_val.len() =>
                                 {
-                                    new_val.replace_range(start.min(end)..end.max(start), input);
+                                    new_val.replace_range(
+                                        byte_index(&new_val, start.min(end))
+                                            ..byte_index(&new_val, end.max(start)),
+                                        input,
+                                    );
                                 }
                                 _ => return event::Status::Ignored,
                             }
 
-                            match T::from_str(&new_val) {
-                                Ok(val)
-                                    if val >= self.min && val <= self.max && val != self.value =>
-                                {
-                                    self.value = val;
-                                    forward_to_text(event, shell, child, clipboard)
-                                }
-                                Ok(val) if val >= self.min && val <= self.max => {
-                                    forward_to_text(event, shell, child, clipboard)
-                                }
-                                Ok(_) => event::Status::Captured,
-                                _ => event::Status::Ignored,
-                            }
+                            self.on_event_typed_text(
+                                &new_val, event, child, content, cursor, renderer, clipboard,
+                                shell, viewport,
+                            )
                         }
                     }
                     None => match key {
+                        keyboard::Key::Named(keyboard::key::Named::ArrowDown)
+                            if modifiers.keyboard_modifiers.shift() =>
+                        {
+                            self.step_value(-(self.shift_multiplier as i32), shell);
+                            event::Status::Captured
+                        }
+                        keyboard::Key::Named(keyboard::key::Named::ArrowUp)
+                            if modifiers.keyboard_modifiers.shift() =>
+                        {
+                            self.step_value(self.shift_multiplier as i32, shell);
+                            event::Status::Captured
+                        }
                         keyboard::Key::Named(keyboard::key::Named::ArrowDown) => {
                             self.decrease_value(shell);
                             event::Status::Captured
@@ -569,6 +870,14 @@ where
                             self.increase_value(shell);
                             event::Status::Captured
                         }
+                        keyboard::Key::Named(keyboard::key::Named::PageDown) => {
+                            self.step_value(-(self.page_multiplier as i32), shell);
+                            event::Status::Captured
+                        }
+                        keyboard::Key::Named(keyboard::key::Named::PageUp) => {
+                            self.step_value(self.page_multiplier as i32, shell);
+                            event::Status::Captured
+                        }
                         keyboard::Key::Named(
                             keyboard::key::Named::ArrowLeft
                             | keyboard::key::Named::ArrowRight
@@ -603,25 +912,104 @@ where
                     modifiers.increase_pressed = true;
                     self.increase_value(shell);
                 }
+
+                let next_repeat = Instant::now() + REPEAT_DELAY;
+                modifiers.next_repeat = Some(next_repeat);
+                modifiers.repeat_interval = REPEAT_INTERVAL;
+                shell.request_redraw(window::RedrawRequest::At(next_repeat));
                 event::Status::Captured
             }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-                if mouse_over_button =>
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if content.bounds().contains(cursor_position)
+                    && self.scrub_events
+                    && !text_input.is_focused() =>
             {
-                if mouse_over_dec {
-                    modifiers.decrease_pressed = false;
-                } else {
-                    modifiers.increase_pressed = false;
+                modifiers.scrub_position = Some(cursor_position.x);
+                modifiers.scrub_remainder = 0.0;
+                forward_to_text(event, shell, child, clipboard)
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                match modifiers.scrub_position {
+                    Some(scrub_position) => {
+                        let distance = if modifiers.keyboard_modifiers.shift() {
+                            self.scrub_distance * SCRUB_FACTOR as f32
+                        } else {
+                            self.scrub_distance
+                        };
+                        let scrubbed =
+                            modifiers.scrub_remainder + (position.x - scrub_position) / distance;
+                        let steps = scrubbed.trunc();
+
+                        modifiers.scrub_position = Some(position.x);
+                        modifiers.scrub_remainder = scrubbed - steps;
+
+                        if steps.abs() >= 1.0 {
+                            modifiers.is_scrubbing = true;
+                            let factor = if modifiers.keyboard_modifiers.control() {
+                                SCRUB_FACTOR
+                            } else {
+                                1
+                            };
+                            self.step_value(steps as i32 * factor, shell);
+                            event::Status::Captured
+                        } else if modifiers.is_scrubbing {
+                            event::Status::Captured
+                        } else {
+                            forward_to_text(event, shell, child, clipboard)
+                        }
+                    }
+                    None => forward_to_text(event, shell, child, clipboard),
                 }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if modifiers.scrub_position.is_some() =>
+            {
+                modifiers.scrub_position = None;
+                modifiers.is_scrubbing = false;
+                forward_to_text(event, shell, child, clipboard)
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if mouse_over_button
+                    || modifiers.decrease_pressed
+                    || modifiers.increase_pressed =>
+            {
+                modifiers.decrease_pressed = false;
+                modifiers.increase_pressed = false;
+                modifiers.next_repeat = None;
                 event::Status::Captured
             }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let now = *now;
+
+                if let Some(mut next_repeat) = modifiers.next_repeat {
+                    // Held buttons only repeat while the cursor stays over them.
+                    if now >= next_repeat {
+                        if modifiers.decrease_pressed && mouse_over_dec {
+                            self.step_value(-1, shell);
+                        } else if modifiers.increase_pressed && mouse_over_inc {
+                            self.step_value(1, shell);
+                        }
+
+                        modifiers.repeat_interval = modifiers
+                            .repeat_interval
+                            .mul_f32(REPEAT_ACCELERATION)
+                            .max(MIN_REPEAT_INTERVAL);
+                        next_repeat = now + modifiers.repeat_interval;
+                        modifiers.next_repeat = Some(next_repeat);
+                    }
+
+                    shell.request_redraw(window::RedrawRequest::At(next_repeat));
+                }
+
+                forward_to_text(event, shell, child, clipboard)
+            }
             _ => forward_to_text(event, shell, child, clipboard),
         }
     }
 
     fn mouse_interaction(
         &self,
-        _state: &Tree,
+        state: &Tree,
         layout: Layout<'_>,
         cursor: Cursor,
         _viewport: &Rectangle,
@@ -629,7 +1017,7 @@ where
     ) -> mouse::Interaction {
         let bounds = layout.bounds();
         let mut children = layout.children();
-        let _content_layout = children.next().expect("fail to get content layout");
+        let content_layout = children.next().expect("fail to get content layout");
         let mut mod_children = children
             .next()
             .expect("fail to get modifiers layout")
@@ -647,8 +1035,21 @@ where
         let is_increase_disabled = self.value >= self.max || self.min == self.max;
         let mouse_over_decrease = dec_bounds.contains(cursor.position().unwrap_or_default());
         let mouse_over_increase = inc_bounds.contains(cursor.position().unwrap_or_default());
+        let is_scrubbing = state.state.downcast_ref::<ModifierState>().is_scrubbing;
+        let is_focused = state.children[0].children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
 
-        if ((mouse_over_decrease && !is_decrease_disabled)
+        if is_scrubbing
+            || (self.scrub_events
+                && !is_focused
+                && content_layout
+                    .bounds()
+                    .contains(cursor.position().unwrap_or_default()))
+        {
+            mouse::Interaction::ResizingHorizontally
+        } else if ((mouse_over_decrease && !is_decrease_disabled)
             || (mouse_over_increase && !is_increase_disabled))
             && !self.ignore_buttons
         {
@@ -789,6 +1190,158 @@ where
     }
 }
 
+/// The formatting of the text of a [`NumberInput`].
+struct NumberFormat<'a, T>
+where
+    T: FromStr,
+{
+    /// The locale of the thousands separators, decimal mark and minus sign.
+    locale: Option<Locale>,
+    /// The number of decimal places.
+    precision: Option<usize>,
+    /// The text shown in front of the value.
+    prefix: String,
+    /// The text shown after the value.
+    suffix: String,
+    /// The custom function formatting the value.
+    formatter: Option<Rc<dyn Fn(T) -> String + 'a>>,
+    /// The custom function parsing the text.
+    parser: Option<Rc<dyn Fn(&str) -> Result<T, T::Err> + 'a>>,
+}
+
+impl<'a, T> NumberFormat<'a, T>
+where
+    T: Display + FromStr + Copy,
+{
+    /// Formats the value.
+    fn format(&self, value: T) -> String {
+        let number = match (&self.formatter, self.precision, self.locale) {
+            (Some(formatter), _, _) => formatter(value),
+            (None, Some(precision), locale) => localize(format!("{value:.precision$}"), locale),
+            (None, None, locale) => localize(value.to_string(), locale),
+        };
+
+        format!("{}{number}{}", self.prefix, self.suffix)
+    }
+
+    /// Removes the prefix and the suffix from the text.
+    fn strip<'t>(&self, text: &'t str) -> &'t str {
+        let text = text.trim();
+        let text = text.strip_prefix(self.prefix.trim()).unwrap_or(text);
+        text.strip_suffix(self.suffix.trim()).unwrap_or(text).trim()
+    }
+
+    /// Parses the text, with or without the prefix and the suffix.
+    fn parse(&self, text: &str) -> Result<T, T::Err> {
+        let text = self.strip(text);
+
+        match (&self.parser, self.locale) {
+            (Some(parser), _) => parser(text),
+            (None, Some(locale)) => parse_number(text, &locale),
+            (None, None) => T::from_str(text),
+        }
+    }
+
+    /// Evaluates the arithmetic expression of the text, with or without the prefix and the
+    /// suffix.
+    ///
+    /// The result is clamped to the bounds before it is converted, so it fits into the type, and
+    /// a result with a fraction is rounded if the type has none.
+    fn evaluate(&self, text: &str, min: T, max: T) -> Option<T> {
+        let text = self.strip(text);
+        let text = match self.locale {
+            Some(locale) => normalize_number(text, &locale),
+            None => text.to_owned(),
+        };
+        let bound = |bound: T| bound.to_string().parse::<f64>().ok();
+        let result = evaluate_expression(&text)?;
+        let result = bound(min).map_or(result, |min| result.max(min));
+        let result = bound(max).map_or(result, |max| result.min(max));
+
+        T::from_str(&result.to_string())
+            .or_else(|_| T::from_str(&result.round().to_string()))
+            .ok()
+    }
+
+    /// Checks if the text can be part of an arithmetic expression.
+    fn is_expression(&self, text: &str) -> bool {
+        self.strip(text).chars().all(|c| {
+            c.is_ascii_digit()
+                || c.is_whitespace()
+                || "+-−*×/÷()%=.,".contains(c)
+                || self.locale.is_some_and(|locale| {
+                    [locale.decimal(), locale.separator(), locale.minus_sign()]
+                        .iter()
+                        .any(|text| text.contains(c))
+                })
+        })
+    }
+
+    /// Checks if the typed text can be part of a formatted number, including its prefix and
+    /// suffix.
+    fn accepts(&self, text: &str) -> bool {
+        self.parser.is_some()
+            || text.parse::<i64>().is_ok()
+            || text == "-"
+            || text == "."
+            || self.locale.is_some_and(|locale| {
+                [locale.decimal(), locale.separator(), locale.minus_sign()].contains(&text)
+            })
+            || (!text.is_empty()
+                && text
+                    .chars()
+                    .all(|c| self.prefix.contains(c) || self.suffix.contains(c)))
+    }
+}
+
+impl<'a, T> Clone for NumberFormat<'a, T>
+where
+    T: FromStr,
+{
+    fn clone(&self) -> Self {
+        Self {
+            locale: self.locale,
+            precision: self.precision,
+            prefix: self.prefix.clone(),
+            suffix: self.suffix.clone(),
+            formatter: self.formatter.clone(),
+            parser: self.parser.clone(),
+        }
+    }
+}
+
+impl<'a, T> Default for NumberFormat<'a, T>
+where
+    T: FromStr,
+{
+    fn default() -> Self {
+        Self {
+            locale: None,
+            precision: None,
+            prefix: String::new(),
+            suffix: String::new(),
+            formatter: None,
+            parser: None,
+        }
+    }
+}
+
+/// Gets the byte index of the character with the given index in the text.
+fn byte_index(text: &str, index: usize) -> usize {
+    text.char_indices()
+        .nth(index)
+        .map_or(text.len(), |(byte_index, _)| byte_index)
+}
+
+/// Formats the text of a number with the thousands separators and decimal
+/// mark of the locale.
+fn localize(number: String, locale: Option<Locale>) -> String {
+    match locale {
+        Some(locale) => format_number(&number, &locale),
+        None => number,
+    }
+}
+
 /// The modifier state of a [`NumberInput`].
 #[derive(Default, Clone, Debug)]
 pub struct ModifierState {
@@ -796,6 +1349,21 @@ pub struct ModifierState {
     pub decrease_pressed: bool,
     /// The state of increase button on a [`NumberInput`].
     pub increase_pressed: bool,
+    /// The horizontal position of the cursor while the mouse is pressed on a [`NumberInput`] that
+    /// can be scrubbed.
+    pub scrub_position: Option<f32>,
+    /// The part of a step dragged but not yet applied while scrubbing a [`NumberInput`].
+    pub scrub_remainder: f32,
+    /// Whether the value of a [`NumberInput`] is changed by dragging.
+    pub is_scrubbing: bool,
+    /// The pressed keyboard modifiers.
+    pub keyboard_modifiers: keyboard::Modifiers,
+    /// Whether the input of a [`NumberInput`] was focused at the last event.
+    pub is_focused: bool,
+    /// The time of the next repeated step while a button of a [`NumberInput`] is held.
+    pub next_repeat: Option<Instant>,
+    /// The current interval of the repeated steps while a button of a [`NumberInput`] is held.
+    pub repeat_interval: Duration,
 }
 
 impl<'a, T, Message, Theme, Renderer> From<NumberInput<'a, T, Message, Theme, Renderer>>
@@ -810,3 +1378,127 @@ where
         Element::new(num_input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use num_format::Locale;
+
+    use super::{typed_input, NumberFormat};
+
+    #[test]
+    fn format_precision_test() {
+        let format = NumberFormat::<f64> {
+            precision: Some(2),
+            ..NumberFormat::default()
+        };
+
+        assert_eq!(format.format(1.5), "1.50");
+        assert_eq!(format.format(-0.126), "-0.13");
+        assert_eq!(format.parse("1.50"), Ok(1.5));
+        assert_eq!(format.parse(&format.format(42.0)), Ok(42.0));
+    }
+
+    #[test]
+    fn format_prefix_suffix_test() {
+        let format = NumberFormat::<f64> {
+            prefix: "$".to_owned(),
+            suffix: " %".to_owned(),
+            ..NumberFormat::default()
+        };
+
+        assert_eq!(format.format(12.5), "$12.5 %");
+        assert_eq!(format.parse("$12.5 %"), Ok(12.5));
+        assert_eq!(format.parse("12.5"), Ok(12.5));
+        assert_eq!(format.parse(" $ 12.5"), Ok(12.5));
+        assert!(format.parse("12.5 €").is_err());
+    }
+
+    #[test]
+    fn format_locale_test() {
+        let format = NumberFormat::<f64> {
+            locale: Some(Locale::de),
+            precision: Some(2),
+            suffix: " €".to_owned(),
+            ..NumberFormat::default()
+        };
+
+        assert_eq!(format.format(1234.5), "1.234,50 €");
+        assert_eq!(format.parse("1.234,50 €"), Ok(1234.5));
+
+        let format = NumberFormat::<i32> {
+            locale: Some(Locale::en),
+            ..NumberFormat::default()
+        };
+
+        for value in [0, 999, -1_234_567] {
+            assert_eq!(format.parse(&format.format(value)), Ok(value));
+        }
+    }
+
+    #[test]
+    fn typing_test() {
+        let formats = [
+            NumberFormat::<f64> {
+                locale: Some(Locale::en),
+                ..NumberFormat::default()
+            },
+            NumberFormat::<f64> {
+                precision: Some(2),
+                ..NumberFormat::default()
+            },
+        ];
+
+        for format in formats {
+            let mut state = typed_input::State::new(&format.format(0.0));
+            let mut typed = String::new();
+
+            for c in "12345.5".chars() {
+                typed.push(c);
+                let value = format.parse(&typed).expect("typed text should be a number");
+                state.set_typed_text(typed.clone(), true);
+                state.sync(&format.format(value), &value, |text| format.parse(text));
+                assert_eq!(state.text(), typed);
+            }
+
+            state.sync(&format.format(2.0), &2.0, |text| format.parse(text));
+            assert_eq!(state.text(), format.format(2.0));
+        }
+    }
+
+    #[test]
+    fn evaluate_test() {
+        let format = NumberFormat::<u8>::default();
+
+        assert_eq!(format.evaluate("=12*4+3", u8::MIN, u8::MAX), Some(51));
+        assert_eq!(format.evaluate("10/4", u8::MIN, u8::MAX), Some(3));
+        assert_eq!(format.evaluate("=200*2", u8::MIN, u8::MAX), Some(u8::MAX));
+        assert_eq!(format.evaluate("1-2", u8::MIN, u8::MAX), Some(u8::MIN));
+        assert_eq!(format.evaluate("=50*3", 10, 100), Some(100));
+        assert_eq!(format.evaluate("2*(3", u8::MIN, u8::MAX), None);
+
+        let format = NumberFormat::<f64> {
+            locale: Some(Locale::de),
+            ..NumberFormat::default()
+        };
+
+        assert_eq!(format.evaluate("1,5*3", -10.0, 10.0), Some(4.5));
+        assert_eq!(format.evaluate("-1.000", -10.0, 10.0), Some(-10.0));
+    }
+
+    #[test]
+    fn accepts_test() {
+        let format = NumberFormat::<f64> {
+            locale: Some(Locale::de),
+            prefix: "ca. ".to_owned(),
+            suffix: " °C".to_owned(),
+            ..NumberFormat::default()
+        };
+
+        for text in ["5", "-", ".", ",", "°", "C", " ", "ca"] {
+            assert!(format.accepts(text), "{text:?} should be accepted");
+        }
+        for text in ["", "x", "€", "%"] {
+            assert!(!format.accepts(text), "{text:?} should not be accepted");
+        }
+    }
+}
@@ -6,8 +6,11 @@ use crate::{
     color_picker,
     core::icons::bootstrap::{icon_to_string, Bootstrap},
     core::{
-        color::{HexString, Hsv},
-        overlay::Position,
+        color::{parse_color, Cmyk, HexString, Hsl, Hsv, Oklab, Oklch},
+        overlay::{
+            text_input::{self, TextMessage},
+            Position,
+        },
     },
     style::{self, color_picker::Style, style_state::StyleState, Status},
 };
@@ -28,7 +31,7 @@ use iced::{
     touch,
     widget::{
         canvas::{self, LineCap, Path, Stroke},
-        text, Button, Column, Row,
+        text, Button, Column, Row, TextInput,
     },
     Alignment,
     Border,
@@ -54,18 +57,22 @@ const SPACING: f32 = 15.0;
 const BUTTON_SPACING: f32 = 5.0;
 
 /// The step value of the keyboard change of the sat/value color values.
-const SAT_VALUE_STEP: f32 = 0.005;
+pub(crate) const SAT_VALUE_STEP: f32 = 0.005;
 /// The step value of the keyboard change of the hue color value.
-const HUE_STEP: i32 = 1;
-/// The step value of the keyboard change of the RGBA color values.
-const RGBA_STEP: i16 = 1;
+pub(crate) const HUE_STEP: i32 = 1;
+/// The step value of the keyboard change of the alpha value.
+const ALPHA_STEP: i16 = 1;
+/// The width and height of a swatch.
+const SWATCH_SIZE: f32 = 20.0;
 
 /// The overlay of the [`ColorPicker`](crate::widgets::ColorPicker).
 #[allow(missing_debug_implementations)]
 pub struct ColorPickerOverlay<'a, 'b, Message, Theme>
 where
     Message: Clone,
-    Theme: style::color_picker::Catalog + iced::widget::button::Catalog,
+    Theme: style::color_picker::Catalog
+        + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog,
     'b: 'a,
 {
     /// The state of the [`ColorPickerOverlay`].
@@ -74,8 +81,16 @@ where
     cancel_button: Button<'a, Message, Theme, Renderer>,
     /// The submit button of the [`ColorPickerOverlay`].
     submit_button: Button<'a, Message, Theme, Renderer>,
+    /// The text input of the [`ColorPickerOverlay`], in which a color can be typed.
+    text_input: TextInput<'a, TextMessage, Theme, Renderer>,
     /// The function that produces a message when the submit button of the [`ColorPickerOverlay`].
     on_submit: &'a dyn Fn(Color) -> Message,
+    /// The function that produces a message while the color of the [`ColorPickerOverlay`] is changed.
+    on_change: Option<&'a dyn Fn(Color) -> Message>,
+    /// The palette shown as swatches before the recent colors.
+    palette: &'a [Color],
+    /// The maximum number of recently submitted colors shown as swatches.
+    recent_colors: usize,
     /// The position of the [`ColorPickerOverlay`].
     position: Point,
     /// The style of the [`ColorPickerOverlay`].
@@ -90,6 +105,7 @@ where
     Theme: 'a
         + style::color_picker::Catalog
         + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog
         + iced::widget::text::Catalog,
     'b: 'a,
 {
@@ -98,12 +114,15 @@ where
         state: &'a mut color_picker::State,
         on_cancel: Message,
         on_submit: &'a dyn Fn(Color) -> Message,
+        on_change: Option<&'a dyn Fn(Color) -> Message>,
+        palette: &'a [Color],
+        recent_colors: usize,
         position: Point,
         class: &'a <Theme as style::color_picker::Catalog>::Class<'b>,
         tree: &'a mut Tree,
     ) -> Self {
         //state.color_hex = color_picker::State::color_as_string(state.color);
-        let color_picker::State { overlay_state } = state;
+        let color_picker::State { overlay_state, .. } = state;
 
         ColorPickerOverlay {
             state: overlay_state,
@@ -123,7 +142,11 @@ where
             )
             .width(Length::Fill)
             .on_press(on_cancel), // Sending a fake message
+            text_input: text_input::text_input("#rrggbbaa", &overlay_state.text()),
             on_submit,
+            on_change,
+            palette,
+            recent_colors,
             position,
             class,
             tree,
@@ -194,51 +217,20 @@ where
             _ => {}
         }
 
-        let calc_percentage_sat =
-            |cursor_position: Point| (cursor_position.x.max(0.0) / sat_value_bounds.width).min(1.0);
-
-        let calc_percentage_value = |cursor_position: Point| {
-            (cursor_position.y.max(0.0) / sat_value_bounds.height).min(1.0)
-        };
-
-        let calc_hue = |cursor_position: Point| {
-            ((cursor_position.x.max(0.0) / hue_bounds.width).min(1.0) * 360.0) as u16
+        let dragged_color = match (self.state.color_bar_dragged, cursor.position()) {
+            (ColorBarDragged::SatValue, Some(position)) => {
+                Some(sat_value_at(hsv_color, sat_value_bounds, position))
+            }
+            (ColorBarDragged::Hue, Some(position)) => Some(hue_at(hsv_color, hue_bounds, position)),
+            _ => None,
         };
 
-        match self.state.color_bar_dragged {
-            ColorBarDragged::SatValue => {
-                self.state.color = Color {
-                    a: self.state.color.a,
-                    ..Hsv {
-                        saturation: cursor
-                            .position_in(sat_value_bounds)
-                            .map(calc_percentage_sat)
-                            .unwrap_or_default(),
-                        value: cursor
-                            .position_in(sat_value_bounds)
-                            .map(calc_percentage_value)
-                            .unwrap_or_default(),
-                        ..hsv_color
-                    }
-                    .into()
-                };
-                color_changed = true;
-            }
-            ColorBarDragged::Hue => {
-                self.state.color = Color {
-                    a: self.state.color.a,
-                    ..Hsv {
-                        hue: cursor
-                            .position_in(hue_bounds)
-                            .map(calc_hue)
-                            .unwrap_or_default(),
-                        ..hsv_color
-                    }
-                    .into()
-                };
-                color_changed = true;
-            }
-            _ => {}
+        if let Some(hsv_color) = dragged_color {
+            self.state.color = Color {
+                a: self.state.color.a,
+                ..hsv_color.into()
+            };
+            color_changed = true;
         }
 
         if color_changed {
@@ -248,56 +240,37 @@ where
         }
     }
 
-    /// The event handling for the RGBA color area.
-    #[allow(clippy::too_many_lines)]
-    fn on_event_rgba_color(
+    /// The event handling for the color model and the bars of its channels.
+    fn on_event_color_channels(
         &mut self,
         event: &Event,
         layout: Layout<'_>,
         cursor: Cursor,
     ) -> event::Status {
-        let mut rgba_color_children = layout.children();
-        let mut color_changed = false;
-
-        let mut red_row_children = rgba_color_children
-            .next()
-            .expect("widgets: Layout should have a red row layout")
-            .children();
-        let _ = red_row_children.next();
-        let red_bar_bounds = red_row_children
-            .next()
-            .expect("widgets: Layout should have a red bar layout")
-            .bounds();
+        let mut color_channels_children = layout.children();
+        let model = self.state.color_model;
+        let mut status = event::Status::Ignored;
 
-        let mut green_row_children = rgba_color_children
+        let model_layout = color_channels_children
             .next()
-            .expect("widgets: Layout should have a green row layout")
-            .children();
-        let _ = green_row_children.next();
-        let green_bar_bounds = green_row_children
+            .expect("widgets: Layout should have a color model layout");
+        let mut model_children = model_layout.children();
+        let model_left_bounds = model_children
             .next()
-            .expect("widgets: Layout should have a green bar layout")
+            .expect("widgets: Layout should have a left color model arrow layout")
             .bounds();
 
-        let mut blue_row_children = rgba_color_children
-            .next()
-            .expect("widgets: Layout should have a blue row layout")
-            .children();
-        let _ = blue_row_children.next();
-        let blue_bar_bounds = blue_row_children
-            .next()
-            .expect("widgets: Layout should have a blue bar layout")
-            .bounds();
+        let channel_bounds: Vec<Rectangle> = color_channels_children
+            .by_ref()
+            .take(model.channel_count())
+            .map(bar_bounds)
+            .collect();
 
-        let mut alpha_row_children = rgba_color_children
-            .next()
-            .expect("widgets: Layout should have an alpha row layout")
-            .children();
-        let _ = alpha_row_children.next();
-        let alpha_bar_bounds = alpha_row_children
-            .next()
-            .expect("widgets: Layout should have an alpha bar layout")
-            .bounds();
+        let alpha_bar_bounds = bar_bounds(
+            color_channels_children
+                .next()
+                .expect("widgets: Layout should have an alpha row layout"),
+        );
 
         match event {
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => match delta {
@@ -306,49 +279,37 @@ where
                         //|value: f32, y: f32| (value * 255.0 + y).clamp(0.0, 255.0) / 255.0;
                         |value: f32, y: f32| value.mul_add(255.0, y).clamp(0.0, 255.0) / 255.0;
 
-                    if cursor.is_over(red_bar_bounds) {
-                        self.state.color = Color {
-                            r: move_value(self.state.color.r, *y),
-                            ..self.state.color
-                        };
-                        color_changed = true;
-                    }
-                    if cursor.is_over(green_bar_bounds) {
-                        self.state.color = Color {
-                            g: move_value(self.state.color.g, *y),
-                            ..self.state.color
-                        };
-                        color_changed = true;
-                    }
-                    if cursor.is_over(blue_bar_bounds) {
-                        self.state.color = Color {
-                            b: move_value(self.state.color.b, *y),
-                            ..self.state.color
-                        };
-                        color_changed = true;
+                    for (channel, bounds) in channel_bounds.iter().enumerate() {
+                        if cursor.is_over(*bounds) {
+                            self.state.step_channel(channel, *y);
+                            status = event::Status::Captured;
+                        }
                     }
                     if cursor.is_over(alpha_bar_bounds) {
                         self.state.color = Color {
                             a: move_value(self.state.color.a, *y),
                             ..self.state.color
                         };
-                        color_changed = true;
+                        status = event::Status::Captured;
                     }
                 }
             },
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if cursor.is_over(red_bar_bounds) {
-                    self.state.color_bar_dragged = ColorBarDragged::Red;
-                    self.state.focus = Focus::Red;
-                }
-                if cursor.is_over(green_bar_bounds) {
-                    self.state.color_bar_dragged = ColorBarDragged::Green;
-                    self.state.focus = Focus::Green;
+                if cursor.is_over(model_layout.bounds()) {
+                    self.state.focus = Focus::Model;
+                    self.state.color_model = if cursor.is_over(model_left_bounds) {
+                        model.previous()
+                    } else {
+                        model.next()
+                    };
+                    status = event::Status::Captured;
                 }
-                if cursor.is_over(blue_bar_bounds) {
-                    self.state.color_bar_dragged = ColorBarDragged::Blue;
-                    self.state.focus = Focus::Blue;
+                for (channel, bounds) in channel_bounds.iter().enumerate() {
+                    if cursor.is_over(*bounds) {
+                        self.state.color_bar_dragged = ColorBarDragged::Channel(channel);
+                        self.state.focus = Focus::Channel(channel);
+                    }
                 }
                 if cursor.is_over(alpha_bar_bounds) {
                     self.state.color_bar_dragged = ColorBarDragged::Alpha;
@@ -367,35 +328,17 @@ where
         };
 
         match self.state.color_bar_dragged {
-            ColorBarDragged::Red => {
-                self.state.color = Color {
-                    r: cursor
-                        .position_in(red_bar_bounds)
-                        .map(|position| calc_percentage(red_bar_bounds, position))
-                        .unwrap_or_default(),
-                    ..self.state.color
-                };
-                color_changed = true;
-            }
-            ColorBarDragged::Green => {
-                self.state.color = Color {
-                    g: cursor
-                        .position_in(green_bar_bounds)
-                        .map(|position| calc_percentage(green_bar_bounds, position))
-                        .unwrap_or_default(),
-                    ..self.state.color
-                };
-                color_changed = true;
-            }
-            ColorBarDragged::Blue => {
-                self.state.color = Color {
-                    b: cursor
-                        .position_in(blue_bar_bounds)
-                        .map(|position| calc_percentage(blue_bar_bounds, position))
-                        .unwrap_or_default(),
-                    ..self.state.color
-                };
-                color_changed = true;
+            ColorBarDragged::Channel(channel) => {
+                if let Some(bounds) = channel_bounds.get(channel) {
+                    self.state.set_channel(
+                        channel,
+                        cursor
+                            .position_in(*bounds)
+                            .map(|position| calc_percentage(*bounds, position))
+                            .unwrap_or_default(),
+                    );
+                    status = event::Status::Captured;
+                }
             }
             ColorBarDragged::Alpha => {
                 self.state.color = Color {
@@ -405,16 +348,119 @@ where
                         .unwrap_or_default(),
                     ..self.state.color
                 };
-                color_changed = true;
+                status = event::Status::Captured;
             }
             _ => {}
         }
 
-        if color_changed {
-            event::Status::Captured
-        } else {
-            event::Status::Ignored
+        status
+    }
+
+    /// Gets the number of swatches, made of the palette followed by the
+    /// recent colors.
+    fn swatch_count(&self) -> usize {
+        self.palette.len() + self.state.recent_colors.len().min(self.recent_colors)
+    }
+
+    /// Gets the color of the swatch with the given index.
+    fn swatch(&self, index: usize) -> Option<Color> {
+        self.palette
+            .iter()
+            .chain(self.state.recent_colors.iter().take(self.recent_colors))
+            .nth(index)
+            .copied()
+    }
+
+    /// Publishes the submit message with the given color and remembers it as
+    /// the most recent color.
+    fn submit(&mut self, color: Color, shell: &mut Shell<Message>) {
+        self.state.push_recent_color(color, self.recent_colors);
+        shell.publish((self.on_submit)(color));
+    }
+
+    /// Publishes the change message if the color differs from the given
+    /// previous color.
+    fn publish_change(&self, previous_color: Color, shell: &mut Shell<Message>) {
+        if let Some(on_change) = self.on_change {
+            if self.state.color != previous_color {
+                shell.publish(on_change(self.state.color));
+            }
+        }
+    }
+
+    /// The event handling for the swatches.
+    fn on_event_swatches(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) -> event::Status {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            let clicked = layout
+                .children()
+                .position(|swatch| cursor.is_over(swatch.bounds()));
+
+            if let Some((index, color)) =
+                clicked.and_then(|index| self.swatch(index).map(|color| (index, color)))
+            {
+                self.state.color = color;
+                self.state.focus = Focus::Swatch(index);
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    /// The event handling for the text input, in which a color can be typed.
+    fn on_event_text_input(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let (status, messages) = text_input::on_event(
+            &mut self.text_input,
+            &mut self.tree.children[2],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            || self.state.focus = Focus::None,
+        );
+
+        for message in messages {
+            match message {
+                TextMessage::Input(text) => {
+                    if let Some(color) = parse_color(&text) {
+                        self.state.color = color;
+                        self.state.sat_value_canvas_cache.clear();
+                        self.state.hue_canvas_cache.clear();
+                    }
+                    self.state.text = Some(text);
+                }
+                TextMessage::Submit => {
+                    let color = self
+                        .state
+                        .text
+                        .as_deref()
+                        .map_or(Some(self.state.color), parse_color);
+
+                    if let Some(color) = color {
+                        self.submit(color, shell);
+                    }
+                }
+            }
         }
+
+        status
     }
 
     /// The even handling for the keyboard input.
@@ -426,11 +472,14 @@ where
         if let Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) = event {
             let mut status = event::Status::Ignored;
 
+            let channel_count = self.state.color_model.channel_count();
+            let swatch_count = self.swatch_count();
+
             if matches!(key, keyboard::Key::Named(keyboard::key::Named::Tab)) {
                 if self.state.keyboard_modifiers.shift() {
-                    self.state.focus = self.state.focus.previous();
+                    self.state.focus = self.state.focus.previous(channel_count, swatch_count);
                 } else {
-                    self.state.focus = self.state.focus.next();
+                    self.state.focus = self.state.focus.next(channel_count, swatch_count);
                 }
                 // TODO: maybe place this better
                 self.state.sat_value_canvas_cache.clear();
@@ -502,7 +551,37 @@ where
                     status
                 };
 
-                let rgba_bar_handle = |key_code: &keyboard::Key, value: &mut f32| {
+                let channel_handle =
+                    |key_code: &keyboard::Key, state: &mut State, channel: usize| {
+                        let steps = match key_code {
+                            keyboard::Key::Named(
+                                keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowDown,
+                            ) => -1.0,
+                            keyboard::Key::Named(
+                                keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowUp,
+                            ) => 1.0,
+                            _ => return event::Status::Ignored,
+                        };
+
+                        state.step_channel(channel, steps);
+                        event::Status::Captured
+                    };
+
+                let model_handle = |key_code: &keyboard::Key, model: &mut ColorModel| {
+                    match key_code {
+                        keyboard::Key::Named(
+                            keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowDown,
+                        ) => *model = model.previous(),
+                        keyboard::Key::Named(
+                            keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowUp,
+                        ) => *model = model.next(),
+                        _ => return event::Status::Ignored,
+                    }
+
+                    event::Status::Captured
+                };
+
+                let alpha_bar_handle = |key_code: &keyboard::Key, value: &mut f32| {
                     let mut byte_value = (*value * 255.0) as i16;
                     let mut status = event::Status::Captured;
 
@@ -510,13 +589,13 @@ where
                         keyboard::Key::Named(
                             keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowDown,
                         ) => {
-                            byte_value -= RGBA_STEP;
+                            byte_value -= ALPHA_STEP;
                             status = event::Status::Captured;
                         }
                         keyboard::Key::Named(
                             keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowUp,
                         ) => {
-                            byte_value += RGBA_STEP;
+                            byte_value += ALPHA_STEP;
                             status = event::Status::Captured;
                         }
                         _ => {}
@@ -529,10 +608,33 @@ where
                 match self.state.focus {
                     Focus::SatValue => status = sat_value_handle(key, &mut self.state.color),
                     Focus::Hue => status = hue_handle(key, &mut self.state.color),
-                    Focus::Red => status = rgba_bar_handle(key, &mut self.state.color.r),
-                    Focus::Green => status = rgba_bar_handle(key, &mut self.state.color.g),
-                    Focus::Blue => status = rgba_bar_handle(key, &mut self.state.color.b),
-                    Focus::Alpha => status = rgba_bar_handle(key, &mut self.state.color.a),
+                    Focus::Model => status = model_handle(key, &mut self.state.color_model),
+                    Focus::Channel(channel) if channel < channel_count => {
+                        status = channel_handle(key, self.state, channel);
+                    }
+                    Focus::Alpha => status = alpha_bar_handle(key, &mut self.state.color.a),
+                    Focus::Swatch(swatch) => {
+                        status = event::Status::Captured;
+                        match key {
+                            keyboard::Key::Named(
+                                keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowUp,
+                            ) => self.state.focus = Focus::Swatch(swatch.saturating_sub(1)),
+                            keyboard::Key::Named(
+                                keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowDown,
+                            ) => {
+                                self.state.focus =
+                                    Focus::Swatch((swatch + 1).min(swatch_count.saturating_sub(1)));
+                            }
+                            keyboard::Key::Named(
+                                keyboard::key::Named::Enter | keyboard::key::Named::Space,
+                            ) => {
+                                if let Some(color) = self.swatch(swatch) {
+                                    self.state.color = color;
+                                }
+                            }
+                            _ => status = event::Status::Ignored,
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -554,6 +656,7 @@ where
     Theme: 'a
         + style::color_picker::Catalog
         + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog
         + iced::widget::text::Catalog,
 {
     fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
@@ -629,9 +732,18 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
     ) -> event::Status {
+        let color_model = self.state.color_model;
+        let initial_color = self.state.color;
+        let previous_color = self.state.color;
+
         if event::Status::Captured == self.on_event_keyboard(&event) {
             self.state.sat_value_canvas_cache.clear();
             self.state.hue_canvas_cache.clear();
+            self.state.sync_text(previous_color);
+            self.publish_change(initial_color, shell);
+            if self.state.color_model != color_model {
+                shell.invalidate_layout();
+            }
             return event::Status::Captured;
         }
 
@@ -652,18 +764,43 @@ where
             .expect("widgets: Layout should have a 2. block layout")
             .children();
 
-        // ----------- RGB Color -----------------------
-        let rgba_color_layout = block2_children
+        // ----------- Color channels -----------------
+        let color_channels_layout = block2_children
             .next()
-            .expect("widgets: Layout should have a RGBA color layout");
-        let rgba_color_status = self.on_event_rgba_color(&event, rgba_color_layout, cursor);
+            .expect("widgets: Layout should have a color channels layout");
+        let color_channels_status =
+            self.on_event_color_channels(&event, color_channels_layout, cursor);
+
+        if self.state.color_model != color_model {
+            shell.invalidate_layout();
+        }
+        self.state.sync_text(previous_color);
 
         let mut fake_messages: Vec<Message> = Vec::new();
 
         // ----------- Text input ----------------------
-        let _text_input_layout = block2_children
+        let text_input_layout = block2_children
+            .next()
+            .expect("widgets: Layout should have a hex text layout")
+            .children()
+            .nth(1)
+            .expect("widgets: Layout should have a text input layout");
+        let text_input_status = self.on_event_text_input(
+            &event,
+            text_input_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        // ----------- Swatches ------------------------
+        let previous_color = self.state.color;
+        let swatches_layout = block2_children
             .next()
-            .expect("widgets: Layout should have a hex text layout");
+            .expect("widgets: Layout should have a swatches layout");
+        let swatches_status = self.on_event_swatches(&event, swatches_layout, cursor);
+        self.state.sync_text(previous_color);
 
         // ----------- Buttons -------------------------
         let cancel_button_layout = block2_children
@@ -694,13 +831,16 @@ where
             &layout.bounds(),
         );
 
+        self.publish_change(initial_color, shell);
+
         if !fake_messages.is_empty() {
-            shell.publish((self.on_submit)(self.state.color));
+            self.submit(self.state.color, shell);
         }
         // ----------- Block 2 end ------------------
 
         if hsv_color_status == event::Status::Captured
-            || rgba_color_status == event::Status::Captured
+            || color_channels_status == event::Status::Captured
+            || swatches_status == event::Status::Captured
         {
             self.state.sat_value_canvas_cache.clear();
             self.state.hue_canvas_cache.clear();
@@ -708,7 +848,9 @@ where
 
         status
             .merge(hsv_color_status)
-            .merge(rgba_color_status)
+            .merge(color_channels_status)
+            .merge(text_input_status)
+            .merge(swatches_status)
             .merge(cancel_button_status)
             .merge(submit_button_status)
     }
@@ -750,11 +892,18 @@ where
             .expect("Graphics: Layout should have a 2. block layout");
         let mut block2_mouse_interaction = mouse::Interaction::default();
         let mut block2_children = block2_layout.children();
-        // RGBA color
-        let rgba_color_layout = block2_children
+        // Color channels
+        let color_channels_layout = block2_children
+            .next()
+            .expect("Graphics: Layout should have a color channels layout");
+        let mut color_channels_children = color_channels_layout.children();
+
+        let model_layout = color_channels_children
             .next()
-            .expect("Graphics: Layout should have a RGBA color layout");
-        let mut rgba_color_children = rgba_color_layout.children();
+            .expect("Graphics: Layout should have a color model layout");
+        if cursor.is_over(model_layout.bounds()) {
+            block2_mouse_interaction = block2_mouse_interaction.max(mouse::Interaction::Pointer);
+        }
 
         let f = |layout: Layout<'_>, cursor: Cursor| {
             let mut children = layout.children();
@@ -770,24 +919,38 @@ where
                 mouse::Interaction::default()
             }
         };
-        let red_row_layout = rgba_color_children
-            .next()
-            .expect("Graphics: Layout should have a red row layout");
-        block2_mouse_interaction = block2_mouse_interaction.max(f(red_row_layout, cursor));
-        let green_row_layout = rgba_color_children
-            .next()
-            .expect("Graphics: Layout should have a green row layout");
-        block2_mouse_interaction = block2_mouse_interaction.max(f(green_row_layout, cursor));
-        let blue_row_layout = rgba_color_children
-            .next()
-            .expect("Graphics: Layout should have a blue row layout");
-        block2_mouse_interaction = block2_mouse_interaction.max(f(blue_row_layout, cursor));
-        let alpha_row_layout = rgba_color_children
+        // The rows of the channels followed by the alpha row
+        for row_layout in color_channels_children {
+            block2_mouse_interaction = block2_mouse_interaction.max(f(row_layout, cursor));
+        }
+
+        // Text input
+        let text_input_layout = block2_children
             .next()
-            .expect("Graphics: Layout should have an alpha row layout");
-        block2_mouse_interaction = block2_mouse_interaction.max(f(alpha_row_layout, cursor));
+            .expect("Graphics: Layout should have a hex text layout")
+            .children()
+            .nth(1)
+            .expect("Graphics: Layout should have a text input layout");
+        let text_input_mouse_interaction =
+            Widget::<TextMessage, Theme, Renderer>::mouse_interaction(
+                &self.text_input,
+                &self.tree.children[2],
+                text_input_layout,
+                cursor,
+                viewport,
+                renderer,
+            );
 
-        let _hex_text_layout = block2_children.next();
+        // Swatches
+        let swatches_layout = block2_children
+            .next()
+            .expect("Graphics: Layout should have a swatches layout");
+        if swatches_layout
+            .children()
+            .any(|swatch| cursor.is_over(swatch.bounds()))
+        {
+            block2_mouse_interaction = block2_mouse_interaction.max(mouse::Interaction::Pointer);
+        }
 
         // Buttons
         let cancel_button_layout = block2_children
@@ -815,6 +978,7 @@ where
         mouse_interaction
             .max(block1_mouse_interaction)
             .max(block2_mouse_interaction)
+            .max(text_input_mouse_interaction)
             .max(cancel_mouse_interaction)
             .max(submit_mouse_interaction)
     }
@@ -905,6 +1069,7 @@ where
     Theme: 'a
         + style::color_picker::Catalog
         + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog
         + iced::widget::text::Catalog,
 {
     let block1_limits = Limits::new(Size::ZERO, bounds.size())
@@ -939,6 +1104,7 @@ where
     Theme: 'a
         + style::color_picker::Catalog
         + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog
         + iced::widget::text::Catalog,
 {
     let block2_limits = Limits::new(Size::ZERO, bounds.size())
@@ -953,23 +1119,79 @@ where
         &cancel_limits,
     );
 
-    let hex_text_limits = block2_limits;
+    // The preview of the color followed by the text input
+    let preview_width = renderer.default_size().0 + 2.0 * PADDING;
+    let text_input_limits = block2_limits.shrink(Size::new(preview_width + SPACING, 0.0));
+    let text_input = Widget::<TextMessage, Theme, Renderer>::layout(
+        &color_picker.text_input,
+        &mut color_picker.tree.children[2],
+        renderer,
+        &text_input_limits,
+    );
+    let hex_height = text_input.bounds().height.max(preview_width);
+    let text_input = text_input.move_to(Point::new(
+        preview_width + SPACING,
+        (hex_height - text_input.bounds().height) / 2.0,
+    ));
+    let preview = Node::new(Size::new(preview_width, hex_height));
+
+    let mut hex_text_layout = Node::with_children(
+        Size::new(
+            preview_width + SPACING + text_input.bounds().width,
+            hex_height,
+        ),
+        vec![preview, text_input],
+    );
 
-    let mut hex_text_layout = Row::<Message, Theme, Renderer>::new()
-        .width(Length::Fill)
-        .height(Length::Fixed(renderer.default_size().0 + 2.0 * PADDING))
-        .layout(color_picker.tree, renderer, &hex_text_limits);
+    // The palette followed by the recent colors
+    let mut swatches = swatches_layout(
+        bounds.width,
+        color_picker.palette.len(),
+        color_picker
+            .state
+            .recent_colors
+            .len()
+            .min(color_picker.recent_colors),
+    );
+    let swatches_height = if swatches.children().is_empty() {
+        0.0
+    } else {
+        swatches.bounds().height + SPACING
+    };
 
     let block2_limits = block2_limits.shrink(Size::new(
         0.0,
-        cancel_button.bounds().height + hex_text_layout.bounds().height + 2.0 * SPACING,
+        cancel_button.bounds().height
+            + hex_text_layout.bounds().height
+            + swatches_height
+            + 2.0 * SPACING,
     ));
 
-    // RGBA Colors
+    // Color model and its channels
     let mut rgba_colors: Column<'_, Message, Theme, Renderer> =
-        Column::<Message, Theme, Renderer>::new();
+        Column::<Message, Theme, Renderer>::new().push(
+            Row::new()
+                .align_y(Alignment::Center)
+                .spacing(SPACING)
+                .padding(PADDING)
+                .height(Length::Fill)
+                .push(
+                    widget::Text::new(icon_to_string(Bootstrap::CaretLeftFill))
+                        .font(crate::BOOTSTRAP_FONT)
+                        .align_x(Horizontal::Center)
+                        .align_y(Vertical::Center),
+                )
+                .push(Row::new().width(Length::Fill).height(Length::Fill))
+                .push(
+                    widget::Text::new(icon_to_string(Bootstrap::CaretRightFill))
+                        .font(crate::BOOTSTRAP_FONT)
+                        .align_x(Horizontal::Center)
+                        .align_y(Vertical::Center),
+                ),
+        );
 
-    for _ in 0..4 {
+    // The channels and the alpha
+    for _ in 0..=color_picker.state.color_model.channel_count() {
         rgba_colors = rgba_colors.push(
             Row::new()
                 .align_y(Alignment::Center)
@@ -994,13 +1216,13 @@ where
         );
     }
     let element: Element<Message, Theme, Renderer> = Element::new(rgba_colors);
-    let rgba_tree = if let Some(child_tree) = color_picker.tree.children.get_mut(2) {
+    let rgba_tree = if let Some(child_tree) = color_picker.tree.children.get_mut(3) {
         child_tree.diff(element.as_widget());
         child_tree
     } else {
         let child_tree = Tree::new(element.as_widget());
-        color_picker.tree.children.insert(2, child_tree);
-        &mut color_picker.tree.children[2]
+        color_picker.tree.children.insert(3, child_tree);
+        &mut color_picker.tree.children[3]
     };
 
     let mut rgba_colors = element
@@ -1019,6 +1241,12 @@ where
     ));
     let hex_bounds = hex_text_layout.bounds();
 
+    // Swatches
+    swatches = swatches.move_to(Point::new(
+        PADDING,
+        rgba_bounds.height + hex_bounds.height + PADDING + 2.0 * SPACING,
+    ));
+
     // Buttons
     let cancel_limits =
         block2_limits.max_width(((rgba_bounds.width / 2.0) - BUTTON_SPACING).max(0.0));
@@ -1041,14 +1269,24 @@ where
     let cancel_bounds = cancel_button.bounds();
     cancel_button = cancel_button.move_to(Point::new(
         cancel_bounds.x + PADDING,
-        cancel_bounds.y + rgba_bounds.height + hex_bounds.height + PADDING + 2.0 * SPACING,
+        cancel_bounds.y
+            + rgba_bounds.height
+            + hex_bounds.height
+            + swatches_height
+            + PADDING
+            + 2.0 * SPACING,
     ));
     let cancel_bounds = cancel_button.bounds();
 
     let submit_bounds = submit_button.bounds();
     submit_button = submit_button.move_to(Point::new(
         submit_bounds.x + rgba_colors.bounds().width - submit_bounds.width + PADDING,
-        submit_bounds.y + rgba_bounds.height + hex_bounds.height + PADDING + 2.0 * SPACING,
+        submit_bounds.y
+            + rgba_bounds.height
+            + hex_bounds.height
+            + swatches_height
+            + PADDING
+            + 2.0 * SPACING,
     ));
 
     Node::with_children(
@@ -1056,15 +1294,47 @@ where
             rgba_bounds.width + (2.0 * PADDING),
             rgba_bounds.height
                 + hex_bounds.height
+                + swatches_height
                 + cancel_bounds.height
                 + (2.0 * PADDING)
                 + (2.0 * SPACING),
         ),
-        vec![rgba_colors, hex_text_layout, cancel_button, submit_button],
+        vec![
+            rgba_colors,
+            hex_text_layout,
+            swatches,
+            cancel_button,
+            submit_button,
+        ],
     )
     .move_to(Point::new(bounds.x, bounds.y))
 }
 
+/// Defines the layout of the swatches, wrapping the swatches of the palette
+/// and of the recent colors into rows fitting into the given width. The recent
+/// colors start on a new row.
+fn swatches_layout(width: f32, palette_count: usize, recent_count: usize) -> Node {
+    let columns = (((width + BUTTON_SPACING) / (SWATCH_SIZE + BUTTON_SPACING)) as usize).max(1);
+
+    let mut rows = 0;
+    let mut swatches = Vec::with_capacity(palette_count + recent_count);
+    for count in [palette_count, recent_count] {
+        for index in 0..count {
+            swatches.push(
+                Node::new(Size::new(SWATCH_SIZE, SWATCH_SIZE)).move_to(Point::new(
+                    (index % columns) as f32 * (SWATCH_SIZE + BUTTON_SPACING),
+                    (rows + index / columns) as f32 * (SWATCH_SIZE + BUTTON_SPACING),
+                )),
+            );
+        }
+        rows += count.div_ceil(columns);
+    }
+
+    let height = (rows as f32 * (SWATCH_SIZE + BUTTON_SPACING) - BUTTON_SPACING).max(0.0);
+
+    Node::with_children(Size::new(width, height), swatches)
+}
+
 /// Draws the 1. block of the color picker containing the HSV part.
 fn block1<Message, Theme>(
     renderer: &mut Renderer,
@@ -1074,8 +1344,10 @@ fn block1<Message, Theme>(
     style_sheet: &HashMap<StyleState, Style>,
 ) where
     Message: Clone,
-    Theme:
-        style::color_picker::Catalog + iced::widget::button::Catalog + iced::widget::text::Catalog,
+    Theme: style::color_picker::Catalog
+        + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog
+        + iced::widget::text::Catalog,
 {
     // ----------- Block 1 ----------------------
     let hsv_color_layout = layout;
@@ -1106,20 +1378,24 @@ fn block2<Message, Theme>(
     style_sheet: &HashMap<StyleState, Style>,
 ) where
     Message: Clone,
-    Theme:
-        style::color_picker::Catalog + iced::widget::button::Catalog + iced::widget::text::Catalog,
+    Theme: style::color_picker::Catalog
+        + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog
+        + iced::widget::text::Catalog,
 {
     // ----------- Block 2 ----------------------
     let mut block2_children = layout.children();
 
-    // ----------- RGBA Color ----------------------
-    let rgba_color_layout = block2_children
+    // ----------- Color channels -----------------
+    let color_channels_layout = block2_children
         .next()
-        .expect("Graphics: Layout should have a RGBA color layout");
-    rgba_color(
+        .expect("Graphics: Layout should have a color channels layout");
+    color_channels(
         renderer,
-        rgba_color_layout,
+        color_channels_layout,
         &color_picker.state.color,
+        color_picker.state.color_model,
+        color_picker.state.channels(),
         cursor,
         style,
         style_sheet,
@@ -1132,14 +1408,21 @@ fn block2<Message, Theme>(
         .expect("Graphics: Layout should have a hex text layout");
     hex_text(
         renderer,
+        color_picker,
         hex_text_layout,
-        &color_picker.state.color,
         cursor,
+        theme,
         style,
+        viewport,
         style_sheet,
-        color_picker.state.focus,
     );
 
+    // ----------- Swatches ------------------------
+    let swatches_layout = block2_children
+        .next()
+        .expect("Graphics: Layout should have a swatches layout");
+    swatches(renderer, color_picker, swatches_layout, cursor, style_sheet);
+
     // ----------- Buttons -------------------------
     let cancel_button_layout = block2_children
         .next()
@@ -1218,8 +1501,10 @@ fn hsv_color<Message, Theme>(
     style_sheet: &HashMap<StyleState, Style>,
 ) where
     Message: Clone,
-    Theme:
-        style::color_picker::Catalog + iced::widget::button::Catalog + iced::widget::text::Catalog,
+    Theme: style::color_picker::Catalog
+        + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog
+        + iced::widget::text::Catalog,
 {
     let mut hsv_color_children = layout.children();
     let hsv_color: Hsv = color_picker.state.color.into();
@@ -1235,83 +1520,14 @@ fn hsv_color<Message, Theme>(
         sat_value_style_state = sat_value_style_state.max(StyleState::Hovered);
     }
 
-    let geometry = color_picker.state.sat_value_canvas_cache.draw(
+    draw_sat_value(
         renderer,
-        sat_value_layout.bounds().size(),
-        |frame| {
-            let column_count = frame.width() as u16;
-            let row_count = frame.height() as u16;
-
-            for column in 0..column_count {
-                for row in 0..row_count {
-                    let saturation = f32::from(column) / frame.width();
-                    let value = f32::from(row) / frame.height();
-
-                    frame.fill_rectangle(
-                        Point::new(f32::from(column), f32::from(row)),
-                        Size::new(1.0, 1.0),
-                        Color::from(Hsv::from_hsv(hsv_color.hue, saturation, value)),
-                    );
-                }
-            }
-
-            let stroke = Stroke {
-                style: canvas::Style::Solid(
-                    Hsv {
-                        hue: 0,
-                        saturation: 0.0,
-                        value: 1.0 - hsv_color.value,
-                    }
-                    .into(),
-                ),
-                width: 3.0,
-                line_cap: LineCap::Round,
-                ..Stroke::default()
-            };
-
-            let saturation = hsv_color.saturation * frame.width();
-            let value = hsv_color.value * frame.height();
-
-            frame.stroke(
-                &Path::line(
-                    Point::new(saturation, 0.0),
-                    Point::new(saturation, frame.height()),
-                ),
-                stroke,
-            );
-
-            frame.stroke(
-                &Path::line(Point::new(0.0, value), Point::new(frame.width(), value)),
-                stroke,
-            );
-
-            let stroke = Stroke {
-                style: canvas::Style::Solid(
-                    style_sheet
-                        .get(&sat_value_style_state)
-                        .expect("Style Sheet not found.")
-                        .bar_border_color,
-                ),
-                width: 2.0,
-                line_cap: LineCap::Round,
-                ..Stroke::default()
-            };
-
-            frame.stroke(
-                &Path::rectangle(
-                    Point::new(0.0, 0.0),
-                    Size::new(frame.size().width - 0.0, frame.size().height - 0.0),
-                ),
-                stroke,
-            );
-        },
+        &color_picker.state.sat_value_canvas_cache,
+        sat_value_layout.bounds(),
+        hsv_color,
+        style_sheet[&sat_value_style_state].bar_border_color,
     );
 
-    let translation = Vector::new(sat_value_layout.bounds().x, sat_value_layout.bounds().y);
-    renderer.with_translation(translation, |renderer| {
-        renderer.draw_geometry(geometry);
-    });
-
     let hue_layout = hsv_color_children
         .next()
         .expect("Graphics: Layout should have a hue layout");
@@ -1323,92 +1539,227 @@ fn hsv_color<Message, Theme>(
         hue_style_state = hue_style_state.max(StyleState::Hovered);
     }
 
-    let geometry =
-        color_picker
-            .state
-            .hue_canvas_cache
-            .draw(renderer, hue_layout.bounds().size(), |frame| {
-                let column_count = frame.width() as u16;
-
-                for column in 0..column_count {
-                    let hue = (f32::from(column) * 360.0 / frame.width()) as u16;
-
-                    let hsv_color = Hsv::from_hsv(hue, 1.0, 1.0);
-                    let stroke = Stroke {
-                        style: canvas::Style::Solid(hsv_color.into()),
-                        width: 1.0,
-                        line_cap: LineCap::Round,
-                        ..Stroke::default()
-                    };
+    draw_hue(
+        renderer,
+        &color_picker.state.hue_canvas_cache,
+        hue_layout.bounds(),
+        hsv_color,
+        style_sheet[&hue_style_state].bar_border_color,
+    );
+}
 
-                    frame.stroke(
-                        &Path::line(
-                            Point::new(f32::from(column), 0.0),
-                            Point::new(f32::from(column), frame.height()),
-                        ),
-                        stroke,
-                    );
+/// Gets the color with the saturation and the value picked at the given
+/// position in the bounds of a saturation and value area.
+pub(crate) fn sat_value_at(hsv_color: Hsv, bounds: Rectangle, position: Point) -> Hsv {
+    Hsv {
+        saturation: ((position.x - bounds.x) / bounds.width).clamp(0.0, 1.0),
+        value: ((position.y - bounds.y) / bounds.height).clamp(0.0, 1.0),
+        ..hsv_color
+    }
+}
+
+/// Gets the color with the hue picked at the given position in the bounds of
+/// a hue bar.
+pub(crate) fn hue_at(hsv_color: Hsv, bounds: Rectangle, position: Point) -> Hsv {
+    Hsv {
+        hue: (((position.x - bounds.x) / bounds.width).clamp(0.0, 1.0) * 360.0) as u16,
+        ..hsv_color
+    }
+}
+
+/// Draws the saturation and value area of the hue of the given color, marking
+/// the saturation and the value of the color.
+pub(crate) fn draw_sat_value(
+    renderer: &mut Renderer,
+    cache: &canvas::Cache,
+    bounds: Rectangle,
+    hsv_color: Hsv,
+    border_color: Color,
+) {
+    let geometry = cache.draw(renderer, bounds.size(), |frame| {
+        let column_count = frame.width() as u16;
+        let row_count = frame.height() as u16;
+
+        for column in 0..column_count {
+            for row in 0..row_count {
+                let saturation = f32::from(column) / frame.width();
+                let value = f32::from(row) / frame.height();
+
+                frame.fill_rectangle(
+                    Point::new(f32::from(column), f32::from(row)),
+                    Size::new(1.0, 1.0),
+                    Color::from(Hsv::from_hsv(hsv_color.hue, saturation, value)),
+                );
+            }
+        }
+
+        let stroke = Stroke {
+            style: canvas::Style::Solid(
+                Hsv {
+                    hue: 0,
+                    saturation: 0.0,
+                    value: 1.0 - hsv_color.value,
                 }
+                .into(),
+            ),
+            width: 3.0,
+            line_cap: LineCap::Round,
+            ..Stroke::default()
+        };
 
-                let stroke = Stroke {
-                    style: canvas::Style::Solid(Color::BLACK),
-                    width: 3.0,
-                    line_cap: LineCap::Round,
-                    ..Stroke::default()
-                };
+        let saturation = hsv_color.saturation * frame.width();
+        let value = hsv_color.value * frame.height();
 
-                let column = f32::from(hsv_color.hue) * frame.width() / 360.0;
+        frame.stroke(
+            &Path::line(
+                Point::new(saturation, 0.0),
+                Point::new(saturation, frame.height()),
+            ),
+            stroke,
+        );
 
-                frame.stroke(
-                    &Path::line(Point::new(column, 0.0), Point::new(column, frame.height())),
-                    stroke,
-                );
+        frame.stroke(
+            &Path::line(Point::new(0.0, value), Point::new(frame.width(), value)),
+            stroke,
+        );
 
-                let stroke = Stroke {
-                    style: canvas::Style::Solid(
-                        style_sheet
-                            .get(&hue_style_state)
-                            .expect("Style Sheet not found.")
-                            .bar_border_color,
-                    ),
-                    width: 2.0,
-                    line_cap: LineCap::Round,
-                    ..Stroke::default()
-                };
+        let stroke = Stroke {
+            style: canvas::Style::Solid(border_color),
+            width: 2.0,
+            line_cap: LineCap::Round,
+            ..Stroke::default()
+        };
 
-                frame.stroke(
-                    &Path::rectangle(
-                        Point::new(0.0, 0.0),
-                        Size::new(frame.size().width, frame.size().height),
-                    ),
-                    stroke,
-                );
-            });
+        frame.stroke(
+            &Path::rectangle(
+                Point::new(0.0, 0.0),
+                Size::new(frame.size().width - 0.0, frame.size().height - 0.0),
+            ),
+            stroke,
+        );
+    });
 
-    let translation = Vector::new(hue_layout.bounds().x, hue_layout.bounds().y);
+    let translation = Vector::new(bounds.x, bounds.y);
     renderer.with_translation(translation, |renderer| {
         renderer.draw_geometry(geometry);
     });
 }
 
-/// Draws the RGBA color area.
-#[allow(clippy::too_many_lines)]
-fn rgba_color(
+/// Draws the hue bar, marking the hue of the given color.
+pub(crate) fn draw_hue(
+    renderer: &mut Renderer,
+    cache: &canvas::Cache,
+    bounds: Rectangle,
+    hsv_color: Hsv,
+    border_color: Color,
+) {
+    let geometry = cache.draw(renderer, bounds.size(), |frame| {
+        let column_count = frame.width() as u16;
+
+        for column in 0..column_count {
+            let hue = (f32::from(column) * 360.0 / frame.width()) as u16;
+
+            let hsv_color = Hsv::from_hsv(hue, 1.0, 1.0);
+            let stroke = Stroke {
+                style: canvas::Style::Solid(hsv_color.into()),
+                width: 1.0,
+                line_cap: LineCap::Round,
+                ..Stroke::default()
+            };
+
+            frame.stroke(
+                &Path::line(
+                    Point::new(f32::from(column), 0.0),
+                    Point::new(f32::from(column), frame.height()),
+                ),
+                stroke,
+            );
+        }
+
+        let stroke = Stroke {
+            style: canvas::Style::Solid(Color::BLACK),
+            width: 3.0,
+            line_cap: LineCap::Round,
+            ..Stroke::default()
+        };
+
+        let column = f32::from(hsv_color.hue) * frame.width() / 360.0;
+
+        frame.stroke(
+            &Path::line(Point::new(column, 0.0), Point::new(column, frame.height())),
+            stroke,
+        );
+
+        let stroke = Stroke {
+            style: canvas::Style::Solid(border_color),
+            width: 2.0,
+            line_cap: LineCap::Round,
+            ..Stroke::default()
+        };
+
+        frame.stroke(
+            &Path::rectangle(
+                Point::new(0.0, 0.0),
+                Size::new(frame.size().width, frame.size().height),
+            ),
+            stroke,
+        );
+    });
+
+    let translation = Vector::new(bounds.x, bounds.y);
+    renderer.with_translation(translation, |renderer| {
+        renderer.draw_geometry(geometry);
+    });
+}
+
+/// Draws the color model and the bars of its channels.
+#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+fn color_channels(
     renderer: &mut Renderer,
     layout: Layout<'_>,
     color: &Color,
+    model: ColorModel,
+    channels: [f32; 4],
     cursor: Cursor,
     style: &renderer::Style,
     style_sheet: &HashMap<StyleState, Style>,
     focus: Focus,
 ) {
-    let mut rgba_color_children = layout.children();
+    let mut color_channels_children = layout.children();
+
+    let draw_focus = |renderer: &mut Renderer, bounds: Rectangle| {
+        if (bounds.width > 0.) && (bounds.height > 0.) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: style_sheet
+                            .get(&StyleState::Focused)
+                            .expect("Style Sheet not found.")
+                            .border_radius
+                            .into(),
+                        width: style_sheet
+                            .get(&StyleState::Focused)
+                            .expect("Style Sheet not found.")
+                            .border_width,
+                        color: style_sheet
+                            .get(&StyleState::Focused)
+                            .expect("Style Sheet not found.")
+                            .border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                Color::TRANSPARENT,
+            );
+        }
+    };
 
     let f = |renderer: &mut Renderer,
              layout: Layout,
              label: &str,
              color: Color,
              value: f32,
+             text: String,
              cursor: Cursor,
              target: Focus| {
         let mut children = layout.children();
@@ -1509,7 +1860,7 @@ fn rgba_color(
         // Value
         renderer.fill_text(
             Text {
-                content: format!("{}", (255.0 * value) as u8),
+                content: text,
                 bounds: Size::new(value_layout.bounds().width, value_layout.bounds().height),
                 size: renderer.default_size(),
                 font: renderer.default_font(),
@@ -1526,80 +1877,81 @@ fn rgba_color(
             value_layout.bounds(),
         );
 
-        let bounds = layout.bounds();
-        if (focus == target) && (bounds.width > 0.) && (bounds.height > 0.) {
-            renderer.fill_quad(
-                renderer::Quad {
-                    bounds,
-                    border: Border {
-                        radius: style_sheet
-                            .get(&StyleState::Focused)
-                            .expect("Style Sheet not found.")
-                            .border_radius
-                            .into(),
-                        width: style_sheet
-                            .get(&StyleState::Focused)
-                            .expect("Style Sheet not found.")
-                            .border_width,
-                        color: style_sheet
-                            .get(&StyleState::Focused)
-                            .expect("Style Sheet not found.")
-                            .border_color,
-                    },
-                    shadow: Shadow::default(),
-                },
-                Color::TRANSPARENT,
-            );
+        if focus == target {
+            draw_focus(renderer, layout.bounds());
         }
     };
 
-    // Red
-    let red_row_layout = rgba_color_children
+    // Color model
+    let model_layout = color_channels_children
         .next()
-        .expect("Graphics: Layout should have a red row layout");
+        .expect("Graphics: Layout should have a color model layout");
+    let mut model_children = model_layout.children();
 
-    f(
-        renderer,
-        red_row_layout,
-        "R:",
-        Color::from_rgb(color.r, 0.0, 0.0),
-        color.r,
-        cursor,
-        Focus::Red,
-    );
+    for (content, font) in [
+        (
+            icon_to_string(Bootstrap::CaretLeftFill),
+            crate::BOOTSTRAP_FONT,
+        ),
+        (model.name().to_owned(), renderer.default_font()),
+        (
+            icon_to_string(Bootstrap::CaretRightFill),
+            crate::BOOTSTRAP_FONT,
+        ),
+    ] {
+        let bounds = model_children
+            .next()
+            .expect("Graphics: Layout should have a color model child layout")
+            .bounds();
 
-    // Green
-    let green_row_layout = rgba_color_children
-        .next()
-        .expect("Graphics: Layout should have a green row layout");
+        renderer.fill_text(
+            Text {
+                content,
+                bounds: Size::new(bounds.width, bounds.height),
+                size: renderer.default_size(),
+                font,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                line_height: text::LineHeight::Relative(1.3),
+                shaping: text::Shaping::Advanced,
+            },
+            Point::new(bounds.center_x(), bounds.center_y()),
+            style.text_color,
+            bounds,
+        );
+    }
 
-    f(
-        renderer,
-        green_row_layout,
-        "G:",
-        Color::from_rgb(0.0, color.g, 0.0),
-        color.g,
-        cursor,
-        Focus::Green,
-    );
+    if focus == Focus::Model {
+        draw_focus(renderer, model_layout.bounds());
+    }
 
-    // Blue
-    let blue_row_layout = rgba_color_children
-        .next()
-        .expect("Graphics: Layout should have a blue row layout");
+    // Channels
+    for (channel, label) in model.labels().iter().enumerate() {
+        let row_layout = color_channels_children
+            .next()
+            .expect("Graphics: Layout should have a channel row layout");
 
-    f(
-        renderer,
-        blue_row_layout,
-        "B:",
-        Color::from_rgb(0.0, 0.0, color.b),
-        color.b,
-        cursor,
-        Focus::Blue,
-    );
+        let bar_color = match (model, channel) {
+            (ColorModel::Rgb, 0) => Color::from_rgb(color.r, 0.0, 0.0),
+            (ColorModel::Rgb, 1) => Color::from_rgb(0.0, color.g, 0.0),
+            (ColorModel::Rgb, _) => Color::from_rgb(0.0, 0.0, color.b),
+            _ => Color { a: 1.0, ..*color },
+        };
+
+        f(
+            renderer,
+            row_layout,
+            label,
+            bar_color,
+            channels[channel],
+            model.channel_text(channel, channels[channel]),
+            cursor,
+            Focus::Channel(channel),
+        );
+    }
 
     // Alpha
-    let alpha_row_layout = rgba_color_children
+    let alpha_row_layout = color_channels_children
         .next()
         .expect("Graphics: Layout should have an alpha row layout");
 
@@ -1609,30 +1961,42 @@ fn rgba_color(
         "A:",
         Color::from_rgba(0.0, 0.0, 0.0, color.a),
         color.a,
+        format!("{}", (255.0 * color.a) as u8),
         cursor,
         Focus::Alpha,
     );
 }
 
-/// Draws the hex text representation of the color.
-fn hex_text(
+/// Draws the preview of the color and the text input, in which a color can be typed.
+#[allow(clippy::too_many_arguments)]
+fn hex_text<Message, Theme>(
     renderer: &mut Renderer,
+    color_picker: &ColorPickerOverlay<'_, '_, Message, Theme>,
     layout: Layout<'_>,
-    color: &Color,
     cursor: Cursor,
-    _style: &renderer::Style,
+    theme: &Theme,
+    style: &renderer::Style,
+    viewport: &Rectangle,
     style_sheet: &HashMap<StyleState, Style>,
-    _focus: Focus,
-) {
-    let hsv: Hsv = (*color).into();
+) where
+    Message: Clone,
+    Theme: style::color_picker::Catalog
+        + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog
+        + iced::widget::text::Catalog,
+{
+    let mut children = layout.children();
 
-    let hex_text_style_state = if cursor.is_over(layout.bounds()) {
+    let preview_layout = children
+        .next()
+        .expect("Graphics: Layout should have a color preview layout");
+    let hex_text_style_state = if cursor.is_over(preview_layout.bounds()) {
         StyleState::Hovered
     } else {
         StyleState::Active
     };
 
-    let bounds = layout.bounds();
+    let bounds = preview_layout.bounds();
     if (bounds.width > 0.) && (bounds.height > 0.) {
         renderer.fill_quad(
             renderer::Quad {
@@ -1644,33 +2008,78 @@ fn hex_text(
                 },
                 shadow: Shadow::default(),
             },
-            *color,
+            color_picker.state.color,
         );
     }
 
-    renderer.fill_text(
-        Text {
-            content: color.as_hex_string(),
-            bounds: Size::new(bounds.width, bounds.height),
-            size: renderer.default_size(),
-            font: renderer.default_font(),
-            horizontal_alignment: Horizontal::Center,
-            vertical_alignment: Vertical::Center,
-            line_height: text::LineHeight::Relative(1.3),
-            shaping: text::Shaping::Basic,
-        },
-        Point::new(bounds.center_x(), bounds.center_y()),
-        Color {
-            a: 1.0,
-            ..Hsv {
-                hue: 0,
-                saturation: 0.0,
-                value: if hsv.value < 0.5 { 1.0 } else { 0.0 },
-            }
-            .into()
-        },
-        bounds,
+    let text_input_layout = children
+        .next()
+        .expect("Graphics: Layout should have a text input layout");
+    Widget::<TextMessage, Theme, Renderer>::draw(
+        &color_picker.text_input,
+        &color_picker.tree.children[2],
+        renderer,
+        theme,
+        style,
+        text_input_layout,
+        cursor,
+        viewport,
     );
+
+    let is_valid = color_picker
+        .state
+        .text
+        .as_deref()
+        .map_or(true, |text| parse_color(text).is_some());
+    if !is_valid {
+        text_input::draw_error(
+            renderer,
+            text_input_layout.bounds(),
+            style_sheet[&StyleState::Active].error_color,
+        );
+    }
+}
+
+/// Draws the swatches of the palette and of the recent colors.
+fn swatches<Message, Theme>(
+    renderer: &mut Renderer,
+    color_picker: &ColorPickerOverlay<'_, '_, Message, Theme>,
+    layout: Layout<'_>,
+    cursor: Cursor,
+    style_sheet: &HashMap<StyleState, Style>,
+) where
+    Message: Clone,
+    Theme: style::color_picker::Catalog
+        + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog,
+{
+    for (index, swatch_layout) in layout.children().enumerate() {
+        let Some(color) = color_picker.swatch(index) else {
+            continue;
+        };
+
+        let bounds = swatch_layout.bounds();
+        let style_state = if color_picker.state.focus == Focus::Swatch(index) {
+            StyleState::Focused
+        } else if cursor.is_over(bounds) {
+            StyleState::Hovered
+        } else {
+            StyleState::Active
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    radius: style_sheet[&style_state].bar_border_radius.into(),
+                    width: style_sheet[&style_state].bar_border_width,
+                    color: style_sheet[&style_state].bar_border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            color,
+        );
+    }
 }
 
 /// The state of the [`ColorPickerOverlay`].
@@ -1688,6 +2097,16 @@ pub struct State {
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// The color model of the bars of the [`ColorPickerOverlay`].
+    pub(crate) color_model: ColorModel,
+    /// The channels of the color model last edited on their bars, along with
+    /// the color they make, keeping the values that can not be told from the
+    /// color like the hue of grays.
+    pub(crate) channels: Option<(ColorModel, Color, [f32; 4])>,
+    /// The text typed into the text input if it differs from the color.
+    pub(crate) text: Option<String>,
+    /// The recently submitted colors, the most recent first.
+    pub(crate) recent_colors: Vec<Color>,
 }
 
 impl State {
@@ -1699,6 +2118,67 @@ impl State {
             ..Self::default()
         }
     }
+
+    /// Gets the text shown in the text input.
+    pub(crate) fn text(&self) -> String {
+        self.text
+            .clone()
+            .unwrap_or_else(|| self.color.as_hex_string())
+    }
+
+    /// Remembers the submitted color as the most recent color, keeping at
+    /// most the given number of recent colors.
+    pub(crate) fn push_recent_color(&mut self, color: Color, limit: usize) {
+        self.recent_colors.retain(|recent| *recent != color);
+        self.recent_colors.insert(0, color);
+        self.recent_colors.truncate(limit);
+    }
+
+    /// Gets the channels of the color in the color model, each scaled to the
+    /// range of its bar from 0 to 1.
+    ///
+    /// The last edited channels are kept as long as the color and the color
+    /// model did not change since.
+    pub(crate) fn channels(&self) -> [f32; 4] {
+        match self.channels {
+            Some((model, color, channels))
+                if model == self.color_model
+                    && Color {
+                        a: self.color.a,
+                        ..color
+                    } == self.color =>
+            {
+                channels
+            }
+            _ => self.color_model.channels(self.color),
+        }
+    }
+
+    /// Sets the channel of the color to the given value scaled to the range
+    /// of its bar from 0 to 1.
+    pub(crate) fn set_channel(&mut self, channel: usize, value: f32) {
+        let mut channels = self.channels();
+        channels[channel] = value.clamp(0.0, 1.0);
+
+        self.color = self.color_model.color(channels, self.color.a);
+        self.channels = Some((self.color_model, self.color, channels));
+    }
+
+    /// Moves the channel of the color by the given number of steps of the
+    /// value shown next to its bar.
+    pub(crate) fn step_channel(&mut self, channel: usize, steps: f32) {
+        let (min, max) = self.color_model.channel_range(channel);
+        let span = max - min;
+        let value = (self.channels()[channel] * span).round() + steps;
+
+        self.set_channel(channel, value / span);
+    }
+
+    /// Drops the typed text if the color was changed by something else than
+    /// the text input.
+    pub(crate) fn sync_text(&mut self, previous_color: Color) {
+        text_input::sync_text(&mut self.text, &self.color, &previous_color);
+    }
 }
 
 impl Default for State {
@@ -1710,6 +2190,10 @@ impl Default for State {
             color_bar_dragged: ColorBarDragged::None,
             focus: Focus::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            color_model: ColorModel::default(),
+            channels: None,
+            text: None,
+            recent_colors: Vec::new(),
         }
     }
 }
@@ -1719,12 +2203,16 @@ impl Default for State {
 pub struct ColorPickerOverlayButtons<'a, Message, Theme>
 where
     Message: Clone,
-    Theme: style::color_picker::Catalog + iced::widget::button::Catalog,
+    Theme: style::color_picker::Catalog
+        + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog,
 {
     /// The cancel button of the [`ColorPickerOverlay`].
     cancel_button: Element<'a, Message, Theme, Renderer>,
     /// The submit button of the [`ColorPickerOverlay`].
     submit_button: Element<'a, Message, Theme, Renderer>,
+    /// The text input of the [`ColorPickerOverlay`].
+    text_input: TextInput<'a, TextMessage, Theme, Renderer>,
 }
 
 impl<'a, Message, Theme> Default for ColorPickerOverlayButtons<'a, Message, Theme>
@@ -1733,6 +2221,7 @@ where
     Theme: 'a
         + style::color_picker::Catalog
         + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog
         + iced::widget::text::Catalog,
 {
     fn default() -> Self {
@@ -1745,6 +2234,7 @@ where
                 widget::Text::new(icon_to_string(Bootstrap::Check)).font(crate::BOOTSTRAP_FONT),
             )
             .into(),
+            text_input: text_input::text_input("", ""),
         }
     }
 }
@@ -1754,18 +2244,22 @@ impl<'a, Message, Theme> Widget<Message, Theme, Renderer>
     for ColorPickerOverlayButtons<'a, Message, Theme>
 where
     Message: Clone,
-    Theme:
-        style::color_picker::Catalog + iced::widget::button::Catalog + iced::widget::text::Catalog,
+    Theme: style::color_picker::Catalog
+        + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog
+        + iced::widget::text::Catalog,
 {
     fn children(&self) -> Vec<Tree> {
-        vec![
-            Tree::new(&self.cancel_button),
-            Tree::new(&self.submit_button),
-        ]
+        text_input::children(&self.cancel_button, &self.submit_button, &self.text_input)
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&[&self.cancel_button, &self.submit_button]);
+        text_input::diff(
+            tree,
+            &self.cancel_button,
+            &self.submit_button,
+            &self.text_input,
+        );
     }
 
     fn size(&self) -> Size<Length> {
@@ -1797,6 +2291,7 @@ where
     Theme: 'a
         + style::color_picker::Catalog
         + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog
         + iced::widget::text::Catalog,
 {
     fn from(overlay: ColorPickerOverlayButtons<'a, Message, Theme>) -> Self {
@@ -1816,14 +2311,9 @@ pub enum ColorBarDragged {
     /// The hue area is focussed.
     Hue,
 
-    /// The red area is focussed.
-    Red,
-
-    /// The green area is focussed.
-    Green,
-
-    /// The blue area is focussed.
-    Blue,
+    /// The bar of the channel with the given index of the color model is
+    /// focussed.
+    Channel(usize),
 
     /// The alpha area is focussed.
     Alpha,
@@ -1850,18 +2340,19 @@ pub enum Focus {
     /// The hue bar is in focus.
     Hue,
 
-    /// The red bar is in focus.
-    Red,
+    /// The color model switch is in focus.
+    Model,
 
-    /// The green bar is in focus.
-    Green,
-
-    /// The blue bar is in focus.
-    Blue,
+    /// The bar of the channel with the given index of the color model is in
+    /// focus.
+    Channel(usize),
 
     /// The alpha bar is in focus.
     Alpha,
 
+    /// The swatch with the given index is in focus.
+    Swatch(usize),
+
     /// The cancel button is in focus.
     Cancel,
 
@@ -1870,34 +2361,39 @@ pub enum Focus {
 }
 
 impl Focus {
-    /// Gets the next focusable element.
+    /// Gets the next focusable element for a color model with the given
+    /// number of channels and the given number of swatches.
     #[must_use]
-    pub const fn next(self) -> Self {
+    pub const fn next(self, channel_count: usize, swatch_count: usize) -> Self {
         match self {
             Self::Overlay => Self::SatValue,
             Self::SatValue => Self::Hue,
-            Self::Hue => Self::Red,
-            Self::Red => Self::Green,
-            Self::Green => Self::Blue,
-            Self::Blue => Self::Alpha,
-            Self::Alpha => Self::Cancel,
+            Self::Hue => Self::Model,
+            Self::Model => Self::Channel(0),
+            Self::Channel(channel) if channel + 1 < channel_count => Self::Channel(channel + 1),
+            Self::Channel(_) => Self::Alpha,
+            Self::Alpha if swatch_count > 0 => Self::Swatch(0),
+            Self::Alpha | Self::Swatch(_) => Self::Cancel,
             Self::Cancel => Self::Submit,
             Self::Submit | Self::None => Self::Overlay,
         }
     }
 
-    /// Gets the previous focusable element.
+    /// Gets the previous focusable element for a color model with the given
+    /// number of channels and the given number of swatches.
     #[must_use]
-    pub const fn previous(self) -> Self {
+    pub const fn previous(self, channel_count: usize, swatch_count: usize) -> Self {
         match self {
             Self::None => Self::None,
             Self::Overlay => Self::Submit,
             Self::SatValue => Self::Overlay,
             Self::Hue => Self::SatValue,
-            Self::Red => Self::Hue,
-            Self::Green => Self::Red,
-            Self::Blue => Self::Green,
-            Self::Alpha => Self::Blue,
+            Self::Model => Self::Hue,
+            Self::Channel(0) => Self::Model,
+            Self::Channel(channel) => Self::Channel(channel - 1),
+            Self::Alpha => Self::Channel(channel_count.saturating_sub(1)),
+            Self::Swatch(_) => Self::Alpha,
+            Self::Cancel if swatch_count > 0 => Self::Swatch(0),
             Self::Cancel => Self::Alpha,
             Self::Submit => Self::Cancel,
         }
@@ -1909,3 +2405,159 @@ impl Default for Focus {
         Self::None
     }
 }
+
+/// The color models of the bars of the [`ColorPickerOverlay`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ColorModel {
+    /// Red, green and blue.
+    Rgb,
+
+    /// Hue, saturation and lightness.
+    Hsl,
+
+    /// The perceived lightness and the green/red and blue/yellow axes.
+    Oklab,
+
+    /// The perceived lightness, the chroma and the hue.
+    Oklch,
+
+    /// Cyan, magenta, yellow and black.
+    Cmyk,
+}
+
+impl ColorModel {
+    /// Gets the next color model.
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Rgb => Self::Hsl,
+            Self::Hsl => Self::Oklab,
+            Self::Oklab => Self::Oklch,
+            Self::Oklch => Self::Cmyk,
+            Self::Cmyk => Self::Rgb,
+        }
+    }
+
+    /// Gets the previous color model.
+    #[must_use]
+    pub const fn previous(self) -> Self {
+        match self {
+            Self::Rgb => Self::Cmyk,
+            Self::Hsl => Self::Rgb,
+            Self::Oklab => Self::Hsl,
+            Self::Oklch => Self::Oklab,
+            Self::Cmyk => Self::Oklch,
+        }
+    }
+
+    /// Gets the name of the color model.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Rgb => "RGB",
+            Self::Hsl => "HSL",
+            Self::Oklab => "OKLab",
+            Self::Oklch => "OKLCH",
+            Self::Cmyk => "CMYK",
+        }
+    }
+
+    /// Gets the labels of the channels of the color model.
+    #[must_use]
+    pub const fn labels(self) -> &'static [&'static str] {
+        match self {
+            Self::Rgb => &["R:", "G:", "B:"],
+            Self::Hsl => &["H:", "S:", "L:"],
+            Self::Oklab => &["L:", "a:", "b:"],
+            Self::Oklch => &["L:", "C:", "H:"],
+            Self::Cmyk => &["C:", "M:", "Y:", "K:"],
+        }
+    }
+
+    /// Gets the number of channels of the color model without the alpha.
+    #[must_use]
+    pub const fn channel_count(self) -> usize {
+        self.labels().len()
+    }
+
+    /// Gets the range of the value shown next to the bar of the channel.
+    const fn channel_range(self, channel: usize) -> (f32, f32) {
+        match (self, channel) {
+            (Self::Rgb, _) => (0.0, 255.0),
+            (Self::Hsl, 0) | (Self::Oklch, 2) => (0.0, 360.0),
+            (Self::Oklab, 1 | 2) => (-40.0, 40.0),
+            (Self::Oklch, 1) => (0.0, 40.0),
+            _ => (0.0, 100.0),
+        }
+    }
+
+    /// Gets the channels of the color in the color model, each scaled to the
+    /// range of its bar from 0 to 1.
+    fn channels(self, color: Color) -> [f32; 4] {
+        match self {
+            Self::Rgb => [color.r, color.g, color.b, 0.0],
+            Self::Hsl => {
+                let hsl = Hsl::from(color);
+                [hsl.hue / 360.0, hsl.saturation, hsl.lightness, 0.0]
+            }
+            Self::Oklab => {
+                let oklab = Oklab::from(color);
+                [
+                    oklab.lightness,
+                    (oklab.a + 0.4) / 0.8,
+                    (oklab.b + 0.4) / 0.8,
+                    0.0,
+                ]
+            }
+            Self::Oklch => {
+                let oklch = Oklch::from(color);
+                [oklch.lightness, oklch.chroma / 0.4, oklch.hue / 360.0, 0.0]
+            }
+            Self::Cmyk => {
+                let cmyk = Cmyk::from(color);
+                [cmyk.cyan, cmyk.magenta, cmyk.yellow, cmyk.key]
+            }
+        }
+    }
+
+    /// Creates the color of the channels, each scaled to the range of its bar
+    /// from 0 to 1, with the given alpha.
+    fn color(self, channels: [f32; 4], alpha: f32) -> Color {
+        let [first, second, third, fourth] = channels;
+
+        let color: Color = match self {
+            Self::Rgb => Color::from_rgb(first, second, third),
+            Self::Hsl => Hsl::from_hsl(first * 360.0, second, third).into(),
+            Self::Oklab => {
+                Oklab::from_lab(first, second.mul_add(0.8, -0.4), third.mul_add(0.8, -0.4)).into()
+            }
+            Self::Oklch => Oklch::from_lch(first, second * 0.4, third * 360.0).into(),
+            Self::Cmyk => Cmyk::from_cmyk(first, second, third, fourth).into(),
+        };
+
+        Color { a: alpha, ..color }
+    }
+
+    /// Gets the value shown next to the bar of the channel.
+    fn channel_text(self, channel: usize, value: f32) -> String {
+        let (min, max) = self.channel_range(channel);
+        format!("{}", value.mul_add(max - min, min) as i32)
+    }
+}
+
+impl Default for ColorModel {
+    fn default() -> Self {
+        Self::Rgb
+    }
+}
+
+/// Gets the bounds of the bar of a row of the color channels.
+fn bar_bounds(row: Layout<'_>) -> Rectangle {
+    let mut children = row.children();
+    let _label_layout = children.next();
+
+    children
+        .next()
+        .expect("widgets: Layout should have a bar layout")
+        .bounds()
+}
@@ -5,14 +5,17 @@
 use crate::{
     core::icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
     core::{
-        date::{Date, IsInMonth},
-        overlay::Position,
+        date::{format_date, parse_date, IsInMonth, Locale},
+        overlay::{
+            text_input::{self, TextMessage},
+            Position,
+        },
     },
-    date_picker,
+    date_picker::{self, DayDecorator, Marker, OnSubmit, Restrictions},
     style::{date_picker::Style, style_state::StyleState, Status},
 };
 
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Datelike, Local, NaiveDate, Weekday};
 use iced::{
     advanced::{
         layout::{Limits, Node},
@@ -26,7 +29,7 @@ use iced::{
     keyboard,
     mouse::{self, Cursor},
     touch,
-    widget::{text, Button, Column, Container, Row, Text},
+    widget::{text, Button, Column, Container, Row, Text, TextInput},
     Alignment,
     Border,
     Color,
@@ -51,13 +54,17 @@ const SPACING: f32 = 15.0;
 const DAY_CELL_PADDING: f32 = 7.0;
 /// The spacing between the buttons.
 const BUTTON_SPACING: f32 = 5.0;
+/// The padding of the text input.
+const TEXT_INPUT_PADDING: f32 = 5.0;
 
 /// The overlay of the [`DatePicker`](crate::widgets::DatePicker).
 #[allow(missing_debug_implementations)]
 pub struct DatePickerOverlay<'a, 'b, Message, Theme>
 where
     Message: Clone,
-    Theme: crate::style::date_picker::Catalog + iced::widget::button::Catalog,
+    Theme: crate::style::date_picker::Catalog
+        + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog,
     'b: 'a,
 {
     /// The state of the [`DatePickerOverlay`].
@@ -67,15 +74,19 @@ where
     /// The submit button of the [`DatePickerOverlay`].
     submit_button: Button<'a, Message, Theme, Renderer>,
     /// The function that produces a message when the submit button of the [`DatePickerOverlay`] is pressed.
-    on_submit: &'a dyn Fn(Date) -> Message,
+    on_submit: &'a OnSubmit<Message>,
     /// The position of the [`DatePickerOverlay`].
     position: Point,
     /// The style of the [`DatePickerOverlay`].
     class: &'a <Theme as crate::style::date_picker::Catalog>::Class<'b>,
     /// The reference to the tree holding the state of this overlay.
     tree: &'a mut Tree,
-    /// The font size of text and icons in the [`DatePickerOverlay`]
-    font_size: Pixels,
+    /// The options of the calendar of the [`DatePickerOverlay`].
+    options: CalendarOptions<'a>,
+    /// The text input in which a date can be typed, if enabled.
+    text_input: Option<TextInput<'a, TextMessage, Theme, Renderer>>,
+    /// The `chrono` format of the dates typed into the text input.
+    text_format: Option<&'a str>,
 }
 
 impl<'a, 'b, Message, Theme> DatePickerOverlay<'a, 'b, Message, Theme>
@@ -85,28 +96,40 @@ where
         + crate::style::date_picker::Catalog
         + iced::widget::button::Catalog
         + iced::widget::text::Catalog
-        + iced::widget::container::Catalog,
+        + iced::widget::container::Catalog
+        + iced::widget::text_input::Catalog,
     'b: 'a,
 {
     /// Creates a new [`DatePickerOverlay`] on the given position.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         state: &'a mut date_picker::State,
         on_cancel: Message,
-        on_submit: &'a dyn Fn(Date) -> Message,
+        on_submit: &'a OnSubmit<Message>,
         position: Point,
         class: &'a <Theme as crate::style::date_picker::Catalog>::Class<'b>,
         tree: &'a mut Tree,
         //button_style: impl Clone +  Into<<Renderer as button::Renderer>::Style>, // clone not satisfied
-        font_size: Pixels,
+        options: CalendarOptions<'a>,
+        text_format: Option<&'a str>,
     ) -> Self {
         let date_picker::State { overlay_state } = state;
 
+        // Dates can only be typed if a single date is picked.
+        let text_format = text_format.filter(|_| !options.range);
+        let text_input = text_format.map(|format| {
+            text_input::text_input(format, &overlay_state.text(format))
+                .size(options.font_size)
+                .padding(TEXT_INPUT_PADDING)
+                .width(Length::Fill)
+        });
+
         DatePickerOverlay {
             state: overlay_state,
             cancel_button: Button::new(
                 text::Text::new(icon_to_string(Bootstrap::X))
                     .font(crate::BOOTSTRAP_FONT)
-                    .size(font_size)
+                    .size(options.font_size)
                     .align_x(Horizontal::Center)
                     .width(Length::Fill),
             )
@@ -115,7 +138,7 @@ where
             submit_button: Button::new(
                 text::Text::new(icon_to_string(Bootstrap::Check))
                     .font(crate::BOOTSTRAP_FONT)
-                    .size(font_size)
+                    .size(options.font_size)
                     .align_x(Horizontal::Center)
                     .width(Length::Fill),
             )
@@ -125,7 +148,9 @@ where
             position,
             class,
             tree,
-            font_size,
+            options,
+            text_input,
+            text_format,
         }
     }
 
@@ -135,160 +160,6 @@ where
         overlay::Element::new(Box::new(self))
     }
 
-    /// String representation of the current month and year.
-    fn date_as_string(&self) -> String {
-        crate::core::date::date_as_string(self.state.date)
-    }
-
-    /// The event handling for the month / year bar.
-    fn on_event_month_year(
-        &mut self,
-        event: &Event,
-        layout: Layout<'_>,
-        cursor: Cursor,
-    ) -> event::Status {
-        let mut children = layout.children();
-
-        let mut status = event::Status::Ignored;
-
-        // ----------- Month ----------------------
-        let month_layout = children
-            .next()
-            .expect("widgets: Layout should have a month layout");
-        let mut month_children = month_layout.children();
-
-        let left_bounds = month_children
-            .next()
-            .expect("widgets: Layout should have a left month arrow layout")
-            .bounds();
-        let _center_bounds = month_children
-            .next()
-            .expect("widgets: Layout should have a center month layout")
-            .bounds();
-        let right_bounds = month_children
-            .next()
-            .expect("widgets: Layout should have a right month arrow layout")
-            .bounds();
-
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if cursor.is_over(month_layout.bounds()) {
-                    self.state.focus = Focus::Month;
-                }
-
-                if cursor.is_over(left_bounds) {
-                    self.state.date = crate::core::date::pred_month(self.state.date);
-                    status = event::Status::Captured;
-                } else if cursor.is_over(right_bounds) {
-                    self.state.date = crate::core::date::succ_month(self.state.date);
-                    status = event::Status::Captured;
-                }
-            }
-            _ => {}
-        }
-
-        // ----------- Year -----------------------
-        let year_layout = children
-            .next()
-            .expect("widgets: Layout should have a year layout");
-        let mut year_children = year_layout.children();
-
-        let left_bounds = year_children
-            .next()
-            .expect("widgets: Layout should have a left year arrow layout")
-            .bounds();
-        let _center_bounds = year_children
-            .next()
-            .expect("widgets: Layout should have a center year layout")
-            .bounds();
-        let right_bounds = year_children
-            .next()
-            .expect("widgets: Layout should have a right year arrow layout")
-            .bounds();
-
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if cursor.is_over(year_layout.bounds()) {
-                    self.state.focus = Focus::Year;
-                }
-
-                if cursor.is_over(left_bounds) {
-                    self.state.date = crate::core::date::pred_year(self.state.date);
-                    status = event::Status::Captured;
-                } else if cursor.is_over(right_bounds) {
-                    self.state.date = crate::core::date::succ_year(self.state.date);
-                    status = event::Status::Captured;
-                }
-            }
-            _ => {}
-        }
-
-        status
-    }
-
-    /// The event handling for the calendar days.
-    fn on_event_days(
-        &mut self,
-        event: &Event,
-        layout: Layout<'_>,
-        cursor: Cursor,
-    ) -> event::Status {
-        let mut children = layout.children();
-
-        let _day_labels_layout = children
-            .next()
-            .expect("widgets: Layout should have a day label layout");
-
-        let mut status = event::Status::Ignored;
-
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if cursor.is_over(layout.bounds()) {
-                    self.state.focus = Focus::Day;
-                }
-
-                'outer: for (y, row) in children.enumerate() {
-                    for (x, label) in row.children().enumerate() {
-                        let bounds = label.bounds();
-                        if cursor.is_over(bounds) {
-                            let (day, is_in_month) = crate::core::date::position_to_day(
-                                x,
-                                y,
-                                self.state.date.year(),
-                                self.state.date.month(),
-                            );
-
-                            self.state.date = match is_in_month {
-                                IsInMonth::Previous => {
-                                    crate::core::date::pred_month(self.state.date)
-                                        .with_day(day as u32)
-                                        .expect("Previous month with day should be valid")
-                                }
-                                IsInMonth::Same => self
-                                    .state
-                                    .date
-                                    .with_day(day as u32)
-                                    .expect("Same month with day should be valid"),
-                                IsInMonth::Next => crate::core::date::succ_month(self.state.date)
-                                    .with_day(day as u32)
-                                    .expect("Succeeding month with day should be valid"),
-                            };
-
-                            status = event::Status::Captured;
-                            break 'outer;
-                        }
-                    }
-                }
-            }
-            _ => {}
-        }
-
-        status
-    }
-
     /// The event handling for the keyboard input.
     fn on_event_keyboard(&mut self, event: &Event) -> event::Status {
         if self.state.focus == Focus::None {
@@ -305,51 +176,11 @@ where
                     } else {
                         self.state.focus = self.state.focus.next();
                     }
+                    self.state.sync_focus();
+                }
+                keyboard::Key::Named(k) => {
+                    status = on_event_calendar_keyboard(self.state, &self.options, k);
                 }
-                keyboard::Key::Named(k) => match self.state.focus {
-                    Focus::Month => match k {
-                        keyboard::key::Named::ArrowLeft => {
-                            self.state.date = crate::core::date::pred_month(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        keyboard::key::Named::ArrowRight => {
-                            self.state.date = crate::core::date::succ_month(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        _ => {}
-                    },
-                    Focus::Year => match k {
-                        keyboard::key::Named::ArrowLeft => {
-                            self.state.date = crate::core::date::pred_year(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        keyboard::key::Named::ArrowRight => {
-                            self.state.date = crate::core::date::succ_year(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        _ => {}
-                    },
-                    Focus::Day => match k {
-                        keyboard::key::Named::ArrowLeft => {
-                            self.state.date = crate::core::date::pred_day(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        keyboard::key::Named::ArrowRight => {
-                            self.state.date = crate::core::date::succ_day(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        keyboard::key::Named::ArrowUp => {
-                            self.state.date = crate::core::date::pred_week(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        keyboard::key::Named::ArrowDown => {
-                            self.state.date = crate::core::date::succ_week(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        _ => {}
-                    },
-                    _ => {}
-                },
                 _ => {}
             }
 
@@ -361,6 +192,62 @@ where
             event::Status::Ignored
         }
     }
+
+    /// The event handling for the text input, in which a date can be typed.
+    fn on_event_text_input(
+        &mut self,
+        event: &Event,
+        layout: Option<Layout<'_>>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let (Some(text_input), Some(format), Some(layout)) =
+            (&mut self.text_input, self.text_format, layout)
+        else {
+            return event::Status::Ignored;
+        };
+
+        let (status, messages) = text_input::on_event(
+            text_input,
+            &mut self.tree.children[2],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            || self.state.focus = Focus::None,
+        );
+
+        let restrictions = self.options.restrictions;
+        for message in messages {
+            match message {
+                TextMessage::Input(text) => {
+                    if let Some(date) = typed_date(&text, format, restrictions) {
+                        self.state.date = date;
+                    }
+                    self.state.text = Some(text);
+                }
+                TextMessage::Submit => {
+                    let date = self
+                        .state
+                        .text
+                        .as_deref()
+                        .map_or(Some(self.state.date), |text| {
+                            typed_date(text, format, restrictions)
+                        });
+
+                    if let (Some(date), OnSubmit::Single(on_submit)) = (date, self.on_submit) {
+                        shell.publish(on_submit(date.into()));
+                    }
+                }
+            }
+        }
+
+        status
+    }
 }
 
 impl<'a, 'b, Message, Theme> Overlay<Message, Theme, Renderer>
@@ -371,10 +258,10 @@ where
         + crate::style::date_picker::Catalog
         + iced::widget::button::Catalog
         + iced::widget::text::Catalog
-        + iced::widget::container::Catalog,
+        + iced::widget::container::Catalog
+        + iced::widget::text_input::Catalog,
     'b: 'a,
 {
-    #[allow(clippy::too_many_lines)]
     fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
         let limits = Limits::new(Size::ZERO, bounds)
             .shrink(Padding::from(PADDING))
@@ -389,117 +276,38 @@ where
 
         let limits = limits.shrink(Size::new(0.0, cancel_button.bounds().height + SPACING));
 
-        // Month/Year
-        let font_size = self.font_size;
-
-        let month_year = Row::<Message, Theme, Renderer>::new()
-            .width(Length::Shrink)
-            .spacing(SPACING)
-            .push(
-                Row::new()
-                    .width(Length::Shrink)
-                    .spacing(SPACING)
-                    .align_y(Alignment::Center)
-                    .push(
-                        // Left Month arrow
-                        Container::new(
-                            Text::new(icon_to_string(Bootstrap::CaretLeftFill))
-                                .size(font_size.0 + 1.0)
-                                .font(crate::BOOTSTRAP_FONT),
-                        )
-                        .height(Length::Shrink)
-                        .width(Length::Shrink),
-                    )
-                    .push(
-                        // Month
-                        Text::new("September").size(font_size).width(Length::Shrink),
-                    )
-                    .push(
-                        // Right Month arrow
-                        Container::new(
-                            Text::new(icon_to_string(Bootstrap::CaretRightFill))
-                                .size(font_size.0 + 1.0)
-                                .font(crate::BOOTSTRAP_FONT),
-                        )
-                        .height(Length::Shrink)
-                        .width(Length::Shrink),
-                    ),
-            )
-            .push(
-                Row::new()
-                    .width(Length::Shrink)
-                    .spacing(SPACING)
-                    .align_y(Alignment::Center)
-                    .push(
-                        // Left Year arrow
-                        Container::new(
-                            Text::new(icon_to_string(Bootstrap::CaretLeftFill))
-                                .size(font_size.0 + 1.0)
-                                .font(BOOTSTRAP_FONT),
-                        )
-                        .height(Length::Shrink)
-                        .width(Length::Shrink),
-                    )
-                    .push(
-                        // Year
-                        Text::new("9999").size(font_size).width(Length::Shrink),
-                    )
-                    .push(
-                        // Right Year arrow
-                        Container::new(
-                            Text::new(icon_to_string(Bootstrap::CaretRightFill))
-                                .size(font_size.0 + 1.0)
-                                .font(BOOTSTRAP_FONT),
-                        )
-                        .height(Length::Shrink)
-                        .width(Length::Shrink),
-                    ),
-            );
-
-        let days = Container::<Message, Theme, Renderer>::new((0..7).fold(
-            Column::new().width(Length::Shrink).height(Length::Shrink),
-            |column, _y| {
-                column.push(
-                    (0..7).fold(
-                        Row::new()
-                            .height(Length::Shrink)
-                            .width(Length::Shrink)
-                            .spacing(SPACING),
-                        |row, _x| {
-                            row.push(
-                                Container::new(Row::new().push(Text::new("31").size(font_size)))
-                                    .width(Length::Shrink)
-                                    .height(Length::Shrink)
-                                    .padding(DAY_CELL_PADDING),
-                            )
-                        },
-                    ),
-                )
-            },
-        ))
-        .width(Length::Shrink)
-        .height(Length::Shrink)
-        .center_y(Length::Shrink);
-
-        let col = Column::<Message, Theme, Renderer>::new()
-            .spacing(SPACING)
-            .align_x(Alignment::Center)
-            .push(month_year)
-            .push(days);
-
-        let element: Element<Message, Theme, Renderer> = Element::new(col);
-        let col_tree = if let Some(child_tree) = self.tree.children.get_mut(2) {
+        // Month/Year and Days
+        let element = calendar_element::<Message, Theme>(&self.options);
+        let col_tree = if let Some(child_tree) = self.tree.children.get_mut(3) {
             child_tree.diff(element.as_widget());
             child_tree
         } else {
             let child_tree = Tree::new(element.as_widget());
-            self.tree.children.insert(2, child_tree);
-            &mut self.tree.children[2]
+            self.tree.children.insert(3, child_tree);
+            &mut self.tree.children[3]
         };
 
         let mut col = element.as_widget().layout(col_tree, renderer, &limits);
+
+        // Text input above the calendar
+        let text_input = self.text_input.as_ref().map(|text_input| {
+            Widget::<TextMessage, Theme, Renderer>::layout(
+                text_input,
+                &mut self.tree.children[2],
+                renderer,
+                &limits.max_width(col.bounds().width),
+            )
+            .move_to(Point::new(PADDING, PADDING))
+        });
+        let text_input_height = text_input
+            .as_ref()
+            .map_or(0.0, |text_input| text_input.bounds().height + SPACING);
+
         let col_bounds = col.bounds();
-        col = col.move_to(Point::new(col_bounds.x + PADDING, col_bounds.y + PADDING));
+        col = col.move_to(Point::new(
+            col_bounds.x + PADDING,
+            col_bounds.y + PADDING + text_input_height,
+        ));
 
         // Buttons
         let cancel_limits =
@@ -519,21 +327,29 @@ where
         let cancel_bounds = cancel_button.bounds();
         cancel_button = cancel_button.move_to(Point {
             x: cancel_bounds.x + PADDING,
-            y: cancel_bounds.y + col.bounds().height + PADDING + SPACING,
+            y: cancel_bounds.y + col.bounds().height + text_input_height + PADDING + SPACING,
         });
 
         let submit_bounds = submit_button.bounds();
         submit_button = submit_button.move_to(Point {
             x: submit_bounds.x + col.bounds().width - submit_bounds.width + PADDING,
-            y: submit_bounds.y + col.bounds().height + PADDING + SPACING,
+            y: submit_bounds.y + col.bounds().height + text_input_height + PADDING + SPACING,
         });
 
         let mut node = Node::with_children(
             Size::new(
                 col.bounds().width + (2.0 * PADDING),
-                col.bounds().height + cancel_button.bounds().height + (2.0 * PADDING) + SPACING,
+                col.bounds().height
+                    + text_input_height
+                    + cancel_button.bounds().height
+                    + (2.0 * PADDING)
+                    + SPACING,
             ),
-            vec![col, cancel_button, submit_button],
+            std::iter::once(col)
+                .chain(std::iter::once(cancel_button))
+                .chain(std::iter::once(submit_button))
+                .chain(text_input)
+                .collect(),
         );
         node.center_and_bounce(self.position, bounds);
         node
@@ -548,31 +364,32 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
     ) -> event::Status {
+        let previous_date = self.state.date;
         if event::Status::Captured == self.on_event_keyboard(&event) {
+            self.state.sync_text(previous_date);
             return event::Status::Captured;
         }
 
-        let mut children = layout.children();
-
-        let mut date_children = children
-            .next()
-            .expect("widgets: Layout should have date children")
-            .children();
+        // ----------- Text input ---------------------
+        let text_input_status = self.on_event_text_input(
+            &event,
+            layout.children().nth(3),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+        );
 
-        // ----------- Year/Month----------------------
-        let month_year_layout = date_children
-            .next()
-            .expect("widgets: Layout should have a month/year layout");
-        let month_year_status = self.on_event_month_year(&event, month_year_layout, cursor);
+        let mut children = layout.children();
 
-        // ----------- Days ----------------------
-        let days_layout = date_children
-            .next()
-            .expect("widgets: Layout should have a days table parent")
-            .children()
+        // ----------- Year/Month and Days ------------
+        let previous_date = self.state.date;
+        let calendar_layout = children
             .next()
-            .expect("widgets: Layout should have a days table layout");
-        let days_status = self.on_event_days(&event, days_layout, cursor);
+            .expect("widgets: Layout should have a calendar layout");
+        let calendar_status =
+            on_event_calendar(self.state, &self.options, &event, calendar_layout, cursor);
+        self.state.sync_text(previous_date);
 
         // ----------- Buttons ------------------------
         let cancel_button_layout = children
@@ -608,11 +425,23 @@ where
         );
 
         if !fake_messages.is_empty() {
-            shell.publish((self.on_submit)(self.state.date.into()));
+            let message = match self.on_submit {
+                OnSubmit::Single(on_submit) => {
+                    on_submit(self.options.restrictions.nearest(self.state.date).into())
+                }
+                OnSubmit::Range(on_submit) => {
+                    let (start, end) = self
+                        .state
+                        .range()
+                        .unwrap_or((self.state.date, self.state.date));
+                    on_submit(start.into(), end.into())
+                }
+            };
+            shell.publish(message);
         }
 
-        month_year_status
-            .merge(days_status)
+        text_input_status
+            .merge(calendar_status)
             .merge(cancel_status)
             .merge(submit_status)
     }
@@ -627,74 +456,16 @@ where
         let mouse_interaction = mouse::Interaction::default();
 
         let mut children = layout.children();
-        let mut date_children = children
-            .next()
-            .expect("Graphics: Layout should have a date layout")
-            .children();
-
-        // Month and year mouse interaction
-        let month_year_layout = date_children
-            .next()
-            .expect("Graphics: Layout should have a month/year layout");
-        let mut month_year_children = month_year_layout.children();
-        let month_layout = month_year_children
-            .next()
-            .expect("Graphics: Layout should have a month layout");
-        let year_layout = month_year_children
-            .next()
-            .expect("Graphics: Layout should have a year layout");
-
-        let f = |layout: Layout<'_>| {
-            let mut children = layout.children();
 
-            let left_bounds = children
-                .next()
-                .expect("Graphics: Layout should have a left arrow layout")
-                .bounds();
-            let _center = children.next();
-            let right_bounds = children
+        // Month, year and days mouse interaction
+        let calendar_mouse_interaction = calendar_mouse_interaction(
+            self.state,
+            &self.options,
+            children
                 .next()
-                .expect("Graphics: Layout should have a right arrow layout")
-                .bounds();
-
-            let mut mouse_interaction = mouse::Interaction::default();
-
-            let left_arrow_hovered = cursor.is_over(left_bounds);
-            let right_arrow_hovered = cursor.is_over(right_bounds);
-
-            if left_arrow_hovered || right_arrow_hovered {
-                mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
-            }
-
-            mouse_interaction
-        };
-
-        let month_mouse_interaction = f(month_layout);
-        let year_mouse_interaction = f(year_layout);
-
-        // Days
-        let days_layout = date_children
-            .next()
-            .expect("Graphics: Layout should have a days layout parent")
-            .children()
-            .next()
-            .expect("Graphics: Layout should have a days layout");
-        let mut days_children = days_layout.children();
-        let _day_labels_layout = days_children.next();
-
-        let mut table_mouse_interaction = mouse::Interaction::default();
-
-        for row in days_children {
-            for label in row.children() {
-                let bounds = label.bounds();
-
-                let mouse_over = cursor.is_over(bounds);
-                if mouse_over {
-                    table_mouse_interaction =
-                        table_mouse_interaction.max(mouse::Interaction::Pointer);
-                }
-            }
-        }
+                .expect("Graphics: Layout should have a calendar layout"),
+            cursor,
+        );
 
         // Buttons
         let cancel_button_layout = children
@@ -721,12 +492,26 @@ where
             renderer,
         );
 
+        // Text input
+        let text_input_mouse_interaction = match (&self.text_input, children.next()) {
+            (Some(text_input), Some(text_input_layout)) => {
+                Widget::<TextMessage, Theme, Renderer>::mouse_interaction(
+                    text_input,
+                    &self.tree.children[2],
+                    text_input_layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            }
+            _ => mouse::Interaction::default(),
+        };
+
         mouse_interaction
-            .max(month_mouse_interaction)
-            .max(year_mouse_interaction)
-            .max(table_mouse_interaction)
+            .max(calendar_mouse_interaction)
             .max(cancel_button_mouse_interaction)
             .max(submit_button_mouse_interaction)
+            .max(text_input_mouse_interaction)
     }
 
     fn draw(
@@ -739,28 +524,8 @@ where
     ) {
         let bounds = layout.bounds();
         let mut children = layout.children();
-        let mut date_children = children
-            .next()
-            .expect("Graphics: Layout should have a date layout")
-            .children();
 
-        let mut style_sheet: HashMap<StyleState, Style> = HashMap::new();
-        let _ = style_sheet.insert(
-            StyleState::Active,
-            crate::style::date_picker::Catalog::style(theme, self.class, Status::Active),
-        );
-        let _ = style_sheet.insert(
-            StyleState::Selected,
-            crate::style::date_picker::Catalog::style(theme, self.class, Status::Selected),
-        );
-        let _ = style_sheet.insert(
-            StyleState::Hovered,
-            crate::style::date_picker::Catalog::style(theme, self.class, Status::Hovered),
-        );
-        let _ = style_sheet.insert(
-            StyleState::Focused,
-            crate::style::date_picker::Catalog::style(theme, self.class, Status::Focused),
-        );
+        let style_sheet = style_sheet(theme, self.class);
 
         let mut style_state = StyleState::Active;
         if self.state.focus == Focus::Overlay {
@@ -786,37 +551,16 @@ where
             );
         }
 
-        // ----------- Year/Month----------------------
-        let month_year_layout = date_children
-            .next()
-            .expect("Graphics: Layout should have a month/year layout");
-
-        month_year(
-            renderer,
-            month_year_layout,
-            &self.date_as_string(),
-            cursor.position().unwrap_or_default(),
-            &style_sheet,
-            self.state.focus,
-            self.font_size,
-        );
-
-        // ----------- Days ---------------------------
-        let days_layout = date_children
-            .next()
-            .expect("Graphics: Layout should have a days layout parent")
-            .children()
-            .next()
-            .expect("Graphics: Layout should have a days layout");
-
-        days(
+        // ----------- Year/Month and Days ------------
+        draw_calendar(
             renderer,
-            days_layout,
-            self.state.date,
-            cursor.position().unwrap_or_default(),
+            self.state,
+            &self.options,
+            children
+                .next()
+                .expect("Graphics: Layout should have a calendar layout"),
+            cursor,
             &style_sheet,
-            self.state.focus,
-            self.font_size,
         );
 
         // ----------- Buttons ------------------------
@@ -848,6 +592,33 @@ where
             &bounds,
         );
 
+        // ----------- Text input ---------------------
+        if let (Some(text_input), Some(format), Some(text_input_layout)) =
+            (&self.text_input, self.text_format, children.next())
+        {
+            Widget::<TextMessage, Theme, Renderer>::draw(
+                text_input,
+                &self.tree.children[2],
+                renderer,
+                theme,
+                style,
+                text_input_layout,
+                cursor,
+                &bounds,
+            );
+
+            let is_valid = self.state.text.as_deref().map_or(true, |text| {
+                typed_date(text, format, self.options.restrictions).is_some()
+            });
+            if !is_valid {
+                text_input::draw_error(
+                    renderer,
+                    text_input_layout.bounds(),
+                    style_sheet[&StyleState::Active].error_color,
+                );
+            }
+        }
+
         // Buttons are not focusable right now...
         let cancel_button_bounds = cancel_button_layout.bounds();
         if (self.state.focus == Focus::Cancel)
@@ -898,6 +669,14 @@ pub struct State {
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// The first picked end of the selected range.
+    pub(crate) range_start: Option<NaiveDate>,
+    /// The second picked end of the selected range.
+    pub(crate) range_end: Option<NaiveDate>,
+    /// The text typed into the text input, if it differs from the date.
+    pub(crate) text: Option<String>,
+    /// The zoom level of the calendar.
+    pub(crate) view: View,
 }
 
 impl State {
@@ -909,6 +688,74 @@ impl State {
             ..Self::default()
         }
     }
+
+    /// Creates a new State with the given range of dates.
+    #[must_use]
+    pub fn with_range(start: NaiveDate, end: NaiveDate) -> Self {
+        Self {
+            date: start,
+            range_start: Some(start),
+            range_end: Some(end),
+            ..Self::default()
+        }
+    }
+
+    /// Gets the selected range ordered by its start and end.
+    #[must_use]
+    pub fn range(&self) -> Option<(NaiveDate, NaiveDate)> {
+        self.range_start.map(|start| {
+            let end = self.range_end.unwrap_or(start);
+            (start.min(end), start.max(end))
+        })
+    }
+
+    /// Picks the given day as an end of the range. A completed range is
+    /// replaced by a new one starting at the given day.
+    pub(crate) fn pick_range_day(&mut self, day: NaiveDate) {
+        if self.range_start.is_some() && self.range_end.is_none() {
+            self.range_end = Some(day);
+        } else {
+            self.range_start = Some(day);
+            self.range_end = None;
+        }
+    }
+
+    /// Extends the range to the given day, starting it at the anchor if no
+    /// range is selected yet.
+    pub(crate) fn extend_range(&mut self, anchor: NaiveDate, day: NaiveDate) {
+        if self.range_start.is_none() {
+            self.range_start = Some(anchor);
+        }
+        self.range_end = Some(day);
+    }
+
+    /// Switches the calendar to the given view, moving the focus to its table
+    /// if a table is focused.
+    pub(crate) fn set_view(&mut self, view: View) {
+        self.view = view;
+        self.sync_focus();
+    }
+
+    /// Moves the focus of a table to the table shown in the current view.
+    pub(crate) fn sync_focus(&mut self) {
+        if self.focus.is_table() {
+            self.focus = self.view.focus();
+        }
+    }
+
+    /// Gets the text shown in the text input in the given format.
+    pub(crate) fn text(&self, format: &str) -> String {
+        self.text
+            .clone()
+            .or_else(|| format_date(self.date, format))
+            .unwrap_or_default()
+    }
+
+    /// Drops the typed text if the date was changed by something else than
+    /// the text input.
+    pub(crate) fn sync_text(&mut self, previous_date: NaiveDate) {
+        text_input::sync_text(&mut self.text, &self.date, &previous_date);
+    }
 }
 
 impl Default for State {
@@ -917,6 +764,10 @@ impl Default for State {
             date: Local::now().naive_local().date(),
             focus: Focus::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            range_start: None,
+            range_end: None,
+            text: None,
+            view: View::default(),
         }
     }
 }
@@ -926,12 +777,16 @@ impl Default for State {
 pub struct DatePickerOverlayButtons<'a, Message, Theme>
 where
     Message: Clone,
-    Theme: crate::style::date_picker::Catalog + iced::widget::button::Catalog,
+    Theme: crate::style::date_picker::Catalog
+        + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog,
 {
     /// The cancel button of the [`DatePickerOverlay`].
     cancel_button: Element<'a, Message, Theme, Renderer>,
     /// The submit button of the [`DatePickerOverlay`].
     submit_button: Element<'a, Message, Theme, Renderer>,
+    /// The text input of the [`DatePickerOverlay`].
+    text_input: TextInput<'a, TextMessage, Theme, Renderer>,
 }
 
 impl<'a, Message, Theme> Default for DatePickerOverlayButtons<'a, Message, Theme>
@@ -941,6 +796,7 @@ where
         + crate::style::date_picker::Catalog
         + iced::widget::button::Catalog
         + iced::widget::text::Catalog
+        + iced::widget::text_input::Catalog
         + iced::widget::container::Catalog,
 {
     fn default() -> Self {
@@ -959,6 +815,7 @@ where
                     .width(Length::Fill),
             )
             .into(),
+            text_input: text_input::text_input("", ""),
         }
     }
 }
@@ -970,17 +827,20 @@ where
     Message: Clone,
     Theme: crate::style::date_picker::Catalog
         + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog
         + iced::widget::container::Catalog,
 {
     fn children(&self) -> Vec<Tree> {
-        vec![
-            Tree::new(&self.cancel_button),
-            Tree::new(&self.submit_button),
-        ]
+        text_input::children(&self.cancel_button, &self.submit_button, &self.text_input)
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&[&self.cancel_button, &self.submit_button]);
+        text_input::diff(
+            tree,
+            &self.cancel_button,
+            &self.submit_button,
+            &self.text_input,
+        );
     }
 
     fn size(&self) -> Size<Length> {
@@ -1012,6 +872,7 @@ where
     Theme: 'a
         + crate::style::date_picker::Catalog
         + iced::widget::button::Catalog
+        + iced::widget::text_input::Catalog
         + iced::widget::container::Catalog,
 {
     fn from(overlay: DatePickerOverlayButtons<'a, Message, Theme>) -> Self {
@@ -1019,6 +880,11 @@ where
     }
 }
 
+/// Parses the typed text into a date that can be picked.
+fn typed_date(text: &str, format: &str, restrictions: &Restrictions) -> Option<NaiveDate> {
+    parse_date(text, format).filter(|date| !restrictions.is_disabled(*date))
+}
+
 /// An enumeration of all focusable elements of the [`DatePickerOverlay`].
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Focus {
@@ -1037,6 +903,12 @@ pub enum Focus {
     /// The day area is in focus.
     Day,
 
+    /// The month table of the zoomed out calendar is in focus.
+    Months,
+
+    /// The year table of the zoomed out calendar is in focus.
+    Years,
+
     /// The cancel button is in focus.
     Cancel,
 
@@ -1052,7 +924,7 @@ impl Focus {
             Self::Overlay => Self::Month,
             Self::Month => Self::Year,
             Self::Year => Self::Day,
-            Self::Day => Self::Cancel,
+            Self::Day | Self::Months | Self::Years => Self::Cancel,
             Self::Cancel => Self::Submit,
             Self::Submit | Self::None => Self::Overlay,
         }
@@ -1066,11 +938,17 @@ impl Focus {
             Self::Overlay => Self::Submit,
             Self::Month => Self::Overlay,
             Self::Year => Self::Month,
-            Self::Day => Self::Year,
+            Self::Day | Self::Months | Self::Years => Self::Year,
             Self::Cancel => Self::Day,
             Self::Submit => Self::Cancel,
         }
     }
+
+    /// Checks if the focus is on the table of days, months or years.
+    #[must_use]
+    pub const fn is_table(self) -> bool {
+        matches!(self, Self::Day | Self::Months | Self::Years)
+    }
 }
 
 impl Default for Focus {
@@ -1079,7 +957,825 @@ impl Default for Focus {
     }
 }
 
-/// Draws the month/year row
+/// The zoom levels of the calendar of the [`DatePickerOverlay`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum View {
+    /// The days of the month are shown.
+    Days,
+
+    /// The months of the year are shown.
+    Months,
+
+    /// The years of the decade are shown.
+    Years,
+}
+
+impl View {
+    /// Gets the view one zoom level further out.
+    #[must_use]
+    pub const fn zoom_out(self) -> Self {
+        match self {
+            Self::Days => Self::Months,
+            Self::Months | Self::Years => Self::Years,
+        }
+    }
+
+    /// Gets the view one zoom level further in.
+    #[must_use]
+    pub const fn zoom_in(self) -> Self {
+        match self {
+            Self::Days | Self::Months => Self::Days,
+            Self::Years => Self::Months,
+        }
+    }
+
+    /// Gets the focus of the table shown in the view.
+    #[must_use]
+    pub const fn focus(self) -> Focus {
+        match self {
+            Self::Days => Focus::Day,
+            Self::Months => Focus::Months,
+            Self::Years => Focus::Years,
+        }
+    }
+}
+
+impl Default for View {
+    fn default() -> Self {
+        Self::Days
+    }
+}
+
+/// The options of the calendar shown by the [`DatePickerOverlay`] and the
+/// [`Calendar`](crate::widgets::Calendar).
+#[allow(missing_debug_implementations)]
+#[derive(Clone, Copy)]
+pub struct CalendarOptions<'a> {
+    /// Pick a range of dates instead of a single date.
+    pub range: bool,
+    /// The restrictions on the days that can be picked.
+    pub restrictions: &'a Restrictions,
+    /// The font size of text and icons.
+    pub font_size: Pixels,
+    /// The locale of the month and weekday names.
+    pub locale: Locale,
+    /// The first day of the week.
+    pub first_weekday: Weekday,
+    /// Show the ISO week numbers on the left of the days.
+    pub week_numbers: bool,
+    /// The function deciding which days get a [`Marker`].
+    pub day_decorator: Option<&'a DayDecorator>,
+}
+
+/// Creates the element used for the layout of the month/year row and the
+/// days of a calendar.
+pub(crate) fn calendar_element<'a, Message, Theme>(
+    options: &CalendarOptions<'_>,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a + iced::widget::text::Catalog + iced::widget::container::Catalog,
+{
+    let font_size = options.font_size;
+
+    let month_year = Row::<Message, Theme, Renderer>::new()
+        .width(Length::Shrink)
+        .spacing(SPACING)
+        .push(
+            Row::new()
+                .width(Length::Shrink)
+                .spacing(SPACING)
+                .align_y(Alignment::Center)
+                .push(
+                    // Left Month arrow
+                    Container::new(
+                        Text::new(icon_to_string(Bootstrap::CaretLeftFill))
+                            .size(font_size.0 + 1.0)
+                            .font(crate::BOOTSTRAP_FONT),
+                    )
+                    .height(Length::Shrink)
+                    .width(Length::Shrink),
+                )
+                .push(
+                    // Month
+                    Text::new(crate::core::date::longest_month_name(options.locale))
+                        .size(font_size)
+                        .shaping(text::Shaping::Advanced)
+                        .width(Length::Shrink),
+                )
+                .push(
+                    // Right Month arrow
+                    Container::new(
+                        Text::new(icon_to_string(Bootstrap::CaretRightFill))
+                            .size(font_size.0 + 1.0)
+                            .font(crate::BOOTSTRAP_FONT),
+                    )
+                    .height(Length::Shrink)
+                    .width(Length::Shrink),
+                ),
+        )
+        .push(
+            Row::new()
+                .width(Length::Shrink)
+                .spacing(SPACING)
+                .align_y(Alignment::Center)
+                .push(
+                    // Left Year arrow
+                    Container::new(
+                        Text::new(icon_to_string(Bootstrap::CaretLeftFill))
+                            .size(font_size.0 + 1.0)
+                            .font(BOOTSTRAP_FONT),
+                    )
+                    .height(Length::Shrink)
+                    .width(Length::Shrink),
+                )
+                .push(
+                    // Year
+                    Text::new("9999").size(font_size).width(Length::Shrink),
+                )
+                .push(
+                    // Right Year arrow
+                    Container::new(
+                        Text::new(icon_to_string(Bootstrap::CaretRightFill))
+                            .size(font_size.0 + 1.0)
+                            .font(BOOTSTRAP_FONT),
+                    )
+                    .height(Length::Shrink)
+                    .width(Length::Shrink),
+                ),
+        );
+
+    let day_cell = || {
+        Container::<Message, Theme, Renderer>::new(Row::new().push(Text::new("31").size(font_size)))
+            .width(Length::Shrink)
+            .height(Length::Shrink)
+            .padding(DAY_CELL_PADDING)
+    };
+    let week_numbers = options.week_numbers;
+
+    let days = Container::<Message, Theme, Renderer>::new((0..7).fold(
+        Column::new().width(Length::Shrink).height(Length::Shrink),
+        |column, _y| {
+            let row = Row::new()
+                .height(Length::Shrink)
+                .width(Length::Shrink)
+                .spacing(SPACING);
+            // The column of the week numbers
+            let row = if week_numbers {
+                row.push(day_cell())
+            } else {
+                row
+            };
+
+            column.push((0..7).fold(row, |row, _x| row.push(day_cell())))
+        },
+    ))
+    .width(Length::Shrink)
+    .height(Length::Shrink)
+    .center_y(Length::Shrink);
+
+    Column::<Message, Theme, Renderer>::new()
+        .spacing(SPACING)
+        .align_x(Alignment::Center)
+        .push(month_year)
+        .push(days)
+        .into()
+}
+
+/// Creates the style sheet of a calendar for all of its style states.
+pub(crate) fn style_sheet<Theme>(
+    theme: &Theme,
+    class: &<Theme as crate::style::date_picker::Catalog>::Class<'_>,
+) -> HashMap<StyleState, Style>
+where
+    Theme: crate::style::date_picker::Catalog,
+{
+    let mut style_sheet: HashMap<StyleState, Style> = HashMap::new();
+    let _ = style_sheet.insert(
+        StyleState::Active,
+        crate::style::date_picker::Catalog::style(theme, class, Status::Active),
+    );
+    let _ = style_sheet.insert(
+        StyleState::Selected,
+        crate::style::date_picker::Catalog::style(theme, class, Status::Selected),
+    );
+    let _ = style_sheet.insert(
+        StyleState::Hovered,
+        crate::style::date_picker::Catalog::style(theme, class, Status::Hovered),
+    );
+    let _ = style_sheet.insert(
+        StyleState::Focused,
+        crate::style::date_picker::Catalog::style(theme, class, Status::Focused),
+    );
+    let _ = style_sheet.insert(
+        StyleState::Disabled,
+        crate::style::date_picker::Catalog::style(theme, class, Status::Disabled),
+    );
+
+    style_sheet
+}
+
+/// The event handling for the month/year row and the days of a calendar.
+pub(crate) fn on_event_calendar(
+    state: &mut State,
+    options: &CalendarOptions<'_>,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: Cursor,
+) -> event::Status {
+    let mut children = layout.children();
+
+    // ----------- Year/Month----------------------
+    let month_year_layout = children
+        .next()
+        .expect("widgets: Layout should have a month/year layout");
+    let month_year_status = on_event_month_year(state, options, event, month_year_layout, cursor);
+
+    // ----------- Days ----------------------
+    let days_parent_layout = children
+        .next()
+        .expect("widgets: Layout should have a days table parent");
+    let days_status = if state.view == View::Days {
+        let days_layout = days_parent_layout
+            .children()
+            .next()
+            .expect("widgets: Layout should have a days table layout");
+        on_event_days(state, options, event, days_layout, cursor)
+    } else {
+        on_event_zoom_table(state, options, event, days_parent_layout.bounds(), cursor)
+    };
+
+    month_year_status.merge(days_status)
+}
+
+/// The event handling for the month / year bar.
+fn on_event_month_year(
+    state: &mut State,
+    options: &CalendarOptions<'_>,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: Cursor,
+) -> event::Status {
+    let mut children = layout.children();
+
+    let mut status = event::Status::Ignored;
+
+    // ----------- Month ----------------------
+    let month_layout = children
+        .next()
+        .expect("widgets: Layout should have a month layout");
+    let mut month_children = month_layout.children();
+
+    let left_bounds = month_children
+        .next()
+        .expect("widgets: Layout should have a left month arrow layout")
+        .bounds();
+    let center_bounds = month_children
+        .next()
+        .expect("widgets: Layout should have a center month layout")
+        .bounds();
+    let right_bounds = month_children
+        .next()
+        .expect("widgets: Layout should have a right month arrow layout")
+        .bounds();
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if cursor.is_over(month_layout.bounds()) {
+                state.focus = Focus::Month;
+            }
+
+            if cursor.is_over(left_bounds) {
+                state.date = options
+                    .restrictions
+                    .nearest(crate::core::date::pred_month(state.date));
+                status = event::Status::Captured;
+            } else if cursor.is_over(right_bounds) {
+                state.date = options
+                    .restrictions
+                    .nearest(crate::core::date::succ_month(state.date));
+                status = event::Status::Captured;
+            } else if cursor.is_over(center_bounds) {
+                state.set_view(state.view.zoom_out());
+                status = event::Status::Captured;
+            }
+        }
+        _ => {}
+    }
+
+    // ----------- Year -----------------------
+    let year_layout = children
+        .next()
+        .expect("widgets: Layout should have a year layout");
+    let mut year_children = year_layout.children();
+
+    let left_bounds = year_children
+        .next()
+        .expect("widgets: Layout should have a left year arrow layout")
+        .bounds();
+    let center_bounds = year_children
+        .next()
+        .expect("widgets: Layout should have a center year layout")
+        .bounds();
+    let right_bounds = year_children
+        .next()
+        .expect("widgets: Layout should have a right year arrow layout")
+        .bounds();
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if cursor.is_over(year_layout.bounds()) {
+                state.focus = Focus::Year;
+            }
+
+            if cursor.is_over(left_bounds) {
+                state.date = options.restrictions.nearest(pred_years(state));
+                status = event::Status::Captured;
+            } else if cursor.is_over(right_bounds) {
+                state.date = options.restrictions.nearest(succ_years(state));
+                status = event::Status::Captured;
+            } else if cursor.is_over(center_bounds) {
+                state.set_view(View::Years);
+                status = event::Status::Captured;
+            }
+        }
+        _ => {}
+    }
+
+    status
+}
+
+/// The event handling for the calendar days.
+fn on_event_days(
+    state: &mut State,
+    options: &CalendarOptions<'_>,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: Cursor,
+) -> event::Status {
+    let mut children = layout.children();
+
+    let _day_labels_layout = children
+        .next()
+        .expect("widgets: Layout should have a day label layout");
+
+    let mut status = event::Status::Ignored;
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if cursor.is_over(layout.bounds()) {
+                state.focus = Focus::Day;
+            }
+
+            'outer: for (y, row) in children.enumerate() {
+                for (x, label) in row
+                    .children()
+                    .skip(usize::from(options.week_numbers))
+                    .enumerate()
+                {
+                    let bounds = label.bounds();
+                    if cursor.is_over(bounds) {
+                        let date = crate::core::date::position_to_date(
+                            x,
+                            y,
+                            state.date,
+                            options.first_weekday,
+                        );
+                        if options.restrictions.is_disabled(date) {
+                            break 'outer;
+                        }
+
+                        state.date = date;
+
+                        if options.range {
+                            state.pick_range_day(state.date);
+                        }
+
+                        status = event::Status::Captured;
+                        break 'outer;
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+
+    status
+}
+
+/// The event handling for the month or year table of the zoomed out calendar.
+fn on_event_zoom_table(
+    state: &mut State,
+    options: &CalendarOptions<'_>,
+    event: &Event,
+    bounds: Rectangle,
+    cursor: Cursor,
+) -> event::Status {
+    let mut status = event::Status::Ignored;
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if cursor.is_over(bounds) {
+                state.focus = state.view.focus();
+            }
+
+            for (x, y, cell_bounds) in zoom_table_cells(bounds) {
+                if cursor.is_over(cell_bounds) {
+                    let (date, first, last) = zoom_cell(state.view, x, y, state.date);
+                    if options.restrictions.is_outside(first, last) {
+                        break;
+                    }
+
+                    state.date = options.restrictions.nearest(date);
+                    state.set_view(state.view.zoom_in());
+                    status = event::Status::Captured;
+                    break;
+                }
+            }
+        }
+        _ => {}
+    }
+
+    status
+}
+
+/// Gets the cells of the month or year table of the zoomed out calendar
+/// spread over the given bounds.
+fn zoom_table_cells(bounds: Rectangle) -> impl Iterator<Item = (usize, usize, Rectangle)> {
+    let width = bounds.width / crate::core::date::ZOOM_TABLE_COLUMNS as f32;
+    let height = bounds.height / crate::core::date::ZOOM_TABLE_ROWS as f32;
+
+    (0..crate::core::date::ZOOM_TABLE_ROWS).flat_map(move |y| {
+        (0..crate::core::date::ZOOM_TABLE_COLUMNS).map(move |x| {
+            let cell_bounds = Rectangle {
+                x: bounds.x + x as f32 * width + BUTTON_SPACING,
+                y: bounds.y + y as f32 * height + BUTTON_SPACING,
+                width: (width - 2.0 * BUTTON_SPACING).max(0.0),
+                height: (height - 2.0 * BUTTON_SPACING).max(0.0),
+            };
+            (x, y, cell_bounds)
+        })
+    })
+}
+
+/// Gets the date picked by the cell at the given position of the month or
+/// year table together with the first and the last day of its month or year.
+fn zoom_cell(view: View, x: usize, y: usize, date: NaiveDate) -> (NaiveDate, NaiveDate, NaiveDate) {
+    if view == View::Months {
+        let date = crate::core::date::with_month(date, crate::core::date::position_to_month(x, y));
+        let first = date
+            .with_day(1)
+            .expect("First day of the month should be valid");
+        let last = crate::core::date::pred_day(crate::core::date::succ_month(first));
+        (date, first, last)
+    } else {
+        let date = crate::core::date::with_year(
+            date,
+            crate::core::date::position_to_year(x, y, date.year()),
+        );
+        let first = date
+            .with_ordinal(1)
+            .expect("First day of the year should be valid");
+        let last = crate::core::date::pred_day(crate::core::date::succ_year(first));
+        (date, first, last)
+    }
+}
+
+/// Gets the date of the previous year, or of the previous decade if the
+/// years are shown.
+fn pred_years(state: &State) -> NaiveDate {
+    if state.view == View::Years {
+        crate::core::date::with_year(state.date, state.date.year() - 10)
+    } else {
+        crate::core::date::pred_year(state.date)
+    }
+}
+
+/// Gets the date of the next year, or of the next decade if the years are
+/// shown.
+fn succ_years(state: &State) -> NaiveDate {
+    if state.view == View::Years {
+        crate::core::date::with_year(state.date, state.date.year() + 10)
+    } else {
+        crate::core::date::succ_year(state.date)
+    }
+}
+
+/// The event handling for the keys moving through the month, year and days
+/// of a calendar.
+pub(crate) fn on_event_calendar_keyboard(
+    state: &mut State,
+    options: &CalendarOptions<'_>,
+    key: keyboard::key::Named,
+) -> event::Status {
+    let mut status = event::Status::Ignored;
+
+    match state.focus {
+        Focus::Month => match key {
+            keyboard::key::Named::ArrowLeft => {
+                state.date = options
+                    .restrictions
+                    .nearest(crate::core::date::pred_month(state.date));
+                status = event::Status::Captured;
+            }
+            keyboard::key::Named::ArrowRight => {
+                state.date = options
+                    .restrictions
+                    .nearest(crate::core::date::succ_month(state.date));
+                status = event::Status::Captured;
+            }
+            keyboard::key::Named::Enter | keyboard::key::Named::Space => {
+                state.set_view(state.view.zoom_out());
+                status = event::Status::Captured;
+            }
+            _ => {}
+        },
+        Focus::Year => match key {
+            keyboard::key::Named::ArrowLeft => {
+                state.date = options.restrictions.nearest(pred_years(state));
+                status = event::Status::Captured;
+            }
+            keyboard::key::Named::ArrowRight => {
+                state.date = options.restrictions.nearest(succ_years(state));
+                status = event::Status::Captured;
+            }
+            keyboard::key::Named::Enter | keyboard::key::Named::Space => {
+                state.set_view(View::Years);
+                status = event::Status::Captured;
+            }
+            _ => {}
+        },
+        Focus::Months => {
+            let columns = crate::core::date::ZOOM_TABLE_COLUMNS as u32;
+            let month = state.date.month0();
+
+            let month = match key {
+                keyboard::key::Named::ArrowLeft => Some(month + 11),
+                keyboard::key::Named::ArrowRight => Some(month + 1),
+                keyboard::key::Named::ArrowUp => Some(month + 12 - columns),
+                keyboard::key::Named::ArrowDown => Some(month + columns),
+                _ => None,
+            };
+
+            if let Some(month) = month {
+                // The months wrap around within the year.
+                state.date = options
+                    .restrictions
+                    .nearest(crate::core::date::with_month(state.date, month % 12 + 1));
+                status = event::Status::Captured;
+            } else if matches!(
+                key,
+                keyboard::key::Named::Enter | keyboard::key::Named::Space
+            ) {
+                state.set_view(View::Days);
+                status = event::Status::Captured;
+            }
+        }
+        Focus::Years => {
+            let columns = crate::core::date::ZOOM_TABLE_COLUMNS as i32;
+
+            let offset = match key {
+                keyboard::key::Named::ArrowLeft => Some(-1),
+                keyboard::key::Named::ArrowRight => Some(1),
+                keyboard::key::Named::ArrowUp => Some(-columns),
+                keyboard::key::Named::ArrowDown => Some(columns),
+                _ => None,
+            };
+
+            if let Some(offset) = offset {
+                state.date = options.restrictions.nearest(crate::core::date::with_year(
+                    state.date,
+                    state.date.year() + offset,
+                ));
+                status = event::Status::Captured;
+            } else if matches!(
+                key,
+                keyboard::key::Named::Enter | keyboard::key::Named::Space
+            ) {
+                state.set_view(View::Months);
+                status = event::Status::Captured;
+            }
+        }
+        Focus::Day => {
+            let previous = state.date;
+
+            match key {
+                keyboard::key::Named::ArrowLeft => {
+                    state.date = options
+                        .restrictions
+                        .step(state.date, crate::core::date::pred_day);
+                    status = event::Status::Captured;
+                }
+                keyboard::key::Named::ArrowRight => {
+                    state.date = options
+                        .restrictions
+                        .step(state.date, crate::core::date::succ_day);
+                    status = event::Status::Captured;
+                }
+                keyboard::key::Named::ArrowUp => {
+                    state.date = options
+                        .restrictions
+                        .step(state.date, crate::core::date::pred_week);
+                    status = event::Status::Captured;
+                }
+                keyboard::key::Named::ArrowDown => {
+                    state.date = options
+                        .restrictions
+                        .step(state.date, crate::core::date::succ_week);
+                    status = event::Status::Captured;
+                }
+                keyboard::key::Named::Enter | keyboard::key::Named::Space if options.range => {
+                    state.pick_range_day(state.date);
+                    status = event::Status::Captured;
+                }
+                _ => {}
+            }
+
+            // Shift + Arrow extends the range from its start to the new day.
+            if options.range && state.keyboard_modifiers.shift() && state.date != previous {
+                state.extend_range(previous, state.date);
+            }
+        }
+        _ => {}
+    }
+
+    status
+}
+
+/// The mouse interaction of the month/year row and the days of a calendar.
+pub(crate) fn calendar_mouse_interaction(
+    state: &State,
+    options: &CalendarOptions<'_>,
+    layout: Layout<'_>,
+    cursor: Cursor,
+) -> mouse::Interaction {
+    let mut children = layout.children();
+
+    // Month and year mouse interaction
+    let month_year_layout = children
+        .next()
+        .expect("Graphics: Layout should have a month/year layout");
+    let mut month_year_children = month_year_layout.children();
+    let month_layout = month_year_children
+        .next()
+        .expect("Graphics: Layout should have a month layout");
+    let year_layout = month_year_children
+        .next()
+        .expect("Graphics: Layout should have a year layout");
+
+    let f = |layout: Layout<'_>| {
+        let mut children = layout.children();
+
+        let left_bounds = children
+            .next()
+            .expect("Graphics: Layout should have a left arrow layout")
+            .bounds();
+        let center_bounds = children
+            .next()
+            .expect("Graphics: Layout should have a center layout")
+            .bounds();
+        let right_bounds = children
+            .next()
+            .expect("Graphics: Layout should have a right arrow layout")
+            .bounds();
+
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        let left_arrow_hovered = cursor.is_over(left_bounds);
+        let center_hovered = cursor.is_over(center_bounds);
+        let right_arrow_hovered = cursor.is_over(right_bounds);
+
+        if left_arrow_hovered || center_hovered || right_arrow_hovered {
+            mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
+        }
+
+        mouse_interaction
+    };
+
+    let month_mouse_interaction = f(month_layout);
+    let year_mouse_interaction = f(year_layout);
+
+    // Days
+    let days_parent_layout = children
+        .next()
+        .expect("Graphics: Layout should have a days layout parent");
+
+    if state.view != View::Days {
+        let table_mouse_interaction = zoom_table_cells(days_parent_layout.bounds())
+            .find(|(_, _, cell_bounds)| cursor.is_over(*cell_bounds))
+            .map_or(mouse::Interaction::default(), |(x, y, _)| {
+                let (_, first, last) = zoom_cell(state.view, x, y, state.date);
+                if options.restrictions.is_outside(first, last) {
+                    mouse::Interaction::NotAllowed
+                } else {
+                    mouse::Interaction::Pointer
+                }
+            });
+
+        return month_mouse_interaction
+            .max(year_mouse_interaction)
+            .max(table_mouse_interaction);
+    }
+
+    let days_layout = days_parent_layout
+        .children()
+        .next()
+        .expect("Graphics: Layout should have a days layout");
+    let mut days_children = days_layout.children();
+    let _day_labels_layout = days_children.next();
+
+    let mut table_mouse_interaction = mouse::Interaction::default();
+
+    for (y, row) in days_children.enumerate() {
+        for (x, label) in row
+            .children()
+            .skip(usize::from(options.week_numbers))
+            .enumerate()
+        {
+            let bounds = label.bounds();
+
+            let mouse_over = cursor.is_over(bounds);
+            if mouse_over {
+                let date =
+                    crate::core::date::position_to_date(x, y, state.date, options.first_weekday);
+                table_mouse_interaction =
+                    table_mouse_interaction.max(if options.restrictions.is_disabled(date) {
+                        mouse::Interaction::NotAllowed
+                    } else {
+                        mouse::Interaction::Pointer
+                    });
+            }
+        }
+    }
+
+    month_mouse_interaction
+        .max(year_mouse_interaction)
+        .max(table_mouse_interaction)
+}
+
+/// Draws the month/year row and the days of a calendar.
+pub(crate) fn draw_calendar(
+    renderer: &mut Renderer,
+    state: &State,
+    options: &CalendarOptions<'_>,
+    layout: Layout<'_>,
+    cursor: Cursor,
+    style: &HashMap<StyleState, Style>,
+) {
+    let mut children = layout.children();
+
+    // ----------- Year/Month----------------------
+    let month_year_layout = children
+        .next()
+        .expect("Graphics: Layout should have a month/year layout");
+
+    month_year(
+        renderer,
+        month_year_layout,
+        &crate::core::date::date_as_string(state.date, options.locale),
+        cursor.position().unwrap_or_default(),
+        style,
+        state.focus,
+        options.font_size,
+    );
+
+    // ----------- Days ---------------------------
+    let days_parent_layout = children
+        .next()
+        .expect("Graphics: Layout should have a days layout parent");
+
+    if state.view != View::Days {
+        zoom_table(
+            renderer,
+            state,
+            options,
+            days_parent_layout.bounds(),
+            cursor.position().unwrap_or_default(),
+            style,
+        );
+        return;
+    }
+
+    let days_layout = days_parent_layout
+        .children()
+        .next()
+        .expect("Graphics: Layout should have a days layout");
+
+    days(
+        renderer,
+        state,
+        options,
+        days_layout,
+        cursor.position().unwrap_or_default(),
+        style,
+    );
+}
+
+/// Draws the month/year row
 fn month_year(
     renderer: &mut Renderer,
     layout: Layout<'_>,
@@ -1183,7 +1879,7 @@ fn month_year(
                 horizontal_alignment: Horizontal::Center,
                 vertical_alignment: Vertical::Center,
                 line_height: text::LineHeight::Relative(1.3),
-                shaping: text::Shaping::Basic,
+                shaping: text::Shaping::Advanced,
             },
             Point::new(center_bounds.center_x(), center_bounds.center_y()),
             style
@@ -1226,53 +1922,49 @@ fn month_year(
 /// Draws the days
 fn days(
     renderer: &mut Renderer,
+    state: &State,
+    options: &CalendarOptions<'_>,
     layout: Layout<'_>,
-    date: chrono::NaiveDate,
     cursor: Point,
-    //style: &Style,
     style: &HashMap<StyleState, Style>,
-    focus: Focus,
-    font_size: Pixels,
 ) {
     let mut children = layout.children();
 
     let day_labels_layout = children
         .next()
         .expect("Graphics: Layout should have a day labels layout");
-    day_labels(renderer, day_labels_layout, style, focus, font_size);
+    day_labels(renderer, options, day_labels_layout, style);
 
-    day_table(
-        renderer,
-        &mut children,
-        date,
-        cursor,
-        style,
-        focus,
-        font_size,
-    );
+    day_table(renderer, state, options, &mut children, cursor, style);
 }
 
 /// Draws the day labels
 fn day_labels(
     renderer: &mut Renderer,
+    options: &CalendarOptions<'_>,
     layout: Layout<'_>,
     style: &HashMap<StyleState, Style>,
-    _focus: Focus,
-    font_size: Pixels,
 ) {
-    for (i, label) in layout.children().enumerate() {
+    let labels = options.locale.weekday_labels();
+    let first_weekday = options.first_weekday.num_days_from_monday() as usize;
+
+    for (i, label) in layout
+        .children()
+        .skip(usize::from(options.week_numbers))
+        .enumerate()
+    {
         let bounds = label.bounds();
 
         renderer.fill_text(
             iced::advanced::Text {
-                content: crate::core::date::WEEKDAY_LABELS[i].clone(),
+                content: labels[(i + first_weekday) % 7].to_owned(),
                 bounds: Size::new(bounds.width, bounds.height),
-                size: font_size,
+                size: options.font_size,
                 font: renderer.default_font(),
                 horizontal_alignment: Horizontal::Center,
                 vertical_alignment: Vertical::Center,
                 line_height: text::LineHeight::Relative(1.3),
-                shaping: text::Shaping::Basic,
+                shaping: text::Shaping::Advanced,
             },
             Point::new(bounds.center_x(), bounds.center_y()),
             style
@@ -1287,22 +1979,59 @@ fn day_labels(
 /// Draws the day table
 fn day_table(
     renderer: &mut Renderer,
+    state: &State,
+    options: &CalendarOptions<'_>,
     children: &mut dyn Iterator<Item = Layout<'_>>,
-    date: chrono::NaiveDate,
     cursor: Point,
     style: &HashMap<StyleState, Style>,
-    focus: Focus,
-    font_size: Pixels,
 ) {
+    let date = state.date;
+    let range = state.range();
+    let first_weekday = options.first_weekday;
+    let font_size = options.font_size;
+
     for (y, row) in children.enumerate() {
-        for (x, label) in row.children().enumerate() {
+        let mut cells = row.children();
+
+        if options.week_numbers {
+            let bounds = cells
+                .next()
+                .expect("Graphics: Layout should have a week number layout")
+                .bounds();
+
+            renderer.fill_text(
+                iced::advanced::Text {
+                    content: format!(
+                        "{:02}",
+                        crate::core::date::position_to_week(y, date, first_weekday)
+                    ),
+                    bounds: Size::new(bounds.width, bounds.height),
+                    size: font_size,
+                    font: renderer.default_font(),
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    line_height: text::LineHeight::Relative(1.3),
+                    shaping: text::Shaping::Basic,
+                },
+                Point::new(bounds.center_x(), bounds.center_y()),
+                style
+                    .get(&StyleState::Active)
+                    .expect("Style Sheet not found.")
+                    .text_attenuated_color,
+                bounds,
+            );
+        }
+
+        for (x, label) in cells.enumerate() {
             let bounds = label.bounds();
             let (number, is_in_month) =
-                crate::core::date::position_to_day(x, y, date.year(), date.month());
+                crate::core::date::position_to_day(x, y, date.year(), date.month(), first_weekday);
+            let day = crate::core::date::position_to_date(x, y, date, first_weekday);
 
             let mouse_over = bounds.contains(cursor);
 
-            let selected = date.day() == number as u32 && is_in_month == IsInMonth::Same;
+            let selected = range.map_or(day == date, |(start, end)| day == start || day == end);
+            let in_range = range.is_some_and(|(start, end)| start < day && day < end);
 
             let mut style_state = StyleState::Active;
             if selected {
@@ -1311,6 +2040,16 @@ fn day_table(
             if mouse_over {
                 style_state = style_state.max(StyleState::Hovered);
             }
+            if options.restrictions.is_disabled(day) {
+                style_state = style_state.max(StyleState::Disabled);
+            }
+
+            let highlight_range = in_range && style_state == StyleState::Active;
+
+            let marker = options
+                .day_decorator
+                .and_then(|day_decorator| day_decorator(day));
+            let tinted = marker == Some(Marker::Tint) && style_state == StyleState::Active;
 
             if (bounds.width > 0.) && (bounds.height > 0.) {
                 renderer.fill_quad(
@@ -1323,13 +2062,25 @@ fn day_table(
                         },
                         shadow: Shadow::default(),
                     },
-                    style
-                        .get(&style_state)
-                        .expect("Style Sheet not found.")
-                        .day_background,
+                    if highlight_range {
+                        style
+                            .get(&style_state)
+                            .expect("Style Sheet not found.")
+                            .range_background
+                    } else if tinted {
+                        style
+                            .get(&style_state)
+                            .expect("Style Sheet not found.")
+                            .marker_background
+                    } else {
+                        style
+                            .get(&style_state)
+                            .expect("Style Sheet not found.")
+                            .day_background
+                    },
                 );
 
-                if focus == Focus::Day && selected {
+                if state.focus == Focus::Day && day == date {
                     renderer.fill_quad(
                         renderer::Quad {
                             bounds,
@@ -1367,7 +2118,12 @@ fn day_table(
                     shaping: text::Shaping::Basic,
                 },
                 Point::new(bounds.center_x(), bounds.center_y()),
-                if is_in_month == IsInMonth::Same {
+                if highlight_range {
+                    style
+                        .get(&style_state)
+                        .expect("Style Sheet not found.")
+                        .range_text_color
+                } else if is_in_month == IsInMonth::Same {
                     style
                         .get(&style_state)
                         .expect("Style Sheet not found.")
@@ -1380,6 +2136,182 @@ fn day_table(
                 },
                 bounds,
             );
+
+            if let Some(marker) = marker {
+                day_marker(
+                    renderer,
+                    marker,
+                    bounds,
+                    font_size,
+                    style
+                        .get(&style_state)
+                        .expect("Style Sheet not found.")
+                        .marker_color,
+                );
+            }
+        }
+    }
+}
+
+/// Draws the month or year table of the zoomed out calendar.
+fn zoom_table(
+    renderer: &mut Renderer,
+    state: &State,
+    options: &CalendarOptions<'_>,
+    bounds: Rectangle,
+    cursor: Point,
+    style: &HashMap<StyleState, Style>,
+) {
+    let decade_start = crate::core::date::decade_start(state.date.year());
+
+    for (x, y, cell_bounds) in zoom_table_cells(bounds) {
+        let (date, first, last) = zoom_cell(state.view, x, y, state.date);
+
+        let mut style_state = StyleState::Active;
+        if date == state.date {
+            style_state = style_state.max(StyleState::Selected);
+        }
+        if cell_bounds.contains(cursor) {
+            style_state = style_state.max(StyleState::Hovered);
+        }
+        if options.restrictions.is_outside(first, last) {
+            style_state = style_state.max(StyleState::Disabled);
+        }
+
+        if (cell_bounds.width > 0.) && (cell_bounds.height > 0.) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: cell_bounds,
+                    border: Border {
+                        radius: (cell_bounds.height / 2.0).into(),
+                        width: 0.0,
+                        color: Color::TRANSPARENT,
+                    },
+                    shadow: Shadow::default(),
+                },
+                style
+                    .get(&style_state)
+                    .expect("Style Sheet not found.")
+                    .day_background,
+            );
+
+            if state.focus == state.view.focus() && date == state.date {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: cell_bounds,
+                        border: Border {
+                            radius: style
+                                .get(&StyleState::Focused)
+                                .expect("Style Sheet not found.")
+                                .border_radius
+                                .into(),
+                            width: style
+                                .get(&StyleState::Focused)
+                                .expect("Style Sheet not found.")
+                                .border_width,
+                            color: style
+                                .get(&StyleState::Focused)
+                                .expect("Style Sheet not found.")
+                                .border_color,
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+        }
+
+        let (content, is_in_table) = if state.view == View::Months {
+            (
+                options.locale.month_names()[date.month0() as usize].to_owned(),
+                true,
+            )
+        } else {
+            (
+                date.year().to_string(),
+                (decade_start..decade_start + 10).contains(&date.year()),
+            )
+        };
+
+        renderer.fill_text(
+            iced::advanced::Text {
+                content,
+                bounds: Size::new(cell_bounds.width, cell_bounds.height),
+                size: options.font_size,
+                font: renderer.default_font(),
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                line_height: text::LineHeight::Relative(1.3),
+                shaping: text::Shaping::Advanced,
+            },
+            Point::new(cell_bounds.center_x(), cell_bounds.center_y()),
+            if is_in_table {
+                style
+                    .get(&style_state)
+                    .expect("Style Sheet not found.")
+                    .text_color
+            } else {
+                style
+                    .get(&style_state)
+                    .expect("Style Sheet not found.")
+                    .text_attenuated_color
+            },
+            cell_bounds,
+        );
+    }
+}
+
+/// Draws the dot or count of a [`Marker`] in the given day cell.
+fn day_marker(
+    renderer: &mut Renderer,
+    marker: Marker,
+    bounds: Rectangle,
+    font_size: Pixels,
+    color: Color,
+) {
+    match marker {
+        Marker::Dot => {
+            let radius = (bounds.height * 0.08).max(1.5);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.center_x() - radius,
+                        y: bounds.y + bounds.height - DAY_CELL_PADDING / 2.0 - radius,
+                        width: radius * 2.0,
+                        height: radius * 2.0,
+                    },
+                    border: Border {
+                        radius: radius.into(),
+                        width: 0.0,
+                        color: Color::TRANSPARENT,
+                    },
+                    shadow: Shadow::default(),
+                },
+                color,
+            );
+        }
+        Marker::Count(count) => {
+            renderer.fill_text(
+                iced::advanced::Text {
+                    content: if count > 99 {
+                        String::from("99+")
+                    } else {
+                        count.to_string()
+                    },
+                    bounds: Size::new(bounds.width, bounds.height),
+                    size: Pixels(font_size.0 * 0.6),
+                    font: renderer.default_font(),
+                    horizontal_alignment: Horizontal::Right,
+                    vertical_alignment: Vertical::Top,
+                    line_height: text::LineHeight::Relative(1.0),
+                    shaping: text::Shaping::Basic,
+                },
+                Point::new(bounds.x + bounds.width, bounds.y),
+                color,
+                bounds,
+            );
         }
+        Marker::Tint => {}
     }
 }
@@ -0,0 +1,760 @@
+//! Use a date time picker as an input element for picking dates and times.
+//!
+//! *This API requires the following crate features to be activated: `date_time_picker`*
+
+use super::{
+    date_picker::{self, CalendarOptions},
+    time_picker,
+};
+use crate::{
+    core::icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
+    core::overlay::Position,
+    style::{date_time_picker::Catalog, style_state::StyleState, Status},
+    time_picker::Restrictions as TimeRestrictions,
+};
+
+use chrono::{Local, NaiveDateTime};
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay, renderer,
+        widget::tree::Tree,
+        Clipboard, Layout, Overlay, Renderer as _, Shell, Widget,
+    },
+    alignment::Horizontal,
+    event,
+    keyboard,
+    mouse::{self, Cursor},
+    touch,
+    widget::{button, container, text, Button},
+    Border,
+    Element,
+    Event,
+    Length,
+    Padding,
+    Point,
+    Rectangle,
+    Renderer, // the actual type
+    Shadow,
+    Size,
+};
+use std::collections::HashMap;
+
+/// The padding around the elements.
+const PADDING: f32 = 10.0;
+/// The spacing between the elements.
+const SPACING: f32 = 15.0;
+/// The spacing between the buttons.
+const BUTTON_SPACING: f32 = 5.0;
+
+/// The overlay of the [`DateTimePicker`](crate::widgets::DateTimePicker).
+#[allow(missing_debug_implementations)]
+pub struct DateTimePickerOverlay<'a, 'b, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog + button::Catalog,
+    'b: 'a,
+{
+    /// The state of the [`DateTimePickerOverlay`].
+    state: &'a mut State,
+    /// The cancel button of the [`DateTimePickerOverlay`].
+    cancel_button: Button<'a, Message, Theme, Renderer>,
+    /// The submit button of the [`DateTimePickerOverlay`].
+    submit_button: Button<'a, Message, Theme, Renderer>,
+    /// The function that produces a message when the submit button of the [`DateTimePickerOverlay`] is pressed.
+    on_submit: &'a dyn Fn(NaiveDateTime) -> Message,
+    /// The position of the [`DateTimePickerOverlay`].
+    position: Point,
+    /// The style of the [`DateTimePickerOverlay`].
+    class: &'a <Theme as Catalog>::Class<'b>,
+    /// The reference to the tree holding the state of this overlay.
+    tree: &'a mut Tree,
+    /// The options of the calendar of the [`DateTimePickerOverlay`].
+    calendar: CalendarOptions<'a>,
+    /// The restrictions on the times that can be picked.
+    time_restrictions: &'a TimeRestrictions,
+}
+
+impl<'a, 'b, Message, Theme> DateTimePickerOverlay<'a, 'b, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: 'a + Catalog + button::Catalog + text::Catalog + container::Catalog,
+    'b: 'a,
+{
+    /// Creates a new [`DateTimePickerOverlay`] on the given position.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        state: &'a mut crate::date_time_picker::State,
+        on_cancel: Message,
+        on_submit: &'a dyn Fn(NaiveDateTime) -> Message,
+        position: Point,
+        class: &'a <Theme as Catalog>::Class<'b>,
+        tree: &'a mut Tree,
+        calendar: CalendarOptions<'a>,
+        time_restrictions: &'a TimeRestrictions,
+    ) -> Self {
+        let crate::date_time_picker::State { overlay_state } = state;
+
+        DateTimePickerOverlay {
+            state: overlay_state,
+            cancel_button: Button::new(
+                text::Text::new(icon_to_string(Bootstrap::X))
+                    .font(BOOTSTRAP_FONT)
+                    .align_x(Horizontal::Center)
+                    .width(Length::Fill),
+            )
+            .width(Length::Fill)
+            .on_press(on_cancel.clone()),
+            submit_button: Button::new(
+                text::Text::new(icon_to_string(Bootstrap::Check))
+                    .font(BOOTSTRAP_FONT)
+                    .align_x(Horizontal::Center)
+                    .width(Length::Fill),
+            )
+            .width(Length::Fill)
+            .on_press(on_cancel), // Sending a fake message
+            on_submit,
+            position,
+            class,
+            tree,
+            calendar,
+            time_restrictions,
+        }
+    }
+
+    /// Turn this [`DateTimePickerOverlay`] into an overlay [`Element`](overlay::Element).
+    #[must_use]
+    pub fn overlay(self) -> overlay::Element<'a, Message, Theme, Renderer> {
+        overlay::Element::new(Box::new(self))
+    }
+
+    /// The event handling for the keyboard input.
+    ///
+    /// The keys are handled by the calendar or by the clock, depending on
+    /// which of them holds the focus.
+    fn on_event_keyboard(&mut self, event: &Event) -> event::Status {
+        let State {
+            date_state,
+            time_state,
+        } = &mut *self.state;
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                if date_state.focus == date_picker::Focus::None
+                    && time_state.focus == time_picker::Focus::None
+                {
+                    event::Status::Ignored
+                } else if matches!(key, keyboard::Key::Named(keyboard::key::Named::Tab)) {
+                    let backwards = date_state.keyboard_modifiers.shift();
+                    cycle_focus(date_state, time_state, backwards);
+                    event::Status::Captured
+                } else if date_state.focus != date_picker::Focus::None {
+                    if let keyboard::Key::Named(key) = key {
+                        date_picker::on_event_calendar_keyboard(date_state, &self.calendar, *key)
+                    } else {
+                        event::Status::Ignored
+                    }
+                } else {
+                    time_picker::on_event_clock_keyboard(time_state, self.time_restrictions, key)
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                date_state.keyboard_modifiers = *modifiers;
+                time_state.keyboard_modifiers = *modifiers;
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+}
+
+impl<'a, 'b, Message, Theme> Overlay<Message, Theme, Renderer>
+    for DateTimePickerOverlay<'a, 'b, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: 'a + Catalog + button::Catalog + text::Catalog + container::Catalog,
+    'b: 'a,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let limits = Limits::new(Size::ZERO, bounds)
+            .shrink(Padding::from(PADDING))
+            .width(Length::Shrink)
+            .height(Length::Shrink);
+
+        // Pre-Buttons TODO: get rid of it
+        let cancel_limits = limits;
+        let cancel_button =
+            self.cancel_button
+                .layout(&mut self.tree.children[0], renderer, &cancel_limits);
+
+        let limits = limits.shrink(Size::new(0.0, cancel_button.bounds().height + SPACING));
+
+        // Month/Year and Days
+        let element = date_picker::calendar_element::<Message, Theme>(&self.calendar);
+        let calendar_tree = if let Some(child_tree) = self.tree.children.get_mut(2) {
+            child_tree.diff(element.as_widget());
+            child_tree
+        } else {
+            let child_tree = Tree::new(element.as_widget());
+            self.tree.children.insert(2, child_tree);
+            &mut self.tree.children[2]
+        };
+
+        let calendar = element
+            .as_widget()
+            .layout(calendar_tree, renderer, &limits)
+            .move_to(Point::new(PADDING, PADDING));
+        let calendar_bounds = calendar.bounds();
+
+        // Digital clock
+        let element =
+            time_picker::digital_clock_element::<Message, Theme>(&self.state.time_state, renderer);
+        let digital_clock_tree = if let Some(child_tree) = self.tree.children.get_mut(3) {
+            child_tree.diff(element.as_widget());
+            child_tree
+        } else {
+            let child_tree = Tree::new(element.as_widget());
+            self.tree.children.insert(3, child_tree);
+            &mut self.tree.children[3]
+        };
+
+        // The clock is a square filling the height of the calendar together
+        // with the digital clock below it.
+        let digital_clock_height = element
+            .as_widget()
+            .layout(
+                digital_clock_tree,
+                renderer,
+                &limits.max_width(calendar_bounds.height),
+            )
+            .bounds()
+            .height;
+        let clock_size = (calendar_bounds.height - digital_clock_height - SPACING).max(0.0);
+
+        let clock_position = Point::new(PADDING + calendar_bounds.width + SPACING, PADDING);
+
+        let clock = Node::new(Size::new(clock_size, clock_size)).move_to(clock_position);
+
+        let digital_clock = element
+            .as_widget()
+            .layout(digital_clock_tree, renderer, &limits.max_width(clock_size))
+            .move_to(Point::new(
+                clock_position.x,
+                clock_position.y + clock_size + SPACING,
+            ));
+
+        let width = calendar_bounds.width + SPACING + clock_size;
+        let height = calendar_bounds
+            .height
+            .max(clock_size + SPACING + digital_clock.bounds().height);
+
+        // Buttons
+        let cancel_limits = limits.max_width(((width / 2.0) - BUTTON_SPACING).max(0.0));
+
+        let mut cancel_button =
+            self.cancel_button
+                .layout(&mut self.tree.children[0], renderer, &cancel_limits);
+
+        let submit_limits = limits.max_width(((width / 2.0) - BUTTON_SPACING).max(0.0));
+
+        let mut submit_button =
+            self.submit_button
+                .layout(&mut self.tree.children[1], renderer, &submit_limits);
+
+        let cancel_bounds = cancel_button.bounds();
+        cancel_button = cancel_button.move_to(Point {
+            x: cancel_bounds.x + PADDING,
+            y: cancel_bounds.y + height + PADDING + SPACING,
+        });
+
+        let submit_bounds = submit_button.bounds();
+        submit_button = submit_button.move_to(Point {
+            x: submit_bounds.x + width - submit_bounds.width + PADDING,
+            y: submit_bounds.y + height + PADDING + SPACING,
+        });
+
+        let mut node = Node::with_children(
+            Size::new(
+                width + (2.0 * PADDING),
+                height + cancel_button.bounds().height + (2.0 * PADDING) + SPACING,
+            ),
+            vec![calendar, clock, digital_clock, cancel_button, submit_button],
+        );
+        node.center_and_bounce(self.position, bounds);
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        if event::Status::Captured == self.on_event_keyboard(&event) {
+            return event::Status::Captured;
+        }
+
+        let mut children = layout.children();
+
+        let calendar_layout = children
+            .next()
+            .expect("widgets: Layout should have a calendar layout");
+        let clock_layout = children
+            .next()
+            .expect("widgets: Layout should have a clock canvas layout");
+        let digital_clock_layout = children
+            .next()
+            .expect("widgets: Layout should have a digital clock parent");
+
+        // Only one of the calendar and the clock holds the focus.
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if cursor.is_over(calendar_layout.bounds()) {
+                self.state.time_state.focus = time_picker::Focus::None;
+            } else if cursor.is_over(clock_layout.bounds())
+                || cursor.is_over(digital_clock_layout.bounds())
+            {
+                self.state.date_state.focus = date_picker::Focus::None;
+            }
+        }
+
+        // ----------- Year/Month and Days ------------
+        let calendar_status = date_picker::on_event_calendar(
+            &mut self.state.date_state,
+            &self.calendar,
+            &event,
+            calendar_layout,
+            cursor,
+        );
+
+        // ----------- Clock canvas -------------------
+        let clock_status = time_picker::on_event_clock(
+            &mut self.state.time_state,
+            self.time_restrictions,
+            &event,
+            clock_layout,
+            cursor,
+        );
+
+        // ----------- Digital clock ------------------
+        let digital_clock_status = time_picker::on_event_digital_clock(
+            &mut self.state.time_state,
+            self.time_restrictions,
+            &event,
+            digital_clock_layout
+                .children()
+                .next()
+                .expect("widgets: Layout should have a digital clock layout"),
+            cursor,
+        );
+
+        // ----------- Buttons ------------------------
+        let cancel_button_layout = children
+            .next()
+            .expect("widgets: Layout should have a cancel button layout for a DateTimePicker");
+
+        let cancel_status = self.cancel_button.on_event(
+            &mut self.tree.children[0],
+            event.clone(),
+            cancel_button_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+        );
+
+        let submit_button_layout = children
+            .next()
+            .expect("widgets: Layout should have a submit button layout for a DateTimePicker");
+
+        let mut fake_messages: Vec<Message> = Vec::new();
+
+        let submit_status = self.submit_button.on_event(
+            &mut self.tree.children[1],
+            event,
+            submit_button_layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut Shell::new(&mut fake_messages),
+            &layout.bounds(),
+        );
+
+        if !fake_messages.is_empty() {
+            shell.publish((self.on_submit)(
+                self.state
+                    .date_time(self.calendar.restrictions, self.time_restrictions),
+            ));
+        }
+
+        calendar_status
+            .merge(clock_status)
+            .merge(digital_clock_status)
+            .merge(cancel_status)
+            .merge(submit_status)
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mouse_interaction = mouse::Interaction::default();
+
+        let mut children = layout.children();
+
+        // Month, year and days mouse interaction
+        let calendar_mouse_interaction = date_picker::calendar_mouse_interaction(
+            &self.state.date_state,
+            &self.calendar,
+            children
+                .next()
+                .expect("Graphics: Layout should have a calendar layout"),
+            cursor,
+        );
+
+        // Clock and digital clock mouse interaction
+        let clock_layout = children
+            .next()
+            .expect("Graphics: Layout should have a clock canvas layout");
+        let digital_clock_layout = children
+            .next()
+            .expect("Graphics: Layout should have a digital clock layout");
+        let clock_mouse_interaction = time_picker::clock_mouse_interaction(
+            &self.state.time_state,
+            clock_layout,
+            digital_clock_layout,
+            cursor,
+        );
+
+        // Buttons
+        let cancel_button_layout = children
+            .next()
+            .expect("Graphics: Layout should have a cancel button layout for a DateTimePicker");
+
+        let cancel_button_mouse_interaction = self.cancel_button.mouse_interaction(
+            &self.tree.children[0],
+            cancel_button_layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        let submit_button_layout = children
+            .next()
+            .expect("Graphics: Layout should have a submit button layout for a DateTimePicker");
+
+        let submit_button_mouse_interaction = self.submit_button.mouse_interaction(
+            &self.tree.children[1],
+            submit_button_layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        mouse_interaction
+            .max(calendar_mouse_interaction)
+            .max(clock_mouse_interaction)
+            .max(cancel_button_mouse_interaction)
+            .max(submit_button_mouse_interaction)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+
+        let (date_style_sheet, time_style_sheet) = style_sheets(theme, self.class);
+
+        let style_state = if cursor.is_over(bounds) {
+            StyleState::Hovered
+        } else {
+            StyleState::Active
+        };
+
+        // Background
+        if (bounds.width > 0.) && (bounds.height > 0.) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: date_style_sheet[&style_state].border_radius.into(),
+                        width: date_style_sheet[&style_state].border_width,
+                        color: date_style_sheet[&style_state].border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                date_style_sheet[&style_state].background,
+            );
+        }
+
+        // ----------- Year/Month and Days ------------
+        date_picker::draw_calendar(
+            renderer,
+            &self.state.date_state,
+            &self.calendar,
+            children
+                .next()
+                .expect("Graphics: Layout should have a calendar layout"),
+            cursor,
+            &date_style_sheet,
+        );
+
+        // ----------- Clock canvas -------------------
+        time_picker::draw_clock(
+            renderer,
+            &self.state.time_state,
+            self.time_restrictions,
+            children
+                .next()
+                .expect("Graphics: Layout should have a clock canvas layout"),
+            cursor,
+            &time_style_sheet,
+        );
+
+        // ----------- Digital clock ------------------
+        time_picker::draw_digital_clock(
+            renderer,
+            &self.state.time_state,
+            self.time_restrictions,
+            children
+                .next()
+                .expect("Graphics: Layout should have a digital clock layout"),
+            cursor,
+            &time_style_sheet,
+        );
+
+        // ----------- Buttons ------------------------
+        let cancel_button_layout = children
+            .next()
+            .expect("Graphics: Layout should have a cancel button layout for a DateTimePicker");
+
+        self.cancel_button.draw(
+            &self.tree.children[0],
+            renderer,
+            theme,
+            style,
+            cancel_button_layout,
+            cursor,
+            &bounds,
+        );
+
+        let submit_button_layout = children
+            .next()
+            .expect("Graphics: Layout should have a submit button layout for a DateTimePicker");
+
+        self.submit_button.draw(
+            &self.tree.children[1],
+            renderer,
+            theme,
+            style,
+            submit_button_layout,
+            cursor,
+            &bounds,
+        );
+    }
+}
+
+/// Moves the focus to the next element of the calendar and the clock, or
+/// to the previous one if `backwards` is set.
+fn cycle_focus(
+    date_state: &mut date_picker::State,
+    time_state: &mut time_picker::State,
+    backwards: bool,
+) {
+    let mut order = vec![
+        (date_picker::Focus::Month, time_picker::Focus::None),
+        (date_picker::Focus::Year, time_picker::Focus::None),
+        (date_state.view.focus(), time_picker::Focus::None),
+        (date_picker::Focus::None, time_picker::Focus::DigitalHour),
+        (date_picker::Focus::None, time_picker::Focus::DigitalMinute),
+    ];
+    if time_state.show_seconds {
+        order.push((date_picker::Focus::None, time_picker::Focus::DigitalSecond));
+    }
+
+    let next = match order
+        .iter()
+        .position(|focus| *focus == (date_state.focus, time_state.focus))
+    {
+        Some(index) if backwards => (index + order.len() - 1) % order.len(),
+        Some(index) => (index + 1) % order.len(),
+        None => 0,
+    };
+
+    (date_state.focus, time_state.focus) = order[next];
+}
+
+/// Creates the style sheets of the calendar and of the clock for all of
+/// their style states.
+fn style_sheets<Theme>(
+    theme: &Theme,
+    class: &<Theme as Catalog>::Class<'_>,
+) -> (
+    HashMap<StyleState, crate::style::date_picker::Style>,
+    HashMap<StyleState, crate::style::time_picker::Style>,
+)
+where
+    Theme: Catalog,
+{
+    let mut date_style_sheet = HashMap::new();
+    let mut time_style_sheet = HashMap::new();
+
+    for (style_state, status) in [
+        (StyleState::Active, Status::Active),
+        (StyleState::Selected, Status::Selected),
+        (StyleState::Hovered, Status::Hovered),
+        (StyleState::Focused, Status::Focused),
+        (StyleState::Disabled, Status::Disabled),
+    ] {
+        let style = Catalog::style(theme, class, status);
+        let _ = date_style_sheet.insert(style_state, style.date_picker);
+        let _ = time_style_sheet.insert(style_state, style.time_picker);
+    }
+
+    (date_style_sheet, time_style_sheet)
+}
+
+/// The state of the [`DateTimePickerOverlay`].
+#[derive(Debug, Default)]
+pub struct State {
+    /// The state of the calendar of the [`DateTimePickerOverlay`].
+    pub(crate) date_state: date_picker::State,
+    /// The state of the clock of the [`DateTimePickerOverlay`].
+    pub(crate) time_state: time_picker::State,
+}
+
+impl State {
+    /// Creates a new State with the given date and time.
+    #[must_use]
+    pub fn new(date_time: NaiveDateTime, use_24h: bool, show_seconds: bool) -> Self {
+        Self {
+            date_state: date_picker::State::new(date_time.date()),
+            time_state: time_picker::State::new(date_time.time().into(), use_24h, show_seconds),
+        }
+    }
+
+    /// Resets the date and the time of the state to the current date and time.
+    pub(crate) fn reset(&mut self) {
+        let now = Local::now().naive_local();
+        self.date_state.date = now.date();
+        self.date_state.view = date_picker::View::Days;
+        self.time_state.clock_cache.clear();
+        self.time_state.time = now.time();
+        self.time_state.align_to_steps();
+    }
+
+    /// Gets the picked date and time, moved to the nearest date and time that
+    /// can be picked.
+    fn date_time(
+        &self,
+        date_restrictions: &crate::date_picker::Restrictions,
+        time_restrictions: &TimeRestrictions,
+    ) -> NaiveDateTime {
+        date_restrictions
+            .nearest(self.date_state.date)
+            .and_time(self.time_state.picked_time(time_restrictions))
+    }
+}
+
+/// Just a workaround to pass the button states from the tree to the overlay
+#[allow(missing_debug_implementations)]
+pub struct DateTimePickerOverlayButtons<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog + button::Catalog,
+{
+    /// The cancel button of the [`DateTimePickerOverlay`].
+    cancel_button: Element<'a, Message, Theme, Renderer>,
+    /// The submit button of the [`DateTimePickerOverlay`].
+    submit_button: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme> Default for DateTimePickerOverlayButtons<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a + Catalog + button::Catalog + text::Catalog,
+{
+    fn default() -> Self {
+        Self {
+            cancel_button: Button::new(
+                text::Text::new(icon_to_string(Bootstrap::X))
+                    .font(BOOTSTRAP_FONT)
+                    .align_x(Horizontal::Center)
+                    .width(Length::Fill),
+            )
+            .into(),
+            submit_button: Button::new(
+                text::Text::new(icon_to_string(Bootstrap::Check))
+                    .font(BOOTSTRAP_FONT)
+                    .align_x(Horizontal::Center)
+                    .width(Length::Fill),
+            )
+            .into(),
+        }
+    }
+}
+
+#[allow(clippy::unimplemented)]
+impl<'a, Message, Theme> Widget<Message, Theme, Renderer>
+    for DateTimePickerOverlayButtons<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog + button::Catalog,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![
+            Tree::new(&self.cancel_button),
+            Tree::new(&self.submit_button),
+        ]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.cancel_button, &self.submit_button]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        unimplemented!("This should never be reached!")
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, _limits: &Limits) -> Node {
+        unimplemented!("This should never be reached!")
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        _renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        unimplemented!("This should never be reached!")
+    }
+}
+
+impl<'a, Message, Theme> From<DateTimePickerOverlayButtons<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a + Catalog + button::Catalog,
+{
+    fn from(overlay: DateTimePickerOverlayButtons<'a, Message, Theme>) -> Self {
+        Self::new(overlay)
+    }
+}
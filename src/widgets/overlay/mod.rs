@@ -10,6 +10,11 @@ pub mod date_picker;
 #[cfg(feature = "date_picker")]
 pub use date_picker::DatePickerOverlay;
 
+#[cfg(feature = "date_time_picker")]
+pub mod date_time_picker;
+#[cfg(feature = "date_time_picker")]
+pub use date_time_picker::DateTimePickerOverlay;
+
 #[cfg(feature = "time_picker")]
 pub mod time_picker;
 #[cfg(feature = "time_picker")]
@@ -18,10 +18,10 @@ use crate::{
         time_picker::{Catalog, Style},
         Status,
     },
-    time_picker::{self, Time},
+    time_picker::{self, Restrictions, Time},
 };
 
-use chrono::{Duration, Local, NaiveTime, Timelike};
+use chrono::{Duration, FixedOffset, Local, NaiveTime, Timelike};
 use iced::{
     advanced::{
         graphics::geometry::Renderer as _,
@@ -85,6 +85,8 @@ where
     submit_button: Button<'a, Message, Theme, Renderer>,
     /// The function that produces a message when the submit button of the [`TimePickerOverlay`] is pressed.
     on_submit: &'a dyn Fn(Time) -> Message,
+    /// The restrictions on the times that can be picked.
+    restrictions: &'a Restrictions,
     /// The position of the [`TimePickerOverlay`].
     position: Point,
     /// The style of the [`TimePickerOverlay`].
@@ -104,6 +106,7 @@ where
         state: &'a mut time_picker::State,
         on_cancel: Message,
         on_submit: &'a dyn Fn(Time) -> Message,
+        restrictions: &'a Restrictions,
         position: Point,
         class: &'a <Theme as Catalog>::Class<'b>,
         tree: &'a mut Tree,
@@ -129,6 +132,7 @@ where
             .width(Length::Fill)
             .on_press(on_cancel), // Sending a fake message
             on_submit,
+            restrictions,
             position,
             class,
             tree,
@@ -141,309 +145,6 @@ where
         overlay::Element::new(Box::new(self))
     }
 
-    /// The event handling for the clock.
-    #[allow(clippy::too_many_lines)]
-    fn on_event_clock(
-        &mut self,
-        event: &Event,
-        layout: Layout<'_>,
-        cursor: Cursor,
-    ) -> event::Status {
-        if cursor.is_over(layout.bounds()) {
-            self.state.clock_cache_needs_clearance = true;
-            self.state.clock_cache.clear();
-        } else if self.state.clock_cache_needs_clearance {
-            self.state.clock_cache.clear();
-            self.state.clock_cache_needs_clearance = false;
-        }
-
-        let clock_bounds = layout.bounds();
-        if cursor.is_over(clock_bounds) {
-            let center = clock_bounds.center();
-            let radius = clock_bounds.width.min(clock_bounds.height) * 0.5;
-
-            let period_radius = radius * PERIOD_PERCENTAGE;
-
-            let (hour_radius, minute_radius, second_radius) = if self.state.show_seconds {
-                (
-                    radius * HOUR_RADIUS_PERCENTAGE,
-                    radius * MINUTE_RADIUS_PERCENTAGE,
-                    radius * SECOND_RADIUS_PERCENTAGE,
-                )
-            } else {
-                (
-                    radius * HOUR_RADIUS_PERCENTAGE_NO_SECONDS,
-                    radius * MINUTE_RADIUS_PERCENTAGE_NO_SECONDS,
-                    f32::MAX,
-                )
-            };
-
-            let nearest_radius = crate::core::clock::nearest_radius(
-                &if self.state.show_seconds {
-                    vec![
-                        (period_radius, NearestRadius::Period),
-                        (hour_radius, NearestRadius::Hour),
-                        (minute_radius, NearestRadius::Minute),
-                        (second_radius, NearestRadius::Second),
-                    ]
-                } else {
-                    vec![
-                        (period_radius, NearestRadius::Period),
-                        (hour_radius, NearestRadius::Hour),
-                        (minute_radius, NearestRadius::Minute),
-                    ]
-                },
-                cursor.position().unwrap_or_default(),
-                center,
-            );
-
-            let clock_clicked_status = match event {
-                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-                | Event::Touch(touch::Event::FingerPressed { .. }) => match nearest_radius {
-                    NearestRadius::Period => {
-                        let (pm, hour) = self.state.time.hour12();
-                        let hour = if hour == 12 {
-                            if pm {
-                                12
-                            } else {
-                                0
-                            }
-                        } else {
-                            hour
-                        };
-
-                        self.state.time = self
-                            .state
-                            .time
-                            .with_hour(if pm && hour != 12 { hour } else { hour + 12 } % 24)
-                            .expect("New time with hour should be valid");
-                        event::Status::Captured
-                    }
-                    NearestRadius::Hour => {
-                        self.state.focus = Focus::DigitalHour;
-                        self.state.clock_dragged = ClockDragged::Hour;
-                        event::Status::Captured
-                    }
-                    NearestRadius::Minute => {
-                        self.state.focus = Focus::DigitalMinute;
-                        self.state.clock_dragged = ClockDragged::Minute;
-                        event::Status::Captured
-                    }
-                    NearestRadius::Second => {
-                        self.state.focus = Focus::DigitalSecond;
-                        self.state.clock_dragged = ClockDragged::Second;
-                        event::Status::Captured
-                    }
-                    NearestRadius::None => event::Status::Ignored,
-                },
-                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-                | Event::Touch(
-                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
-                ) => {
-                    self.state.clock_dragged = ClockDragged::None;
-                    event::Status::Captured
-                }
-                _ => event::Status::Ignored,
-            };
-
-            let clock_dragged_status = match self.state.clock_dragged {
-                ClockDragged::Hour => {
-                    let hour_points = crate::core::clock::circle_points(hour_radius, center, 12);
-                    let nearest_point = crate::core::clock::nearest_point(
-                        &hour_points,
-                        cursor.position().unwrap_or_default(),
-                    );
-
-                    let (pm, _) = self.state.time.hour12();
-
-                    self.state.time = self
-                        .state
-                        .time
-                        .with_hour((nearest_point as u32 + if pm { 12 } else { 0 }) % 24)
-                        .expect("New time with hour should be valid");
-                    event::Status::Captured
-                }
-                ClockDragged::Minute => {
-                    let minute_points =
-                        crate::core::clock::circle_points(minute_radius, center, 60);
-                    let nearest_point = crate::core::clock::nearest_point(
-                        &minute_points,
-                        cursor.position().unwrap_or_default(),
-                    );
-
-                    self.state.time = self
-                        .state
-                        .time
-                        .with_minute(nearest_point as u32)
-                        .expect("New time with minute should be valid");
-                    event::Status::Captured
-                }
-                ClockDragged::Second => {
-                    let second_points =
-                        crate::core::clock::circle_points(second_radius, center, 60);
-                    let nearest_point = crate::core::clock::nearest_point(
-                        &second_points,
-                        cursor.position().unwrap_or_default(),
-                    );
-
-                    self.state.time = self
-                        .state
-                        .time
-                        .with_second(nearest_point as u32)
-                        .expect("New time with second should be valid");
-                    event::Status::Captured
-                }
-                ClockDragged::None => event::Status::Ignored,
-            };
-
-            clock_clicked_status.merge(clock_dragged_status)
-        } else {
-            match event {
-                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-                | Event::Touch(
-                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
-                ) => {
-                    self.state.clock_dragged = ClockDragged::None;
-                    event::Status::Captured
-                }
-                _ => event::Status::Ignored,
-            }
-        }
-    }
-
-    /// The event handling for the digital clock.
-    #[allow(clippy::too_many_lines)]
-    fn on_event_digital_clock(
-        &mut self,
-        event: &Event,
-        layout: Layout<'_>,
-        cursor: Cursor,
-    ) -> event::Status {
-        let mut digital_clock_children = layout.children();
-
-        if !self.state.use_24h {
-            // Placeholder
-            let _ = digital_clock_children.next();
-        }
-
-        let hour_layout = digital_clock_children
-            .next()
-            .expect("widgets: Layout should have a hour layout");
-        let mut hour_children = hour_layout.children();
-
-        let hour_up_arrow = hour_children
-            .next()
-            .expect("widgets: Layout should have an up arrow for hours");
-        let _ = hour_children.next();
-        let hour_down_arrow = hour_children
-            .next()
-            .expect("widgets: Layout should have a down arrow for hours");
-
-        let _ = digital_clock_children.next();
-
-        let minute_layout = digital_clock_children
-            .next()
-            .expect("widgets: Layout should have a minute layout");
-        let mut minute_children = minute_layout.children();
-
-        let minute_up_arrow = minute_children
-            .next()
-            .expect("widgets: Layout should have an up arrow for minutes");
-        let _ = minute_children.next();
-        let minute_down_arrow = minute_children
-            .next()
-            .expect("widgets: Layout should have a down arrow for minutes");
-
-        let calculate_time = |time: &mut NaiveTime,
-                              up_arrow: Layout<'_>,
-                              down_arrow: Layout<'_>,
-                              duration: Duration| {
-            if cursor.is_over(up_arrow.bounds()) {
-                *time += duration;
-                event::Status::Captured
-            } else if cursor.is_over(down_arrow.bounds()) {
-                *time -= duration;
-                event::Status::Captured
-            } else {
-                event::Status::Ignored
-            }
-        };
-
-        let digital_clock_status = match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if cursor.is_over(hour_layout.bounds()) {
-                    self.state.focus = Focus::DigitalHour;
-
-                    calculate_time(
-                        &mut self.state.time,
-                        hour_up_arrow,
-                        hour_down_arrow,
-                        Duration::hours(1),
-                    )
-                } else if cursor.is_over(minute_layout.bounds()) {
-                    self.state.focus = Focus::DigitalMinute;
-
-                    calculate_time(
-                        &mut self.state.time,
-                        minute_up_arrow,
-                        minute_down_arrow,
-                        Duration::minutes(1),
-                    )
-                } else {
-                    event::Status::Ignored
-                }
-            }
-            _ => event::Status::Ignored,
-        };
-
-        let second_status = if self.state.show_seconds {
-            let _ = digital_clock_children.next();
-
-            let second_layout = digital_clock_children
-                .next()
-                .expect("widgets: Layout should have a second layout");
-            let mut second_children = second_layout.children();
-
-            let second_up_arrow = second_children
-                .next()
-                .expect("widgets: Layout should have an up arrow for seconds");
-            let _ = second_children.next();
-            let second_down_arrow = second_children
-                .next()
-                .expect("widgets: Layout should have a down arrow for seconds");
-
-            match event {
-                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-                | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                    if cursor.is_over(second_layout.bounds()) {
-                        self.state.focus = Focus::DigitalSecond;
-
-                        calculate_time(
-                            &mut self.state.time,
-                            second_up_arrow,
-                            second_down_arrow,
-                            Duration::seconds(1),
-                        )
-                    } else {
-                        event::Status::Ignored
-                    }
-                }
-                _ => event::Status::Ignored,
-            }
-        } else {
-            event::Status::Ignored
-        };
-
-        let digital_clock_status = digital_clock_status.merge(second_status);
-
-        if digital_clock_status == event::Status::Captured {
-            self.state.clock_cache.clear();
-        }
-
-        digital_clock_status
-    }
-
     /// The event handling for the keyboard input.
     fn on_event_keyboard(&mut self, event: &Event) -> event::Status {
         if self.state.focus == Focus::None {
@@ -451,53 +152,16 @@ where
         }
 
         if let Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) = event {
-            let mut status = event::Status::Ignored;
-
             if matches!(key, keyboard::Key::Named(keyboard::key::Named::Tab)) {
                 if self.state.keyboard_modifiers.shift() {
                     self.state.focus = self.state.focus.previous(self.state.show_seconds);
                 } else {
                     self.state.focus = self.state.focus.next(self.state.show_seconds);
                 }
+                event::Status::Ignored
             } else {
-                let mut keyboard_handle =
-                    |key_code: &keyboard::Key, time: &mut NaiveTime, duration: Duration| {
-                        match key_code {
-                            keyboard::Key::Named(
-                                keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowDown,
-                            ) => {
-                                *time -= duration;
-                                status = event::Status::Captured;
-                            }
-                            keyboard::Key::Named(
-                                keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowUp,
-                            ) => {
-                                *time += duration;
-                                status = event::Status::Captured;
-                            }
-                            _ => {}
-                        }
-                    };
-
-                match self.state.focus {
-                    Focus::DigitalHour => {
-                        keyboard_handle(key, &mut self.state.time, Duration::hours(1));
-                    }
-                    Focus::DigitalMinute => {
-                        keyboard_handle(key, &mut self.state.time, Duration::minutes(1));
-                    }
-                    Focus::DigitalSecond => {
-                        keyboard_handle(key, &mut self.state.time, Duration::seconds(1));
-                    }
-                    _ => {}
-                }
-            }
-
-            if status == event::Status::Captured {
-                self.state.clock_cache.clear();
+                on_event_clock_keyboard(self.state, self.restrictions, key)
             }
-
-            status
         } else if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
             self.state.keyboard_modifiers = *modifiers;
             event::Status::Ignored
@@ -523,8 +187,18 @@ where
             .max_height(350.0);
 
         // Digital Clock
-        let digital_clock_limits = limits;
-        let mut digital_clock = digital_clock(self, renderer, digital_clock_limits);
+        let element = digital_clock_element::<Message, Theme>(self.state, renderer);
+        let container_tree = if let Some(child_tree) = self.tree.children.get_mut(2) {
+            child_tree.diff(element.as_widget());
+            child_tree
+        } else {
+            let child_tree = Tree::new(element.as_widget());
+            self.tree.children.insert(2, child_tree);
+            &mut self.tree.children[2]
+        };
+        let mut digital_clock = element
+            .as_widget()
+            .layout(container_tree, renderer, &limits);
 
         // Pre-Buttons TODO: get rid of it
         let cancel_limits = limits;
@@ -625,7 +299,8 @@ where
         let clock_layout = children
             .next()
             .expect("widgets: Layout should have a clock canvas layout");
-        let clock_status = self.on_event_clock(&event, clock_layout, cursor);
+        let clock_status =
+            on_event_clock(self.state, self.restrictions, &event, clock_layout, cursor);
 
         // ----------- Digital clock ------------------
         let digital_clock_layout = children
@@ -634,8 +309,13 @@ where
             .children()
             .next()
             .expect("widgets: Layout should have a digital clock layout");
-        let digital_clock_status =
-            self.on_event_digital_clock(&event, digital_clock_layout, cursor);
+        let digital_clock_status = on_event_digital_clock(
+            self.state,
+            self.restrictions,
+            &event,
+            digital_clock_layout,
+            cursor,
+        );
 
         // ----------- Buttons ------------------------
         let cancel_button_layout = children
@@ -671,6 +351,8 @@ where
         );
 
         if !fake_messages.is_empty() {
+            self.state.time = self.state.picked_time(self.restrictions);
+
             let (hour, period) = if self.state.use_24h {
                 (self.state.time.hour(), Period::H24)
             } else {
@@ -712,79 +394,14 @@ where
         let mut children = layout.children();
         let mouse_interaction = mouse::Interaction::default();
 
-        // Clock canvas
         let clock_layout = children
             .next()
             .expect("Graphics: Layout should have a clock canvas layout");
-        let clock_mouse_interaction = if cursor.is_over(clock_layout.bounds()) {
-            mouse::Interaction::Pointer
-        } else {
-            mouse::Interaction::default()
-        };
-
-        // Digital clock
         let digital_clock_layout = children
             .next()
             .expect("Graphics: Layout should have a digital clock layout");
-        //let digital_clock_mouse_interaction = mouse::Interaction::default();
-        let mut digital_clock_children = digital_clock_layout
-            .children()
-            .next()
-            .expect("Graphics: Layout should have digital clock children")
-            .children();
-
-        let f = |layout: Layout<'_>| {
-            let mut children = layout.children();
-
-            let up_bounds = children
-                .next()
-                .expect("Graphics: Layout should have a up arrow bounds")
-                .bounds();
-            let _center_bounds = children.next();
-            let down_bounds = children
-                .next()
-                .expect("Graphics: Layout should have a down arrow bounds")
-                .bounds();
-
-            let mut mouse_interaction = mouse::Interaction::default();
-
-            let up_arrow_hovered = cursor.is_over(up_bounds);
-            let down_arrow_hovered = cursor.is_over(down_bounds);
-
-            if up_arrow_hovered || down_arrow_hovered {
-                mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
-            }
-
-            mouse_interaction
-        };
-
-        if !self.state.use_24h {
-            // Placeholder
-            let _ = digital_clock_children.next();
-        }
-
-        let hour_layout = digital_clock_children
-            .next()
-            .expect("Graphics: Layout should have a hour layout");
-        let hour_mouse_interaction = f(hour_layout);
-
-        let _hour_minute_separator = digital_clock_children.next();
-
-        let minute_layout = digital_clock_children
-            .next()
-            .expect("Graphics: Layout should have a minute layout");
-        let minute_mouse_interaction = f(minute_layout);
-
-        let second_mouse_interaction = if self.state.show_seconds {
-            let _minute_second_separator = digital_clock_children.next();
-
-            let second_layout = digital_clock_children
-                .next()
-                .expect("Graphics: Layout should have a second layout");
-            f(second_layout)
-        } else {
-            mouse::Interaction::default()
-        };
+        let clock_mouse_interaction =
+            clock_mouse_interaction(self.state, clock_layout, digital_clock_layout, cursor);
 
         // Buttons
         let cancel_button_layout = children
@@ -813,9 +430,6 @@ where
 
         mouse_interaction
             .max(clock_mouse_interaction)
-            .max(hour_mouse_interaction)
-            .max(minute_mouse_interaction)
-            .max(second_mouse_interaction)
             .max(cancel_mouse_interaction)
             .max(submit_mouse_interaction)
     }
@@ -831,23 +445,7 @@ where
         let bounds = layout.bounds();
         let mut children = layout.children();
 
-        let mut style_sheet: HashMap<StyleState, Style> = HashMap::new();
-        let _ = style_sheet.insert(
-            StyleState::Active,
-            Catalog::style(theme, self.class, Status::Active),
-        );
-        let _ = style_sheet.insert(
-            StyleState::Selected,
-            Catalog::style(theme, self.class, Status::Selected),
-        );
-        let _ = style_sheet.insert(
-            StyleState::Hovered,
-            Catalog::style(theme, self.class, Status::Hovered),
-        );
-        let _ = style_sheet.insert(
-            StyleState::Focused,
-            Catalog::style(theme, self.class, Status::Focused),
-        );
+        let style_sheet = style_sheet(theme, self.class);
 
         let mut style_state = StyleState::Active;
         if self.state.focus == Focus::Overlay {
@@ -877,13 +475,27 @@ where
         let clock_layout = children
             .next()
             .expect("Graphics: Layout should have a clock canvas layout");
-        draw_clock(renderer, self, clock_layout, cursor, &style_sheet);
+        draw_clock(
+            renderer,
+            self.state,
+            self.restrictions,
+            clock_layout,
+            cursor,
+            &style_sheet,
+        );
 
         // ----------- Digital clock ------------------
         let digital_clock_layout = children
             .next()
             .expect("Graphics: Layout should have a digital clock layout");
-        draw_digital_clock(renderer, self, digital_clock_layout, cursor, &style_sheet);
+        draw_digital_clock(
+            renderer,
+            self.state,
+            self.restrictions,
+            digital_clock_layout,
+            cursor,
+            &style_sheet,
+        );
 
         // ----------- Buttons ------------------------
         let cancel_button_layout = children
@@ -955,15 +567,511 @@ where
     }
 }
 
-/// Defines the layout of the digital clock of the time picker.
-fn digital_clock<Message, Theme>(
-    time_picker: &mut TimePickerOverlay<'_, '_, Message, Theme>,
+/// The event handling for the clock.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn on_event_clock(
+    state: &mut State,
+    restrictions: &Restrictions,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: Cursor,
+) -> event::Status {
+    if cursor.is_over(layout.bounds()) {
+        state.clock_cache_needs_clearance = true;
+        state.clock_cache.clear();
+    } else if state.clock_cache_needs_clearance {
+        state.clock_cache.clear();
+        state.clock_cache_needs_clearance = false;
+    }
+
+    let clock_bounds = layout.bounds();
+    if cursor.is_over(clock_bounds) {
+        let center = clock_bounds.center();
+        let radius = clock_bounds.width.min(clock_bounds.height) * 0.5;
+
+        let period_radius = radius * PERIOD_PERCENTAGE;
+
+        let (hour_radius, minute_radius, second_radius) = if state.show_seconds {
+            (
+                radius * HOUR_RADIUS_PERCENTAGE,
+                radius * MINUTE_RADIUS_PERCENTAGE,
+                radius * SECOND_RADIUS_PERCENTAGE,
+            )
+        } else {
+            (
+                radius * HOUR_RADIUS_PERCENTAGE_NO_SECONDS,
+                radius * MINUTE_RADIUS_PERCENTAGE_NO_SECONDS,
+                f32::MAX,
+            )
+        };
+
+        let nearest_radius = crate::core::clock::nearest_radius(
+            &if state.show_seconds {
+                vec![
+                    (period_radius, NearestRadius::Period),
+                    (hour_radius, NearestRadius::Hour),
+                    (minute_radius, NearestRadius::Minute),
+                    (second_radius, NearestRadius::Second),
+                ]
+            } else {
+                vec![
+                    (period_radius, NearestRadius::Period),
+                    (hour_radius, NearestRadius::Hour),
+                    (minute_radius, NearestRadius::Minute),
+                ]
+            },
+            cursor.position().unwrap_or_default(),
+            center,
+        );
+
+        let clock_clicked_status = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => match nearest_radius {
+                NearestRadius::Period => {
+                    let (pm, hour) = state.time.hour12();
+                    let hour = if hour == 12 {
+                        if pm {
+                            12
+                        } else {
+                            0
+                        }
+                    } else {
+                        hour
+                    };
+
+                    let time = state
+                        .time
+                        .with_hour(if pm && hour != 12 { hour } else { hour + 12 } % 24)
+                        .expect("New time with hour should be valid");
+                    if restrictions.is_hour_allowed(time.hour()) {
+                        state.time = restrictions.nearest(time);
+                    }
+                    event::Status::Captured
+                }
+                NearestRadius::Hour => {
+                    state.focus = Focus::DigitalHour;
+                    state.clock_dragged = ClockDragged::Hour;
+                    event::Status::Captured
+                }
+                NearestRadius::Minute => {
+                    state.focus = Focus::DigitalMinute;
+                    state.clock_dragged = ClockDragged::Minute;
+                    event::Status::Captured
+                }
+                NearestRadius::Second => {
+                    state.focus = Focus::DigitalSecond;
+                    state.clock_dragged = ClockDragged::Second;
+                    event::Status::Captured
+                }
+                NearestRadius::None => event::Status::Ignored,
+            },
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                state.clock_dragged = ClockDragged::None;
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        };
+
+        let clock_dragged_status = match state.clock_dragged {
+            ClockDragged::Hour => {
+                let hour_points = crate::core::clock::circle_points(hour_radius, center, 12);
+                let nearest_point = crate::core::clock::nearest_point(
+                    &hour_points,
+                    cursor.position().unwrap_or_default(),
+                );
+
+                let (pm, _) = state.time.hour12();
+
+                let time = state
+                    .time
+                    .with_hour((nearest_point as u32 + if pm { 12 } else { 0 }) % 24)
+                    .expect("New time with hour should be valid");
+                if restrictions.is_hour_allowed(time.hour()) {
+                    state.time = restrictions.nearest(time);
+                }
+                event::Status::Captured
+            }
+            ClockDragged::Minute => {
+                let minute_points = crate::core::clock::circle_points(minute_radius, center, 60);
+                let nearest_point = crate::core::clock::nearest_point(
+                    &minute_points,
+                    cursor.position().unwrap_or_default(),
+                );
+
+                let time = state
+                    .time
+                    .with_minute(crate::core::clock::nearest_step(
+                        nearest_point as u32,
+                        state.minute_step,
+                    ))
+                    .expect("New time with minute should be valid");
+                if restrictions.is_minute_allowed(time.hour(), time.minute()) {
+                    state.time = restrictions.nearest(time);
+                }
+                event::Status::Captured
+            }
+            ClockDragged::Second => {
+                let second_points = crate::core::clock::circle_points(second_radius, center, 60);
+                let nearest_point = crate::core::clock::nearest_point(
+                    &second_points,
+                    cursor.position().unwrap_or_default(),
+                );
+
+                let time = state
+                    .time
+                    .with_second(crate::core::clock::nearest_step(
+                        nearest_point as u32,
+                        state.second_step,
+                    ))
+                    .expect("New time with second should be valid");
+                if restrictions.is_allowed(time) {
+                    state.time = time;
+                }
+                event::Status::Captured
+            }
+            ClockDragged::None => event::Status::Ignored,
+        };
+
+        clock_clicked_status.merge(clock_dragged_status)
+    } else {
+        match event {
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                state.clock_dragged = ClockDragged::None;
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+}
+
+/// The event handling for the digital clock.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn on_event_digital_clock(
+    state: &mut State,
+    restrictions: &Restrictions,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: Cursor,
+) -> event::Status {
+    let mut digital_clock_children = layout.children();
+
+    if !state.use_24h {
+        // Placeholder
+        let _ = digital_clock_children.next();
+    }
+
+    let hour_layout = digital_clock_children
+        .next()
+        .expect("widgets: Layout should have a hour layout");
+    let mut hour_children = hour_layout.children();
+
+    let hour_up_arrow = hour_children
+        .next()
+        .expect("widgets: Layout should have an up arrow for hours");
+    let _ = hour_children.next();
+    let hour_down_arrow = hour_children
+        .next()
+        .expect("widgets: Layout should have a down arrow for hours");
+
+    let _ = digital_clock_children.next();
+
+    let minute_layout = digital_clock_children
+        .next()
+        .expect("widgets: Layout should have a minute layout");
+    let mut minute_children = minute_layout.children();
+
+    let minute_up_arrow = minute_children
+        .next()
+        .expect("widgets: Layout should have an up arrow for minutes");
+    let _ = minute_children.next();
+    let minute_down_arrow = minute_children
+        .next()
+        .expect("widgets: Layout should have a down arrow for minutes");
+
+    let calculate_time = |time: &mut NaiveTime,
+                          up_arrow: Layout<'_>,
+                          down_arrow: Layout<'_>,
+                          (up, down): (Duration, Duration),
+                          accept: &dyn Fn(NaiveTime) -> bool| {
+        if cursor.is_over(up_arrow.bounds()) {
+            if let Some(stepped) = restrictions.step(*time, up, accept) {
+                *time = stepped;
+            }
+            event::Status::Captured
+        } else if cursor.is_over(down_arrow.bounds()) {
+            if let Some(stepped) = restrictions.step(*time, -down, accept) {
+                *time = stepped;
+            }
+            event::Status::Captured
+        } else {
+            event::Status::Ignored
+        }
+    };
+
+    let digital_clock_status = match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if cursor.is_over(hour_layout.bounds()) {
+                state.focus = Focus::DigitalHour;
+
+                calculate_time(
+                    &mut state.time,
+                    hour_up_arrow,
+                    hour_down_arrow,
+                    (Duration::hours(1), Duration::hours(1)),
+                    &|time: NaiveTime| restrictions.is_hour_allowed(time.hour()),
+                )
+            } else if cursor.is_over(minute_layout.bounds()) {
+                state.focus = Focus::DigitalMinute;
+
+                let durations = state.minute_durations();
+                calculate_time(
+                    &mut state.time,
+                    minute_up_arrow,
+                    minute_down_arrow,
+                    durations,
+                    &|time: NaiveTime| restrictions.is_minute_allowed(time.hour(), time.minute()),
+                )
+            } else {
+                event::Status::Ignored
+            }
+        }
+        _ => event::Status::Ignored,
+    };
+
+    let second_status = if state.show_seconds {
+        let _ = digital_clock_children.next();
+
+        let second_layout = digital_clock_children
+            .next()
+            .expect("widgets: Layout should have a second layout");
+        let mut second_children = second_layout.children();
+
+        let second_up_arrow = second_children
+            .next()
+            .expect("widgets: Layout should have an up arrow for seconds");
+        let _ = second_children.next();
+        let second_down_arrow = second_children
+            .next()
+            .expect("widgets: Layout should have a down arrow for seconds");
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if cursor.is_over(second_layout.bounds()) {
+                    state.focus = Focus::DigitalSecond;
+
+                    let durations = state.second_durations();
+                    calculate_time(
+                        &mut state.time,
+                        second_up_arrow,
+                        second_down_arrow,
+                        durations,
+                        &|time: NaiveTime| restrictions.is_allowed(time),
+                    )
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    } else {
+        event::Status::Ignored
+    };
+
+    let digital_clock_status = digital_clock_status.merge(second_status);
+
+    if digital_clock_status == event::Status::Captured {
+        state.clock_cache.clear();
+    }
+
+    digital_clock_status
+}
+
+/// The event handling for the arrow keys moving the focused element of the
+/// clock.
+pub(crate) fn on_event_clock_keyboard(
+    state: &mut State,
+    restrictions: &Restrictions,
+    key: &keyboard::Key,
+) -> event::Status {
+    let mut status = event::Status::Ignored;
+
+    let mut keyboard_handle = |key_code: &keyboard::Key,
+                               time: &mut NaiveTime,
+                               (up, down): (Duration, Duration),
+                               accept: &dyn Fn(NaiveTime) -> bool| {
+        let duration = match key_code {
+            keyboard::Key::Named(
+                keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowDown,
+            ) => -down,
+            keyboard::Key::Named(
+                keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowUp,
+            ) => up,
+            _ => return,
+        };
+
+        if let Some(stepped) = restrictions.step(*time, duration, accept) {
+            *time = stepped;
+        }
+        status = event::Status::Captured;
+    };
+
+    match state.focus {
+        Focus::DigitalHour => {
+            keyboard_handle(
+                key,
+                &mut state.time,
+                (Duration::hours(1), Duration::hours(1)),
+                &|time: NaiveTime| restrictions.is_hour_allowed(time.hour()),
+            );
+        }
+        Focus::DigitalMinute => {
+            let durations = state.minute_durations();
+            keyboard_handle(key, &mut state.time, durations, &|time: NaiveTime| {
+                restrictions.is_minute_allowed(time.hour(), time.minute())
+            });
+        }
+        Focus::DigitalSecond => {
+            let durations = state.second_durations();
+            keyboard_handle(key, &mut state.time, durations, &|time: NaiveTime| {
+                restrictions.is_allowed(time)
+            });
+        }
+        _ => {}
+    }
+
+    if status == event::Status::Captured {
+        state.clock_cache.clear();
+    }
+
+    status
+}
+
+/// Creates the style sheet of a clock for all of its style states.
+pub(crate) fn style_sheet<Theme>(
+    theme: &Theme,
+    class: &<Theme as Catalog>::Class<'_>,
+) -> HashMap<StyleState, Style>
+where
+    Theme: Catalog,
+{
+    let mut style_sheet: HashMap<StyleState, Style> = HashMap::new();
+    let _ = style_sheet.insert(
+        StyleState::Active,
+        Catalog::style(theme, class, Status::Active),
+    );
+    let _ = style_sheet.insert(
+        StyleState::Selected,
+        Catalog::style(theme, class, Status::Selected),
+    );
+    let _ = style_sheet.insert(
+        StyleState::Hovered,
+        Catalog::style(theme, class, Status::Hovered),
+    );
+    let _ = style_sheet.insert(
+        StyleState::Focused,
+        Catalog::style(theme, class, Status::Focused),
+    );
+    let _ = style_sheet.insert(
+        StyleState::Disabled,
+        Catalog::style(theme, class, Status::Disabled),
+    );
+
+    style_sheet
+}
+
+/// The mouse interaction of the clock and the digital clock.
+pub(crate) fn clock_mouse_interaction(
+    state: &State,
+    clock_layout: Layout<'_>,
+    digital_clock_layout: Layout<'_>,
+    cursor: Cursor,
+) -> mouse::Interaction {
+    // Clock canvas
+    let clock_mouse_interaction = if cursor.is_over(clock_layout.bounds()) {
+        mouse::Interaction::Pointer
+    } else {
+        mouse::Interaction::default()
+    };
+
+    // Digital clock
+    //let digital_clock_mouse_interaction = mouse::Interaction::default();
+    let mut digital_clock_children = digital_clock_layout
+        .children()
+        .next()
+        .expect("Graphics: Layout should have digital clock children")
+        .children();
+
+    let f = |layout: Layout<'_>| {
+        let mut children = layout.children();
+
+        let up_bounds = children
+            .next()
+            .expect("Graphics: Layout should have a up arrow bounds")
+            .bounds();
+        let _center_bounds = children.next();
+        let down_bounds = children
+            .next()
+            .expect("Graphics: Layout should have a down arrow bounds")
+            .bounds();
+
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        let up_arrow_hovered = cursor.is_over(up_bounds);
+        let down_arrow_hovered = cursor.is_over(down_bounds);
+
+        if up_arrow_hovered || down_arrow_hovered {
+            mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
+        }
+
+        mouse_interaction
+    };
+
+    if !state.use_24h {
+        // Placeholder
+        let _ = digital_clock_children.next();
+    }
+
+    let hour_layout = digital_clock_children
+        .next()
+        .expect("Graphics: Layout should have a hour layout");
+    let hour_mouse_interaction = f(hour_layout);
+
+    let _hour_minute_separator = digital_clock_children.next();
+
+    let minute_layout = digital_clock_children
+        .next()
+        .expect("Graphics: Layout should have a minute layout");
+    let minute_mouse_interaction = f(minute_layout);
+
+    let second_mouse_interaction = if state.show_seconds {
+        let _minute_second_separator = digital_clock_children.next();
+
+        let second_layout = digital_clock_children
+            .next()
+            .expect("Graphics: Layout should have a second layout");
+        f(second_layout)
+    } else {
+        mouse::Interaction::default()
+    };
+
+    clock_mouse_interaction
+        .max(hour_mouse_interaction)
+        .max(minute_mouse_interaction)
+        .max(second_mouse_interaction)
+}
+
+/// Creates the element used for the layout of the digital clock.
+pub(crate) fn digital_clock_element<'a, Message, Theme>(
+    state: &State,
     renderer: &Renderer,
-    limits: Limits,
-) -> Node
+) -> Element<'a, Message, Theme, Renderer>
 where
-    Message: 'static + Clone,
-    Theme: Catalog + button::Catalog + text::Catalog + container::Catalog,
+    Message: 'a + Clone,
+    Theme: 'a + text::Catalog + container::Catalog,
 {
     let arrow_size = renderer.default_size().0;
     let font_size = 1.2 * renderer.default_size().0;
@@ -974,7 +1082,7 @@ where
         .width(Length::Shrink)
         .spacing(1);
 
-    if !time_picker.state.use_24h {
+    if !state.use_24h {
         digital_clock_row = digital_clock_row.push(
             Column::new() // Just a placeholder
                 .height(Length::Shrink)
@@ -994,10 +1102,7 @@ where
                         .width(Length::Fixed(arrow_size))
                         .height(Length::Fixed(arrow_size)),
                 )
-                .push(
-                    text::Text::new(format!("{:02}", time_picker.state.time.hour()))
-                        .size(font_size),
-                )
+                .push(text::Text::new(format!("{:02}", state.time.hour())).size(font_size))
                 .push(
                     // Down Hour arrow
                     Row::new()
@@ -1020,10 +1125,7 @@ where
                         .width(Length::Fixed(arrow_size))
                         .height(Length::Fixed(arrow_size)),
                 )
-                .push(
-                    text::Text::new(format!("{:02}", time_picker.state.time.hour()))
-                        .size(font_size),
-                )
+                .push(text::Text::new(format!("{:02}", state.time.hour())).size(font_size))
                 .push(
                     // Down Minute arrow
                     Row::new()
@@ -1032,7 +1134,7 @@ where
                 ),
         );
 
-    if time_picker.state.show_seconds {
+    if state.show_seconds {
         digital_clock_row = digital_clock_row
             .push(
                 Column::new()
@@ -1049,10 +1151,7 @@ where
                             .width(Length::Fixed(arrow_size))
                             .height(Length::Fixed(arrow_size)),
                     )
-                    .push(
-                        text::Text::new(format!("{:02}", time_picker.state.time.hour()))
-                            .size(font_size),
-                    )
+                    .push(text::Text::new(format!("{:02}", state.time.hour())).size(font_size))
                     .push(
                         // Down Minute arrow
                         Row::new()
@@ -1062,7 +1161,7 @@ where
             );
     }
 
-    if !time_picker.state.use_24h {
+    if !state.use_24h {
         digital_clock_row = digital_clock_row.push(
             Column::new()
                 .height(Length::Shrink)
@@ -1070,51 +1169,43 @@ where
         );
     }
 
+    if let Some(offset) = state.offset {
+        digital_clock_row =
+            digital_clock_row.push(Column::new().height(Length::Shrink).push(
+                text::Text::new(crate::core::time::offset_as_string(offset)).size(font_size),
+            ));
+    }
+
     let container = Container::new(digital_clock_row)
         .width(Length::Fill)
         .height(Length::Shrink)
         .center_x(Length::Fill)
         .center_y(Length::Shrink);
 
-    let element: Element<Message, Theme, Renderer> = Element::new(container);
-    let container_tree = if let Some(child_tree) = time_picker.tree.children.get_mut(2) {
-        child_tree.diff(element.as_widget());
-        child_tree
-    } else {
-        let child_tree = Tree::new(element.as_widget());
-        time_picker.tree.children.insert(2, child_tree);
-        &mut time_picker.tree.children[2]
-    };
-
-    element
-        .as_widget()
-        .layout(container_tree, renderer, &limits)
+    Element::new(container)
 }
 
 /// Draws the analog clock.
 #[allow(clippy::too_many_lines)]
-fn draw_clock<Message, Theme>(
+pub(crate) fn draw_clock(
     renderer: &mut Renderer,
-    time_picker: &TimePickerOverlay<'_, '_, Message, Theme>,
+    state: &State,
+    restrictions: &Restrictions,
     layout: Layout<'_>,
     cursor: Cursor,
     style: &HashMap<StyleState, Style>,
-) where
-    Message: 'static + Clone,
-    Theme: Catalog + button::Catalog + text::Catalog,
-{
+) {
     let mut clock_style_state = StyleState::Active;
     if cursor.is_over(layout.bounds()) {
         clock_style_state = clock_style_state.max(StyleState::Hovered);
     }
 
-    let geometry = time_picker
-        .state
+    let geometry = state
         .clock_cache
         .draw(renderer, layout.bounds().size(), |frame| {
             let center = frame.center();
             let radius = frame.width().min(frame.height()) * 0.5;
-            let period = if time_picker.state.time.hour12().0 {
+            let period = if state.time.hour12().0 {
                 clock::Period::PM
             } else {
                 clock::Period::AM
@@ -1125,7 +1216,7 @@ fn draw_clock<Message, Theme>(
 
             let period_radius = radius * PERIOD_PERCENTAGE;
 
-            let (hour_radius, minute_radius, second_radius) = if time_picker.state.show_seconds {
+            let (hour_radius, minute_radius, second_radius) = if state.show_seconds {
                 (
                     radius * HOUR_RADIUS_PERCENTAGE,
                     radius * MINUTE_RADIUS_PERCENTAGE,
@@ -1144,7 +1235,7 @@ fn draw_clock<Message, Theme>(
 
             let nearest_radius = if cursor.is_over(layout.bounds()) {
                 crate::core::clock::nearest_radius(
-                    &if time_picker.state.show_seconds {
+                    &if state.show_seconds {
                         vec![
                             (period_radius, NearestRadius::Period),
                             (hour_radius, NearestRadius::Hour),
@@ -1207,8 +1298,10 @@ fn draw_clock<Message, Theme>(
                     );
                 }
                 NearestRadius::Minute => {
-                    let nearest_point = minute_points
-                        [crate::core::clock::nearest_point(&minute_points, internal_cursor)];
+                    let nearest_point = minute_points[crate::core::clock::nearest_step(
+                        crate::core::clock::nearest_point(&minute_points, internal_cursor) as u32,
+                        state.minute_step,
+                    ) as usize];
 
                     frame.fill(
                         &Path::circle(nearest_point, 5.0),
@@ -1219,8 +1312,10 @@ fn draw_clock<Message, Theme>(
                     );
                 }
                 NearestRadius::Second => {
-                    let nearest_point = second_points
-                        [crate::core::clock::nearest_point(&second_points, internal_cursor)];
+                    let nearest_point = second_points[crate::core::clock::nearest_step(
+                        crate::core::clock::nearest_point(&second_points, internal_cursor) as u32,
+                        state.second_step,
+                    ) as usize];
 
                     frame.fill(
                         &Path::circle(nearest_point, 5.0),
@@ -1251,8 +1346,8 @@ fn draw_clock<Message, Theme>(
 
             hour_points.iter().enumerate().for_each(|(i, p)| {
                 let (pm, selected) = {
-                    let (pm, _) = time_picker.state.time.hour12();
-                    let hour = time_picker.state.time.hour();
+                    let (pm, _) = state.time.hour12();
+                    let hour = state.time.hour();
                     (pm, hour % 12 == i as u32)
                 };
 
@@ -1268,13 +1363,16 @@ fn draw_clock<Message, Theme>(
                     );
                     style_state = style_state.max(StyleState::Selected);
                 }
+                if !restrictions.is_hour_allowed(i as u32 + if pm { 12 } else { 0 }) {
+                    style_state = style_state.max(StyleState::Disabled);
+                }
 
                 let text = CanvasText {
                     content: format!(
                         "{}",
-                        if pm && time_picker.state.use_24h {
+                        if pm && state.use_24h {
                             i + 12
-                        } else if !time_picker.state.use_24h && i == 0 {
+                        } else if !state.use_24h && i == 0 {
                             12
                         } else {
                             i
@@ -1296,54 +1394,13 @@ fn draw_clock<Message, Theme>(
                 frame.fill_text(text);
             });
 
-            minute_points.iter().enumerate().for_each(|(i, p)| {
-                let selected = time_picker.state.time.minute() == i as u32;
-
-                let mut style_state = StyleState::Active;
-                if selected {
-                    frame.stroke(&Path::line(center, *p), hand_stroke);
-                    frame.fill(
-                        &Path::circle(*p, number_size * 0.6),
-                        style
-                            .get(&StyleState::Selected)
-                            .expect("Style Sheet not found.")
-                            .clock_number_background,
-                    );
-                    style_state = style_state.max(StyleState::Selected);
-                }
-
-                if i % 5 == 0 {
-                    let text = CanvasText {
-                        content: format!("{i:02}"),
-                        position: *p,
-                        color: style
-                            .get(&style_state)
-                            .expect("Style Sheet not found.")
-                            .clock_number_color,
-                        size: Pixels(number_size),
-                        font: renderer.default_font(),
-                        horizontal_alignment: Horizontal::Center,
-                        vertical_alignment: Vertical::Center,
-                        shaping: text::Shaping::Basic,
-                        line_height: text::LineHeight::Relative(1.3),
-                    };
-
-                    frame.fill_text(text);
-                } else {
-                    let circle = Path::circle(*p, number_size * 0.1);
-                    frame.fill(
-                        &circle,
-                        style
-                            .get(&StyleState::Active)
-                            .expect("Style Sheet not found.")
-                            .clock_dots_color,
-                    );
-                }
-            });
-
-            if time_picker.state.show_seconds {
-                second_points.iter().enumerate().for_each(|(i, p)| {
-                    let selected = time_picker.state.time.second() == i as u32;
+            let minute_labels = crate::core::clock::label_step(state.minute_step, 5);
+            minute_points
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| i % usize::from(state.minute_step.max(1)) == 0)
+                .for_each(|(i, p)| {
+                    let selected = state.time.minute() == i as u32;
 
                     let mut style_state = StyleState::Active;
                     if selected {
@@ -1358,7 +1415,14 @@ fn draw_clock<Message, Theme>(
                         style_state = style_state.max(StyleState::Selected);
                     }
 
-                    if i % 10 == 0 {
+                    let dot_state = if restrictions.is_minute_allowed(state.time.hour(), i as u32) {
+                        StyleState::Active
+                    } else {
+                        style_state = style_state.max(StyleState::Disabled);
+                        StyleState::Disabled
+                    };
+
+                    if i % minute_labels == 0 {
                         let text = CanvasText {
                             content: format!("{i:02}"),
                             position: *p,
@@ -1380,12 +1444,75 @@ fn draw_clock<Message, Theme>(
                         frame.fill(
                             &circle,
                             style
-                                .get(&StyleState::Active)
+                                .get(&dot_state)
                                 .expect("Style Sheet not found.")
                                 .clock_dots_color,
                         );
                     }
                 });
+
+            if state.show_seconds {
+                let second_labels = crate::core::clock::label_step(state.second_step, 10);
+                second_points
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| i % usize::from(state.second_step.max(1)) == 0)
+                    .for_each(|(i, p)| {
+                        let selected = state.time.second() == i as u32;
+
+                        let mut style_state = StyleState::Active;
+                        if selected {
+                            frame.stroke(&Path::line(center, *p), hand_stroke);
+                            frame.fill(
+                                &Path::circle(*p, number_size * 0.6),
+                                style
+                                    .get(&StyleState::Selected)
+                                    .expect("Style Sheet not found.")
+                                    .clock_number_background,
+                            );
+                            style_state = style_state.max(StyleState::Selected);
+                        }
+
+                        let dot_state = if restrictions.is_allowed(
+                            state
+                                .time
+                                .with_second(i as u32)
+                                .expect("New time with second should be valid"),
+                        ) {
+                            StyleState::Active
+                        } else {
+                            style_state = style_state.max(StyleState::Disabled);
+                            StyleState::Disabled
+                        };
+
+                        if i % second_labels == 0 {
+                            let text = CanvasText {
+                                content: format!("{i:02}"),
+                                position: *p,
+                                color: style
+                                    .get(&style_state)
+                                    .expect("Style Sheet not found.")
+                                    .clock_number_color,
+                                size: Pixels(number_size),
+                                font: renderer.default_font(),
+                                horizontal_alignment: Horizontal::Center,
+                                vertical_alignment: Vertical::Center,
+                                shaping: text::Shaping::Basic,
+                                line_height: text::LineHeight::Relative(1.3),
+                            };
+
+                            frame.fill_text(text);
+                        } else {
+                            let circle = Path::circle(*p, number_size * 0.1);
+                            frame.fill(
+                                &circle,
+                                style
+                                    .get(&dot_state)
+                                    .expect("Style Sheet not found.")
+                                    .clock_dots_color,
+                            );
+                        }
+                    });
             }
         });
 
@@ -1397,16 +1524,14 @@ fn draw_clock<Message, Theme>(
 
 /// Draws the digital clock.
 #[allow(clippy::too_many_lines)]
-fn draw_digital_clock<Message, Theme>(
+pub(crate) fn draw_digital_clock(
     renderer: &mut Renderer,
-    time_picker: &TimePickerOverlay<'_, '_, Message, Theme>,
+    state: &State,
+    restrictions: &Restrictions,
     layout: Layout<'_>,
     cursor: Cursor,
     style: &HashMap<StyleState, Style>,
-) where
-    Message: 'static + Clone,
-    Theme: Catalog + button::Catalog + text::Catalog,
-{
+) {
     //println!("layout: {:#?}", layout);
     let mut children = layout
         .children()
@@ -1414,8 +1539,20 @@ fn draw_digital_clock<Message, Theme>(
         .expect("Graphics: Layout should have digital clock children")
         .children();
 
+    // The arrows are dimmed if no time can be picked in their direction.
+    let up_state = if restrictions.is_later_allowed(state.time) {
+        StyleState::Active
+    } else {
+        StyleState::Disabled
+    };
+    let down_state = if restrictions.is_earlier_allowed(state.time) {
+        StyleState::Active
+    } else {
+        StyleState::Disabled
+    };
+
     let f = |renderer: &mut Renderer, layout: Layout<'_>, text: String, target: Focus| {
-        let style_state = if time_picker.state.focus == target {
+        let style_state = if state.focus == target {
             StyleState::Focused
         } else {
             StyleState::Active
@@ -1484,7 +1621,7 @@ fn draw_digital_clock<Message, Theme>(
             },
             Point::new(up_bounds.center_x(), up_bounds.center_y()),
             style
-                .get(&StyleState::Active)
+                .get(&up_state)
                 .expect("Style Sheet not found.")
                 .text_color,
             up_bounds,
@@ -1527,14 +1664,14 @@ fn draw_digital_clock<Message, Theme>(
             },
             Point::new(down_bounds.center_x(), down_bounds.center_y()),
             style
-                .get(&StyleState::Active)
+                .get(&down_state)
                 .expect("Style Sheet not found.")
                 .text_color,
             down_bounds,
         );
     };
 
-    if !time_picker.state.use_24h {
+    if !state.use_24h {
         // Placeholder
         let _ = children.next();
     }
@@ -1548,10 +1685,10 @@ fn draw_digital_clock<Message, Theme>(
         hour_layout,
         format!(
             "{:02}",
-            if time_picker.state.use_24h {
-                time_picker.state.time.hour()
+            if state.use_24h {
+                state.time.hour()
             } else {
-                time_picker.state.time.hour12().1
+                state.time.hour12().1
             }
         ),
         Focus::DigitalHour,
@@ -1591,11 +1728,11 @@ fn draw_digital_clock<Message, Theme>(
     f(
         renderer,
         minute_layout,
-        format!("{:02}", time_picker.state.time.minute()),
+        format!("{:02}", state.time.minute()),
         Focus::DigitalMinute,
     );
 
-    if time_picker.state.show_seconds {
+    if state.show_seconds {
         // Draw separator between minutes and seconds
         let minute_second_separator = children
             .next()
@@ -1629,19 +1766,19 @@ fn draw_digital_clock<Message, Theme>(
         f(
             renderer,
             second_layout,
-            format!("{:02}", time_picker.state.time.second()),
+            format!("{:02}", state.time.second()),
             Focus::DigitalSecond,
         );
     }
 
     // Draw period
-    if !time_picker.state.use_24h {
+    if !state.use_24h {
         let period = children
             .next()
             .expect("Graphics: Layout should have a period layout");
         renderer.fill_text(
             Text {
-                content: if time_picker.state.time.hour12().0 {
+                content: if state.time.hour12().0 {
                     "PM".to_owned()
                 } else {
                     "AM".to_owned()
@@ -1659,6 +1796,31 @@ fn draw_digital_clock<Message, Theme>(
             period.bounds(),
         );
     }
+
+    // Draw offset
+    if let Some(offset) = state.offset {
+        let offset_layout = children
+            .next()
+            .expect("Graphics: Layout should have an offset layout");
+        renderer.fill_text(
+            Text {
+                content: crate::core::time::offset_as_string(offset),
+                bounds: Size::new(offset_layout.bounds().width, offset_layout.bounds().height),
+                size: renderer.default_size(),
+                font: renderer.default_font(),
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                line_height: text::LineHeight::Relative(1.3),
+                shaping: text::Shaping::Basic,
+            },
+            Point::new(
+                offset_layout.bounds().center_x(),
+                offset_layout.bounds().center_y(),
+            ),
+            style[&StyleState::Active].text_color,
+            offset_layout.bounds(),
+        );
+    }
 }
 
 /// The state of the [`TimePickerOverlay`].
@@ -1680,6 +1842,12 @@ pub struct State {
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// The offset from UTC shown in the digital clock of the [`TimePickerOverlay`].
+    pub(crate) offset: Option<FixedOffset>,
+    /// The step in minutes the minutes of the [`TimePickerOverlay`] can be picked in.
+    pub(crate) minute_step: u8,
+    /// The step in seconds the seconds of the [`TimePickerOverlay`] can be picked in.
+    pub(crate) second_step: u8,
 }
 
 impl State {
@@ -1693,6 +1861,93 @@ impl State {
             ..Self::default()
         }
     }
+
+    /// Sets the offset from UTC and the steps the time is picked in, aligning
+    /// the time to the steps if they changed.
+    pub(crate) fn set_clock(
+        &mut self,
+        offset: Option<FixedOffset>,
+        minute_step: u8,
+        second_step: u8,
+    ) {
+        if self.offset != offset {
+            self.offset = offset;
+            self.clock_cache.clear();
+        }
+
+        if (self.minute_step, self.second_step) != (minute_step, second_step) {
+            self.minute_step = minute_step;
+            self.second_step = second_step;
+            self.align_to_steps();
+            self.clock_cache.clear();
+        }
+    }
+
+    /// Rounds the minutes and seconds of the time down to multiples of the
+    /// minute and second step.
+    pub(crate) fn align_to_steps(&mut self) {
+        let minute_step = u32::from(self.minute_step.max(1));
+        let second_step = u32::from(self.second_step.max(1));
+
+        self.time = self
+            .time
+            .with_minute(self.time.minute() - self.time.minute() % minute_step)
+            .and_then(|time| time.with_second(time.second() - time.second() % second_step))
+            .expect("New time with minute and second should be valid");
+    }
+
+    /// Gets the picked time, moved to the nearest time that can be picked.
+    ///
+    /// Only whole minutes can be picked while the seconds are not shown, so
+    /// the seconds are dropped before the restrictions are applied and the
+    /// picked time never lies before the earliest time.
+    pub(crate) fn picked_time(&self, restrictions: &Restrictions) -> NaiveTime {
+        if self.show_seconds {
+            return restrictions.nearest(self.time);
+        }
+
+        let restrictions = Restrictions {
+            second_step: 60,
+            ..restrictions.clone()
+        };
+        restrictions.nearest(
+            self.time
+                .with_second(0)
+                .expect("Time with zero seconds should be valid"),
+        )
+    }
+
+    /// Gets the durations moving the minutes up and down to the next multiple
+    /// of the minute step.
+    fn minute_durations(&self) -> (Duration, Duration) {
+        let minute = self.time.minute();
+        (
+            Duration::minutes(i64::from(crate::core::clock::distance_to_next_step(
+                minute,
+                self.minute_step,
+            ))),
+            Duration::minutes(i64::from(crate::core::clock::distance_to_previous_step(
+                minute,
+                self.minute_step,
+            ))),
+        )
+    }
+
+    /// Gets the durations moving the seconds up and down to the next multiple
+    /// of the second step.
+    fn second_durations(&self) -> (Duration, Duration) {
+        let second = self.time.second();
+        (
+            Duration::seconds(i64::from(crate::core::clock::distance_to_next_step(
+                second,
+                self.second_step,
+            ))),
+            Duration::seconds(i64::from(crate::core::clock::distance_to_previous_step(
+                second,
+                self.second_step,
+            ))),
+        )
+    }
 }
 
 impl Default for State {
@@ -1706,6 +1961,9 @@ impl Default for State {
             clock_dragged: ClockDragged::None,
             focus: Focus::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            offset: None,
+            minute_step: 1,
+            second_step: 1,
         }
     }
 }
@@ -1886,3 +2144,37 @@ impl Default for Focus {
         Self::None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveTime;
+
+    use super::State;
+    use crate::time_picker::{Period, Restrictions, Time};
+
+    #[test]
+    fn picked_time_test() {
+        let restrictions = Restrictions {
+            min_time: NaiveTime::from_hms_opt(9, 0, 30),
+            ..Restrictions::default()
+        };
+        let time = Time::Hms {
+            hour: 8,
+            minute: 0,
+            second: 0,
+            period: Period::H24,
+        };
+
+        let state = State::new(time, true, false);
+        assert_eq!(
+            state.picked_time(&restrictions),
+            NaiveTime::from_hms_opt(9, 1, 0).expect("Time should be valid")
+        );
+
+        let state = State::new(time, true, true);
+        assert_eq!(
+            state.picked_time(&restrictions),
+            NaiveTime::from_hms_opt(9, 0, 30).expect("Time should be valid")
+        );
+    }
+}
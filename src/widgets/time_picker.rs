@@ -3,8 +3,9 @@
 //! *This API requires the following crate features to be activated: `time_picker`*
 
 use super::overlay::time_picker::{self, TimePickerOverlay, TimePickerOverlayButtons};
+use crate::core::time::{next_step_time, previous_step_time};
 
-use chrono::Local;
+use chrono::{Duration, FixedOffset, Local, NaiveTime, Utc};
 use iced::{
     advanced::{
         layout::{Limits, Node},
@@ -26,7 +27,7 @@ use iced::{
 };
 
 pub use crate::{
-    core::time::{Period, Time},
+    core::time::{OffsetTime, Period, Time},
     style::{
         time_picker::{Catalog, Style},
         Status, StyleFn,
@@ -81,6 +82,14 @@ where
     use_24h: bool,
     /// Toggle the use of the seconds of the [`TimePickerOverlay`].
     show_seconds: bool,
+    /// The offset from UTC of the picked time if the [`TimePicker`] is offset aware.
+    offset: Option<FixedOffset>,
+    /// The step in minutes the minutes of the [`TimePickerOverlay`] can be picked in.
+    minute_step: u8,
+    /// The step in seconds the seconds of the [`TimePickerOverlay`] can be picked in.
+    second_step: u8,
+    /// The restrictions on the times that can be picked.
+    restrictions: Restrictions,
 }
 
 impl<'a, Message, Theme> TimePicker<'a, Message, Theme>
@@ -119,6 +128,56 @@ where
             overlay_state: TimePickerOverlayButtons::default().into(),
             use_24h: false,
             show_seconds: false,
+            offset: None,
+            minute_step: 1,
+            second_step: 1,
+            restrictions: Restrictions::default(),
+        }
+    }
+
+    /// Creates a new [`TimePicker`] picking the time of day of an offset aware
+    /// date and time, wrapping around the given underlay.
+    ///
+    /// The offset is shown in the digital clock of the [`TimePicker`], the date
+    /// and the offset are kept when the time is picked.
+    ///
+    /// It expects:
+    ///     * if the overlay of the time picker is visible.
+    ///     * the initial date and time to show, e.g. a `chrono::DateTime<FixedOffset>`
+    ///         or a `time::OffsetDateTime`.
+    ///     * the underlay [`Element`] on which this [`TimePicker`]
+    ///         will be wrapped around.
+    ///     * a message that will be send when the cancel button of the [`TimePicker`] is pressed.
+    ///     * a function that will be called when the submit button of the [`TimePicker`]
+    ///         is pressed, which takes the date and time with the picked time of day.
+    pub fn new_with_offset<U, T, F>(
+        show_picker: bool,
+        date_time: T,
+        underlay: U,
+        on_cancel: Message,
+        on_submit: F,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Theme, Renderer>>,
+        T: 'static + OffsetTime,
+        F: 'static + Fn(T) -> Message,
+    {
+        Self {
+            show_picker,
+            time: date_time.time_of_day().into(),
+            underlay: underlay.into(),
+            on_cancel,
+            on_submit: Box::new(move |time: Time| {
+                on_submit(date_time.with_time_of_day(time.into()))
+            }),
+            class: <Theme as Catalog>::default(),
+            overlay_state: TimePickerOverlayButtons::default().into(),
+            use_24h: false,
+            show_seconds: false,
+            offset: Some(date_time.utc_offset()),
+            minute_step: 1,
+            second_step: 1,
+            restrictions: Restrictions::default(),
         }
     }
 
@@ -129,6 +188,48 @@ where
         self
     }
 
+    /// Sets the step in minutes the minutes of the [`TimePicker`] can be picked in,
+    /// e.g. 5, 15 or 30. The step should be a divisor of 60.
+    #[must_use]
+    pub fn minute_step(mut self, step: u8) -> Self {
+        self.minute_step = step.clamp(1, 60);
+        self.restrictions.minute_step = self.minute_step;
+        self
+    }
+
+    /// Sets the step in seconds the seconds of the [`TimePicker`] can be picked in,
+    /// e.g. 5, 15 or 30. The step should be a divisor of 60.
+    #[must_use]
+    pub fn second_step(mut self, step: u8) -> Self {
+        self.second_step = step.clamp(1, 60);
+        self.restrictions.second_step = self.second_step;
+        self
+    }
+
+    /// Sets the earliest time that can be picked in the [`TimePicker`].
+    #[must_use]
+    pub fn min_time(mut self, time: impl Into<Time>) -> Self {
+        self.restrictions.min_time = Some(time.into().into());
+        self
+    }
+
+    /// Sets the latest time that can be picked in the [`TimePicker`].
+    #[must_use]
+    pub fn max_time(mut self, time: impl Into<Time>) -> Self {
+        self.restrictions.max_time = Some(time.into().into());
+        self
+    }
+
+    /// Blocks the times from the start up to, but excluding, the end from
+    /// being picked in the [`TimePicker`], e.g. a lunch break.
+    #[must_use]
+    pub fn block_interval(mut self, start: impl Into<Time>, end: impl Into<Time>) -> Self {
+        self.restrictions
+            .blocked
+            .push((start.into().into(), end.into().into()));
+        self
+    }
+
     /// Sets the style of the [`TimePicker`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -154,6 +255,210 @@ where
     }
 }
 
+/// The restrictions on the times that can be picked in the [`TimePicker`].
+#[derive(Clone, Debug, Default)]
+pub struct Restrictions {
+    /// The earliest time that can be picked.
+    pub(crate) min_time: Option<NaiveTime>,
+    /// The latest time that can be picked.
+    pub(crate) max_time: Option<NaiveTime>,
+    /// The intervals of times that can not be picked, given by their start
+    /// and their excluded end.
+    pub(crate) blocked: Vec<(NaiveTime, NaiveTime)>,
+    /// The step in minutes the times are picked in.
+    pub(crate) minute_step: u8,
+    /// The step in seconds the times are picked in.
+    pub(crate) second_step: u8,
+}
+
+impl Restrictions {
+    /// Checks if any time is restricted.
+    #[must_use]
+    pub fn is_restricted(&self) -> bool {
+        self.min_time.is_some() || self.max_time.is_some() || !self.blocked.is_empty()
+    }
+
+    /// Gets the earliest time that can be picked.
+    fn min(&self) -> NaiveTime {
+        self.min_time
+            .unwrap_or_else(|| NaiveTime::from_hms_opt(0, 0, 0).expect("Time Conversion failed"))
+    }
+
+    /// Gets the latest time that can be picked.
+    fn max(&self) -> NaiveTime {
+        self.max_time
+            .unwrap_or_else(|| NaiveTime::from_hms_opt(23, 59, 59).expect("Time Conversion failed"))
+    }
+
+    /// Gets the blocked interval containing the given time.
+    fn blocking(&self, time: NaiveTime) -> Option<(NaiveTime, NaiveTime)> {
+        self.blocked
+            .iter()
+            .find(|(start, end)| *start <= time && time < *end)
+            .copied()
+    }
+
+    /// Checks if the given time can be picked.
+    #[must_use]
+    pub fn is_allowed(&self, time: NaiveTime) -> bool {
+        self.min() <= time && time <= self.max() && self.blocking(time).is_none()
+    }
+
+    /// Checks if any time from the start up to and including the end can be
+    /// picked.
+    #[must_use]
+    pub fn is_span_allowed(&self, start: NaiveTime, end: NaiveTime) -> bool {
+        // The earliest allowed time is either the start itself or the end of
+        // a restriction lying in the span.
+        std::iter::once(start)
+            .chain(std::iter::once(self.min()))
+            .chain(self.blocked.iter().map(|(_, end)| *end))
+            .filter(|time| start <= *time && *time <= end)
+            .any(|time| self.is_allowed(time))
+    }
+
+    /// Checks if any time of the given hour can be picked.
+    #[must_use]
+    pub fn is_hour_allowed(&self, hour: u32) -> bool {
+        match (
+            NaiveTime::from_hms_opt(hour, 0, 0),
+            NaiveTime::from_hms_opt(hour, 59, 59),
+        ) {
+            (Some(start), Some(end)) => self.is_span_allowed(start, end),
+            _ => false,
+        }
+    }
+
+    /// Checks if any time of the given minute of the given hour can be picked.
+    #[must_use]
+    pub fn is_minute_allowed(&self, hour: u32, minute: u32) -> bool {
+        match (
+            NaiveTime::from_hms_opt(hour, minute, 0),
+            NaiveTime::from_hms_opt(hour, minute, 59),
+        ) {
+            (Some(start), Some(end)) => self.is_span_allowed(start, end),
+            _ => false,
+        }
+    }
+
+    /// Gets the time closest to the given time that can be picked, preferring
+    /// the following times.
+    ///
+    /// The time is rounded to the nearest minute and second step lying inside
+    /// the restrictions.
+    #[must_use]
+    pub fn nearest(&self, time: NaiveTime) -> NaiveTime {
+        let nearest = self.nearest_time(time);
+
+        if self.minute_step <= 1 && self.second_step <= 1 {
+            return nearest;
+        }
+
+        let (min, max) = (self.min(), self.max());
+        let (minute_step, second_step) = (self.minute_step, self.second_step);
+
+        let later =
+            std::iter::successors(next_step_time(nearest, minute_step, second_step), |time| {
+                let (next, overflow) = time.overflowing_add_signed(Duration::seconds(1));
+                (overflow == 0)
+                    .then_some(next)
+                    .and_then(|next| next_step_time(next, minute_step, second_step))
+            })
+            .take_while(|time| *time <= max)
+            .find(|time| self.is_allowed(*time));
+
+        let earlier = std::iter::successors(
+            Some(previous_step_time(nearest, minute_step, second_step)),
+            |time| {
+                let (previous, overflow) = time.overflowing_add_signed(Duration::seconds(-1));
+                (overflow == 0).then(|| previous_step_time(previous, minute_step, second_step))
+            },
+        )
+        .take_while(|time| *time >= min)
+        .find(|time| self.is_allowed(*time));
+
+        match (earlier, later) {
+            (Some(earlier), Some(later)) => {
+                if nearest - earlier < later - nearest {
+                    earlier
+                } else {
+                    later
+                }
+            }
+            (Some(time), None) | (None, Some(time)) => time,
+            (None, None) => nearest,
+        }
+    }
+
+    /// Gets the time closest to the given time that can be picked, preferring
+    /// the following times, regardless of the steps.
+    fn nearest_time(&self, time: NaiveTime) -> NaiveTime {
+        let (min, max) = (self.min(), self.max());
+        let time = time.clamp(min, max.max(min));
+
+        let mut later = time;
+        while let Some((_, end)) = self.blocking(later) {
+            later = end;
+        }
+        if later <= max {
+            return later;
+        }
+
+        let mut earlier = time;
+        while let Some((start, _)) = self.blocking(earlier) {
+            if start <= min {
+                return time;
+            }
+            earlier = start - Duration::seconds(1);
+        }
+        earlier
+    }
+
+    /// Checks if any time later than the given time can be picked.
+    #[must_use]
+    pub fn is_later_allowed(&self, time: NaiveTime) -> bool {
+        let (next, overflow) = time.overflowing_add_signed(Duration::seconds(1));
+        !self.is_restricted() || (overflow == 0 && self.is_span_allowed(next, self.max()))
+    }
+
+    /// Checks if any time earlier than the given time can be picked.
+    #[must_use]
+    pub fn is_earlier_allowed(&self, time: NaiveTime) -> bool {
+        let (previous, overflow) = time.overflowing_add_signed(Duration::seconds(-1));
+        !self.is_restricted() || (overflow == 0 && self.is_span_allowed(self.min(), previous))
+    }
+
+    /// Steps from the given time by the given duration until the check
+    /// accepts the time, without wrapping around midnight. Returns `None` if
+    /// no time is accepted.
+    ///
+    /// Without any restrictions the time is stepped once and wraps around
+    /// midnight.
+    #[must_use]
+    pub fn step(
+        &self,
+        time: NaiveTime,
+        duration: Duration,
+        accept: impl Fn(NaiveTime) -> bool,
+    ) -> Option<NaiveTime> {
+        if !self.is_restricted() {
+            return Some(time.overflowing_add_signed(duration).0);
+        }
+
+        let mut candidate = time;
+        loop {
+            let (next, overflow) = candidate.overflowing_add_signed(duration);
+            if overflow != 0 || next == candidate {
+                return None;
+            }
+            if accept(next) {
+                return Some(self.nearest(next));
+            }
+            candidate = next;
+        }
+    }
+}
+
 /// The state of the [`TimePicker`] / [`TimePickerOverlay`].
 #[derive(Debug)]
 pub struct State {
@@ -178,10 +483,15 @@ impl State {
         }
     }
 
-    /// Resets the time of the state to the current time.
+    /// Resets the time of the state to the current time, in the offset of the
+    /// [`TimePicker`] if it is offset aware.
     pub fn reset(&mut self) {
         self.overlay_state.clock_cache.clear();
-        self.overlay_state.time = Local::now().naive_local().time();
+        self.overlay_state.time = match self.overlay_state.offset {
+            Some(offset) => Utc::now().with_timezone(&offset).time(),
+            None => Local::now().naive_local().time(),
+        };
+        self.overlay_state.align_to_steps();
     }
 }
 
@@ -195,7 +505,12 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new(self.time, self.use_24h, self.show_seconds))
+        let mut state = State::new(self.time, self.use_24h, self.show_seconds);
+        state
+            .overlay_state
+            .set_clock(self.offset, self.minute_step, self.second_step);
+        state.overlay_state.align_to_steps();
+        tree::State::new(state)
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -203,6 +518,11 @@ where
     }
 
     fn diff(&self, tree: &mut Tree) {
+        tree.state.downcast_mut::<State>().overlay_state.set_clock(
+            self.offset,
+            self.minute_step,
+            self.second_step,
+        );
         tree.diff_children(&[&self.underlay, &self.overlay_state]);
     }
 
@@ -303,6 +623,7 @@ where
                 picker_state,
                 self.on_cancel.clone(),
                 &self.on_submit,
+                &self.restrictions,
                 position,
                 &self.class,
                 &mut state.children[1],
@@ -4,25 +4,30 @@
 
 use iced::advanced::layout::{Layout, Limits, Node};
 use iced::advanced::widget::{
-    tree::{State, Tag},
+    tree::{self, Tag},
     Operation, Tree, Widget,
 };
 use iced::advanced::{Clipboard, Shell};
 use iced::mouse::{self, Cursor};
 use iced::{
     event,
-    widget::text_input::{self, TextInput},
+    widget::text_input::{self, TextInput, Value},
     Event, Size,
 };
 use iced::{Element, Length, Rectangle};
 
 use std::{fmt::Display, str::FromStr};
 
+pub use crate::style::typed_input::Catalog;
+
 /// The default padding
 const DEFAULT_PADDING: f32 = 5.0;
 
 /// A field that can only be filled with a specific type.
 ///
+/// Text that is not a valid value stays visible and is drawn with the error
+/// style until it is corrected.
+///
 /// # Example
 /// ```ignore
 /// # use iced_aw::TypedInput;
@@ -41,20 +46,40 @@ const DEFAULT_PADDING: f32 = 5.0;
 /// ```
 pub struct TypedInput<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer>
 where
+    T: FromStr,
     Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
-    Theme: text_input::Catalog,
+    Theme: Catalog,
 {
     /// The current value of the [`TypedInput`].
     value: T,
     /// The underlying element of the [`TypeInput`].
     text_input: text_input::TextInput<'a, InternalMessage, Theme, Renderer>,
+    /// The underlying element of the [`TypeInput`] drawn while the text is invalid.
+    error_input: text_input::TextInput<'a, InternalMessage, Theme, Renderer>,
+    /// The text of the value of the [`TypedInput`].
     text: String,
+    /// The placeholder of the [`TextInput`].
+    placeholder: String,
+    /// The function parsing the text of the [`TypedInput`].
+    parser: Box<dyn Fn(&str) -> Result<T, T::Err> + 'a>,
+    /// The function checking the parsed value of the [`TypedInput`].
+    validator: Option<Box<dyn Fn(&T) -> bool + 'a>>,
     /// The ``on_change`` event of the [`TextInput`].
     on_change: Box<dyn Fn(T) -> Message>,
+    /// The event produced when the text of the [`TextInput`] cannot be parsed.
+    on_invalid: Option<Box<dyn Fn(String, T::Err) -> Message + 'a>>,
+    /// The event produced when the validator rejects the parsed value of the [`TextInput`].
+    on_rejected: Option<Box<dyn Fn(String, T) -> Message + 'a>>,
     /// The ``on_change`` event of the [`TextInput`].
     on_submit: Option<Message>,
     /// The font text of the [`TextInput`].
     font: Renderer::Font,
+    /// The width of the [`TextInput`].
+    width: Length,
+    /// The padding of the [`TextInput`].
+    padding: f32,
+    /// The text size of the [`TextInput`].
+    size: Option<f32>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -68,7 +93,7 @@ where
     T: Display + FromStr,
     Message: Clone,
     Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
-    Theme: text_input::Catalog,
+    Theme: Catalog,
 {
     /// Creates a new [`TypedInput`].
     ///
@@ -81,22 +106,82 @@ where
         T: 'a + Clone,
     {
         let padding = DEFAULT_PADDING;
-
-        Self {
-            value: value.clone(),
-            text_input: text_input::TextInput::new(placeholder, format!("{value}").as_str())
+        let input = || {
+            text_input::TextInput::new(placeholder, format!("{value}").as_str())
                 .on_input(InternalMessage::OnChange)
                 .on_submit(InternalMessage::OnSubmit)
                 .padding(padding)
                 .width(Length::Fixed(127.0))
-                .class(<Theme as text_input::Catalog>::default()),
+        };
+
+        Self {
+            value: value.clone(),
+            text_input: input().class(<Theme as text_input::Catalog>::default()),
+            error_input: input().class(Theme::default_error()),
             text: value.to_string(),
+            placeholder: placeholder.to_owned(),
+            parser: Box::new(T::from_str),
+            validator: None,
             on_change: Box::new(on_changed),
+            on_invalid: None,
+            on_rejected: None,
             on_submit: None,
             font: Renderer::Font::default(),
+            width: Length::Fixed(127.0),
+            padding,
+            size: None,
         }
     }
 
+    /// Sets the function formatting the value shown in the [`TypedInput`],
+    /// instead of its [`Display`] implementation.
+    ///
+    /// Typed text is kept while it is parsed to the same value, so it is only
+    /// replaced by the formatted text when the value is changed from outside.
+    #[must_use]
+    pub fn formatter(mut self, formatter: impl Fn(&T) -> String) -> Self {
+        self.text = formatter(&self.value);
+        self
+    }
+
+    /// Sets the function parsing the text typed into the [`TypedInput`],
+    /// instead of its [`FromStr`] implementation.
+    ///
+    /// It should accept the text produced by the [`formatter`](Self::formatter).
+    #[must_use]
+    pub fn parser(mut self, parser: impl Fn(&str) -> Result<T, T::Err> + 'a) -> Self {
+        self.parser = Box::new(parser);
+        self
+    }
+
+    /// Sets the function checking the parsed value of the [`TypedInput`].
+    ///
+    /// Values it rejects are not published, their text is kept and drawn with
+    /// the error style like text that cannot be parsed and
+    /// [`on_rejected`](Self::on_rejected) is called.
+    #[must_use]
+    pub fn validator(mut self, validator: impl Fn(&T) -> bool + 'a) -> Self {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Sets the function that produces a message when the typed text cannot
+    /// be parsed, taking the text and the error of the parser.
+    #[must_use]
+    pub fn on_invalid(mut self, on_invalid: impl Fn(String, T::Err) -> Message + 'a) -> Self {
+        self.on_invalid = Some(Box::new(on_invalid));
+        self
+    }
+
+    /// Sets the function that produces a message when the
+    /// [`validator`](Self::validator) rejects a parsed value, taking the text
+    /// and the rejected value.
+    #[must_use]
+    pub fn on_rejected(mut self, on_rejected: impl Fn(String, T) -> Message + 'a) -> Self {
+        self.on_rejected = Some(Box::new(on_rejected));
+        self
+    }
+
     /// Gets the text value of the [`TypedInput`].
     pub fn text(&self) -> &str {
         &self.text
@@ -105,7 +190,9 @@ where
     /// Sets the width of the [`TypedInput`].
     #[must_use]
     pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
         self.text_input = self.text_input.width(width);
+        self.error_input = self.error_input.width(width);
         self
     }
 
@@ -118,6 +205,7 @@ where
     pub fn font(mut self, font: Renderer::Font) -> Self {
         self.font = font;
         self.text_input = self.text_input.font(font);
+        self.error_input = self.error_input.font(font);
         self
     }
 
@@ -132,14 +220,18 @@ where
     /// Sets the padding of the [`TypedInput`].
     #[must_use]
     pub fn padding(mut self, units: f32) -> Self {
+        self.padding = units;
         self.text_input = self.text_input.padding(units);
+        self.error_input = self.error_input.padding(units);
         self
     }
 
     /// Sets the text size of the [`TypedInput`].
     #[must_use]
     pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
         self.text_input = self.text_input.size(size);
+        self.error_input = self.error_input.size(size);
         self
     }
 
@@ -162,6 +254,127 @@ where
         self.text_input = self.text_input.class(class);
         self
     }
+
+    /// Sets the style of the input of the [`TypedInput`] while its text is
+    /// not a valid value.
+    #[must_use]
+    pub fn error_style(
+        mut self,
+        style: impl Fn(&Theme, text_input::Status) -> text_input::Style + 'a,
+    ) -> Self
+    where
+        <Theme as text_input::Catalog>::Class<'a>: From<text_input::StyleFn<'a, Theme>>,
+    {
+        self.error_input = self.error_input.style(style);
+        self
+    }
+
+    /// Sets the class of the input of the [`TypedInput`] while its text is
+    /// not a valid value.
+    #[must_use]
+    pub fn error_class(
+        mut self,
+        class: impl Into<<Theme as text_input::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.error_input = self.error_input.class(class);
+        self
+    }
+
+    /// Creates a [`TextInput`] editing the shown text.
+    ///
+    /// The shown text may differ from the text the [`TypedInput`] was created
+    /// with, so the events are handled by an input with the current text.
+    fn editor(&self, text: &str) -> TextInput<'a, InternalMessage, Theme, Renderer> {
+        let editor = TextInput::new(&self.placeholder, text)
+            .on_input(InternalMessage::OnChange)
+            .on_submit(InternalMessage::OnSubmit)
+            .padding(self.padding)
+            .width(self.width)
+            .font(self.font);
+
+        match self.size {
+            Some(size) => editor.size(size),
+            None => editor,
+        }
+    }
+}
+
+/// The state of a [`TypedInput`].
+#[derive(Clone, Debug, Default)]
+pub struct State {
+    /// The shown text.
+    text: String,
+    /// The text of the value the shown text was typed for.
+    value_text: String,
+    /// Whether the shown text is not a valid value.
+    is_invalid: bool,
+}
+
+impl State {
+    /// Creates a new [`State`] showing the given text of the value.
+    #[must_use]
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_owned(),
+            value_text: text.to_owned(),
+            is_invalid: false,
+        }
+    }
+
+    /// Gets the text shown in the [`TypedInput`].
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Checks if the text shown in the [`TypedInput`] is not a valid value.
+    #[must_use]
+    pub const fn is_invalid(&self) -> bool {
+        self.is_invalid
+    }
+
+    /// Shows the given text of a new value in the [`TypedInput`].
+    pub(crate) fn set_text(&mut self, text: &str) {
+        *self = Self::new(text);
+    }
+
+    /// Shows the text typed into the [`TypedInput`], which is the text of its
+    /// value if it is valid.
+    pub(crate) fn set_typed_text(&mut self, text: String, is_valid: bool) {
+        if is_valid {
+            self.value_text.clone_from(&text);
+        }
+        self.text = text;
+        self.is_invalid = !is_valid;
+    }
+
+    /// Shows the given text of the value of the [`TypedInput`] if the value
+    /// changed.
+    ///
+    /// The text of a value may be formatted differently than it was typed,
+    /// so the shown text is kept while it is parsed to the same value.
+    /// Otherwise the caret would stay at its position in the reformatted text.
+    pub(crate) fn sync<T: PartialEq, E>(
+        &mut self,
+        text: &str,
+        value: &T,
+        parser: impl Fn(&str) -> Result<T, E>,
+    ) {
+        if self.value_text == text {
+            return;
+        }
+
+        if !self.is_invalid && parser(&self.text).is_ok_and(|typed| typed == *value) {
+            self.value_text = text.to_owned();
+        } else {
+            *self = Self::new(text);
+        }
+    }
+
+    /// Sets whether the text shown in the [`TypedInput`] is not a valid value.
+    pub(crate) fn set_invalid(&mut self, is_invalid: bool) {
+        self.is_invalid = is_invalid;
+    }
 }
 
 impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -170,56 +383,81 @@ where
     T: Display + FromStr + Clone + PartialEq,
     Message: 'a + Clone,
     Renderer: 'a + iced::advanced::text::Renderer<Font = iced::Font>,
-    Theme: text_input::Catalog,
+    Theme: Catalog,
 {
     fn tag(&self) -> Tag {
-        <TextInput<_, _, _> as Widget<_, _, _>>::tag(&self.text_input)
+        Tag::of::<State>()
     }
-    fn state(&self) -> State {
-        <TextInput<_, _, _> as Widget<_, _, _>>::state(&self.text_input)
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(&self.text))
     }
 
     fn children(&self) -> Vec<Tree> {
-        <TextInput<_, _, _> as Widget<_, _, _>>::children(&self.text_input)
+        vec![Tree {
+            tag: <TextInput<_, _, _> as Widget<_, _, _>>::tag(&self.text_input),
+            state: <TextInput<_, _, _> as Widget<_, _, _>>::state(&self.text_input),
+            children: <TextInput<_, _, _> as Widget<_, _, _>>::children(&self.text_input),
+        }]
     }
 
-    fn diff(&self, state: &mut Tree) {
-        <TextInput<_, _, _> as Widget<_, _, _>>::diff(&self.text_input, state);
+    fn diff(&self, tree: &mut Tree) {
+        tree.state
+            .downcast_mut::<State>()
+            .sync(&self.text, &self.value, &self.parser);
+
+        tree.diff_children_custom(
+            &[&self.text_input],
+            |tree, text_input| <TextInput<_, _, _> as Widget<_, _, _>>::diff(text_input, tree),
+            |&text_input| Tree {
+                tag: <TextInput<_, _, _> as Widget<_, _, _>>::tag(text_input),
+                state: <TextInput<_, _, _> as Widget<_, _, _>>::state(text_input),
+                children: <TextInput<_, _, _> as Widget<_, _, _>>::children(text_input),
+            },
+        );
     }
 
     fn size(&self) -> Size<Length> {
         <TextInput<_, _, _> as Widget<_, _, _>>::size(&self.text_input)
     }
 
-    fn layout(&self, state: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
-        <TextInput<_, _, _> as Widget<_, _, _>>::layout(&self.text_input, state, renderer, limits)
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let value = Value::new(tree.state.downcast_ref::<State>().text());
+        self.text_input
+            .layout(&mut tree.children[0], renderer, limits, Some(&value))
     }
 
     fn draw(
         &self,
-        state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
-        style: &iced::advanced::renderer::Style,
+        _style: &iced::advanced::renderer::Style,
         layout: Layout<'_>,
         cursor: Cursor,
         viewport: &Rectangle,
     ) {
-        <TextInput<_, _, _> as Widget<_, _, _>>::draw(
-            &self.text_input,
-            state,
+        let state = tree.state.downcast_ref::<State>();
+        let text_input = if state.is_invalid() {
+            &self.error_input
+        } else {
+            &self.text_input
+        };
+
+        text_input.draw(
+            &tree.children[0],
             renderer,
             theme,
-            style,
             layout,
             cursor,
+            Some(&Value::new(state.text())),
             viewport,
         );
     }
 
     fn mouse_interaction(
         &self,
-        state: &Tree,
+        tree: &Tree,
         layout: Layout<'_>,
         cursor: Cursor,
         viewport: &Rectangle,
@@ -227,7 +465,7 @@ where
     ) -> mouse::Interaction {
         <TextInput<_, _, _> as Widget<_, _, _>>::mouse_interaction(
             &self.text_input,
-            state,
+            &tree.children[0],
             layout,
             cursor,
             viewport,
@@ -237,14 +475,14 @@ where
 
     fn operate(
         &self,
-        state: &mut Tree,
+        tree: &mut Tree,
         layout: Layout<'_>,
         renderer: &Renderer,
         operation: &mut dyn Operation<()>,
     ) {
         <TextInput<_, _, _> as Widget<_, _, _>>::operate(
             &self.text_input,
-            state,
+            &mut tree.children[0],
             layout,
             renderer,
             operation,
@@ -254,7 +492,7 @@ where
     #[allow(clippy::too_many_lines, clippy::cognitive_complexity)]
     fn on_event(
         &mut self,
-        state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: Cursor,
@@ -263,10 +501,11 @@ where
         shell: &mut Shell<Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
         let mut messages = Vec::new();
         let mut sub_shell = Shell::new(&mut messages);
-        let status = self.text_input.on_event(
-            state,
+        let status = self.editor(&state.text).on_event(
+            &mut tree.children[0],
             event,
             layout,
             cursor,
@@ -289,11 +528,32 @@ where
         for message in messages {
             match message {
                 InternalMessage::OnChange(value) => {
-                    self.text = value;
-                    if let Ok(val) = T::from_str(&self.text) {
-                        if self.value != val {
-                            self.value = val.clone();
-                            shell.publish((self.on_change)(val));
+                    self.text.clone_from(&value);
+
+                    match (self.parser)(&value) {
+                        Ok(val)
+                            if self
+                                .validator
+                                .as_ref()
+                                .map_or(true, |validator| validator(&val)) =>
+                        {
+                            state.set_typed_text(value, true);
+                            if self.value != val {
+                                self.value = val.clone();
+                                shell.publish((self.on_change)(val));
+                            }
+                        }
+                        Ok(val) => {
+                            state.set_typed_text(value, false);
+                            if let Some(on_rejected) = &self.on_rejected {
+                                shell.publish(on_rejected(state.text.clone(), val));
+                            }
+                        }
+                        Err(error) => {
+                            state.set_typed_text(value, false);
+                            if let Some(on_invalid) = &self.on_invalid {
+                                shell.publish(on_invalid(state.text.clone(), error));
+                            }
                         }
                     }
                     shell.invalidate_layout();
@@ -315,7 +575,7 @@ where
     T: 'a + Display + FromStr + Clone + PartialEq,
     Message: 'a + Clone,
     Renderer: 'a + iced::advanced::text::Renderer<Font = iced::Font>,
-    Theme: 'a + text_input::Catalog,
+    Theme: 'a + Catalog,
 {
     fn from(typed_input: TypedInput<'a, T, Message, Theme, Renderer>) -> Self {
         Element::new(typed_input)